@@ -102,6 +102,21 @@ macro_rules! distr {
 }
 
 // uniform
+distr_int!(distr_uniform_u32, u32, Uniform::new(20u32, 100));
+
+#[bench]
+fn distr_uniform_u32_fill(b: &mut Bencher) {
+    let mut rng = SmallRng::from_entropy();
+    let distr = Uniform::new(20u32, 100);
+    let mut buf = [0u32; RAND_BENCH_N as usize];
+
+    b.iter(|| {
+        distr.fill(&mut rng, &mut buf);
+        buf[0]
+    });
+    b.bytes = size_of::<u32>() as u64 * ::RAND_BENCH_N;
+}
+
 distr_int!(distr_uniform_i8, i8, Uniform::new(20i8, 100));
 distr_int!(distr_uniform_i16, i16, Uniform::new(-500i16, 2000));
 distr_int!(distr_uniform_i32, i32, Uniform::new(-200_000_000i32, 800_000_000));
@@ -149,15 +164,42 @@ distr_float!(distr_openclosed01_f32, f32, OpenClosed01);
 distr_float!(distr_openclosed01_f64, f64, OpenClosed01);
 
 // distributions
-distr_float!(distr_exp, f64, Exp::new(1.23 * 4.56));
-distr_float!(distr_normal, f64, Normal::new(-1.23, 4.56));
-distr_float!(distr_log_normal, f64, LogNormal::new(-1.23, 4.56));
-distr_float!(distr_gamma_large_shape, f64, Gamma::new(10., 1.0));
-distr_float!(distr_gamma_small_shape, f64, Gamma::new(0.1, 1.0));
-distr_float!(distr_cauchy, f64, Cauchy::new(4.2, 6.9));
-distr_int!(distr_binomial, u64, Binomial::new(20, 0.7));
-distr_int!(distr_poisson, u64, Poisson::new(4.0));
-distr!(distr_bernoulli, bool, Bernoulli::new(0.18));
+distr_float!(distr_exp, f64, Exp::new(1.23 * 4.56).unwrap());
+distr_float!(distr_normal, f64, Normal::new(-1.23, 4.56).unwrap());
+distr_float!(distr_normal_f32, f32, Normal::new(-1.23f32, 4.56).unwrap());
+distr_float!(distr_normal_affine, f64, StandardNormal.affine(4.56, -1.23));
+distr_float!(distr_log_normal, f64, LogNormal::new(-1.23, 4.56).unwrap());
+distr_float!(distr_gamma_large_shape, f64, Gamma::new(10., 1.0).unwrap());
+distr_float!(distr_gamma_small_shape, f64, Gamma::new(0.1, 1.0).unwrap());
+distr_float!(distr_cauchy, f64, Cauchy::new(4.2, 6.9).unwrap());
+distr_int!(distr_binomial, u64, Binomial::new(20, 0.7).unwrap());
+// The algorithm branches of `Binomial`. The direct method simulates each of
+// the `n < 300` trials, so its cost grows linearly with `n`, about one
+// random `f64` per trial; for `p = 2^-k` it only needs `k` random words per
+// 64 trials, which is much cheaper. The rejection method takes a roughly
+// constant number of attempts whatever `n` is, each costing a Cauchy sample
+// and a few logarithms, so it is slower than the direct method for small
+// `n` but much faster for large `n`. For `p > 0.5` it samples with `1 - p`
+// and reflects the result, which should cost the same as `p < 0.5`.
+distr_int!(distr_binomial_direct, u64, Binomial::new(20, 0.3).unwrap());
+distr_int!(distr_binomial_direct_bits, u64, Binomial::new(20, 0.25).unwrap());
+distr_int!(distr_binomial_rejection, u64, Binomial::new(10_000, 0.5).unwrap());
+distr_int!(distr_binomial_rejection_reflected, u64, Binomial::new(10_000, 0.8).unwrap());
+distr_int!(distr_poisson, u64, Poisson::new(4.0).unwrap());
+
+#[bench]
+fn distr_poisson_sample_into(b: &mut Bencher) {
+    let mut rng = SmallRng::from_entropy();
+    let distr = Poisson::new(50.0).unwrap();
+    let mut buf = [0u64; RAND_BENCH_N as usize];
+
+    b.iter(|| {
+        distr.sample_into(&mut rng, &mut buf);
+        buf[0]
+    });
+    b.bytes = size_of::<u64>() as u64 * ::RAND_BENCH_N;
+}
+distr!(distr_bernoulli, bool, Bernoulli::new(0.18).unwrap());
 
 // Weighted
 distr_int!(distr_weighted_i8, usize, WeightedIndex::new(&[1i8, 2, 3, 4, 12, 0, 2, 1]).unwrap());
@@ -223,7 +265,7 @@ gen_range_float!(gen_range_f64, f64, 123.456f64, 7890.12);
 #[bench]
 fn dist_iter(b: &mut Bencher) {
     let mut rng = SmallRng::from_entropy();
-    let distr = Normal::new(-2.71828, 3.14159);
+    let distr = Normal::new(-2.71828, 3.14159).unwrap();
     let mut iter = distr.sample_iter(&mut rng);
 
     b.iter(|| {
@@ -71,7 +71,7 @@ fn misc_gen_ratio_var(b: &mut Bencher) {
 fn misc_bernoulli_const(b: &mut Bencher) {
     let mut rng = StdRng::from_rng(&mut thread_rng()).unwrap();
     b.iter(|| {
-        let d = rand::distributions::Bernoulli::new(0.18);
+        let d = rand::distributions::Bernoulli::new(0.18).unwrap();
         let mut accum = true;
         for _ in 0..::RAND_BENCH_N {
             accum ^= rng.sample(d);
@@ -87,7 +87,7 @@ fn misc_bernoulli_var(b: &mut Bencher) {
         let mut accum = true;
         let mut p = 0.18;
         for _ in 0..::RAND_BENCH_N {
-            let d = rand::distributions::Bernoulli::new(p);
+            let d = rand::distributions::Bernoulli::new(p).unwrap();
             accum ^= rng.sample(d);
             p += 0.0001;
         }
@@ -102,7 +102,7 @@ macro_rules! sample_binomial {
             let mut rng = SmallRng::from_rng(&mut thread_rng()).unwrap();
             let (n, p) = ($n, $p);
             b.iter(|| {
-                let d = rand::distributions::Binomial::new(n, p);
+                let d = rand::distributions::Binomial::new(n, p).unwrap();
                 rng.sample(d)
             })
         }
@@ -8,4 +8,10 @@ fn main() {
     if version().unwrap() >= Version::parse("1.27.0").unwrap() {
         println!("cargo:rustc-cfg=rust_1_27");
     }
+    if version().unwrap() >= Version::parse("1.28.0").unwrap() {
+        println!("cargo:rustc-cfg=rust_1_28");
+    }
+    if version().unwrap() >= Version::parse("1.51.0").unwrap() {
+        println!("cargo:rustc-cfg=rust_1_51");
+    }
 }
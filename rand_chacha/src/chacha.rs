@@ -67,6 +67,7 @@ const STATE_WORDS: usize = 16;
 /// [`BlockRng`]: ../rand_core/block/struct.BlockRng.html
 /// [`RngCore`]: ../rand_core/trait.RngCore.html
 #[derive(Clone, Debug)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
 pub struct ChaChaRng(BlockRng<ChaChaCore>);
 
 impl RngCore for ChaChaRng {
@@ -89,6 +90,13 @@ impl RngCore for ChaChaRng {
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
         self.0.try_fill_bytes(dest)
     }
+
+    /// Skip ahead in constant time, by moving the word position.
+    #[cfg(rust_1_26)]
+    fn discard_u32(&mut self, n: u64) {
+        let pos = self.get_word_pos();
+        self.set_word_pos(pos.wrapping_add(n as u128));
+    }
 }
 
 impl SeedableRng for ChaChaRng {
@@ -179,6 +187,7 @@ impl ChaChaRng {
 
 /// The core of `ChaChaRng`, used with `BlockRng`.
 #[derive(Clone)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
 pub struct ChaChaCore {
     state: [u32; STATE_WORDS],
 }
@@ -446,4 +455,41 @@ mod test {
             assert_eq!(rng.next_u32(), clone.next_u32());
         }
     }
+
+    #[test]
+    fn test_chacha_discard() {
+        let seed = [0,0,0,0, 1,0,0,0, 2,0,0,0, 3,0,0,0, 4,0,0,0, 5,0,0,0, 6,0,0,0, 7,0,0,0];
+        let mut rng = ChaChaRng::from_seed(seed);
+        let mut clone = rng.clone();
+        // Start part way through a block, and cross block boundaries.
+        for &n in [3u64, 0, 16, 29, 1000].iter() {
+            rng.discard_u32(n);
+            for _ in 0..n {
+                clone.next_u32();
+            }
+            assert_eq!(rng.next_u32(), clone.next_u32());
+        }
+    }
+
+    #[test]
+    #[cfg(feature="serde1")]
+    fn test_chacha_serde() {
+        use bincode;
+        use std::vec::Vec;
+
+        let seed = [0,0,0,0, 1,0,0,0, 2,0,0,0, 3,0,0,0, 4,0,0,0, 5,0,0,0, 6,0,0,0, 7,0,0,0];
+        let mut rng = ChaChaRng::from_seed(seed);
+        rng.set_stream(3);
+        // Save part way through a block of results.
+        for _ in 0..5 {
+            rng.next_u32();
+        }
+        let saved = bincode::serialize(&rng).expect("Could not serialize");
+
+        let expected: Vec<u32> = (0..100).map(|_| rng.next_u32()).collect();
+        let mut restored: ChaChaRng = bincode::deserialize(&saved).expect("Could not deserialize");
+        for &x in expected.iter() {
+            assert_eq!(restored.next_u32(), x);
+        }
+    }
 }
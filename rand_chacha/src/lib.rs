@@ -16,10 +16,17 @@
 #![deny(missing_debug_implementations)]
 #![doc(test(attr(allow(unused_variables), deny(warnings))))]
 
-#![no_std]
+#![cfg_attr(not(all(feature="serde1", test)), no_std)]
 
 extern crate rand_core;
 
+#[cfg(feature="serde1")] extern crate serde;
+#[cfg(feature="serde1")] #[macro_use] extern crate serde_derive;
+
+// To test serialization we need bincode and the standard library
+#[cfg(all(feature="serde1", test))] extern crate bincode;
+#[cfg(all(feature="serde1", test))] extern crate std as core;
+
 mod chacha;
 
 pub use chacha::{ChaChaRng, ChaChaCore};
@@ -17,7 +17,8 @@
 //! generators and external random-number sources.
 //! 
 //! [`SeedableRng`] is an extension trait for construction from fixed seeds and
-//! other random number generators.
+//! other random number generators. [`SeedBuilder`] combines weak sources of
+//! entropy into a seed.
 //! 
 //! [`Error`] is provided for error-handling. It is safe to use in `no_std`
 //! environments.
@@ -28,6 +29,7 @@
 //! [rand]: https://crates.io/crates/rand
 //! [`RngCore`]: trait.RngCore.html
 //! [`SeedableRng`]: trait.SeedableRng.html
+//! [`SeedBuilder`]: struct.SeedBuilder.html
 //! [`Error`]: struct.Error.html
 //! [`impls`]: impls/index.html
 //! [`le`]: le/index.html
@@ -56,9 +58,11 @@ use core::ptr::copy_nonoverlapping;
 #[cfg(all(feature="alloc", not(feature="std")))] use alloc::boxed::Box;
 
 pub use error::{ErrorKind, Error};
+pub use seed_builder::SeedBuilder;
 
 
 mod error;
+mod seed_builder;
 pub mod block;
 pub mod impls;
 pub mod le;
@@ -190,6 +194,19 @@ pub trait RngCore {
     /// 
     /// [`fill_bytes`]: trait.RngCore.html#method.fill_bytes
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error>;
+
+    /// Advance the generator as if [`next_u32`] had been called `n` times.
+    ///
+    /// The default implementation calls `next_u32` in a loop. Generators
+    /// which can skip ahead directly, such as counter-based generators,
+    /// should override this.
+    ///
+    /// [`next_u32`]: trait.RngCore.html#tymethod.next_u32
+    fn discard_u32(&mut self, n: u64) {
+        for _ in 0..n {
+            self.next_u32();
+        }
+    }
 }
 
 /// A marker trait used to indicate that an [`RngCore`] or [`BlockRngCore`]
@@ -337,6 +354,29 @@ pub trait SeedableRng: Sized {
         Self::from_seed(seed)
     }
     
+    /// Create a new PRNG from a string, for example a human-readable
+    /// experiment name kept in a configuration file.
+    /// 
+    /// The string is hashed to a `u64` with the 64-bit FNV-1a hash of its
+    /// UTF-8 bytes, which is passed to [`seed_from_u64`]. This is not a
+    /// cryptographic hash and there are only 2^64 distinct seeds, so this **is
+    /// not suitable for cryptography** either. The hash is fixed: the same
+    /// string yields the same generator in future versions, and changing it
+    /// would be a value-breaking change.
+    /// 
+    /// [`seed_from_u64`]: #method.seed_from_u64
+    fn seed_from_str(s: &str) -> Self {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+        
+        let mut hash = FNV_OFFSET_BASIS;
+        for &byte in s.as_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        Self::seed_from_u64(hash)
+    }
+    
     /// Create a new PRNG seeded from another `Rng`.
     ///
     /// This is the recommended way to initialize PRNGs with fresh entropy. The
@@ -399,6 +439,11 @@ impl<'a, R: RngCore + ?Sized> RngCore for &'a mut R {
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
         (**self).try_fill_bytes(dest)
     }
+
+    #[inline(always)]
+    fn discard_u32(&mut self, n: u64) {
+        (**self).discard_u32(n)
+    }
 }
 
 // Implement `RngCore` for boxed references to an `RngCore`.
@@ -425,6 +470,11 @@ impl<R: RngCore + ?Sized> RngCore for Box<R> {
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
         (**self).try_fill_bytes(dest)
     }
+
+    #[inline(always)]
+    fn discard_u32(&mut self, n: u64) {
+        (**self).discard_u32(n)
+    }
 }
 
 #[cfg(feature="std")]
@@ -483,4 +533,31 @@ mod test {
         // value-breakage test:
         assert_eq!(results[0], 5029875928683246316);
     }
+    
+    #[test]
+    fn test_seed_from_str() {
+        struct SeedableNum(u64);
+        impl SeedableRng for SeedableNum {
+            type Seed = [u8; 8];
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut x = [0u64; 1];
+                le::read_u64_into(&seed, &mut x);
+                SeedableNum(x[0])
+            }
+        }
+        
+        let seed = |s| SeedableNum::seed_from_str(s).0;
+        assert_eq!(seed("experiment-42"), seed("experiment-42"));
+        let names = ["", "a", "b", "experiment-42", "experiment-43", "Experiment-42"];
+        for (i1, n1) in names.iter().enumerate() {
+            for n2 in names[i1 + 1..].iter() {
+                assert!((seed(n1) ^ seed(n2)).count_ones() >= 16, "{} {}", n1, n2);
+            }
+        }
+        
+        // The empty string hashes to the FNV offset basis.
+        assert_eq!(seed(""), SeedableNum::seed_from_u64(0xcbf29ce484222325).0);
+        // value-breakage test:
+        assert_eq!(seed("experiment-42"), 11923576760639151645);
+    }
 }
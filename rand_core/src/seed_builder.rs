@@ -0,0 +1,216 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Combining several weak sources of entropy into a seed.
+
+use core::default::Default;
+use core::convert::AsMut;
+use SeedableRng;
+
+const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// Keys distinguishing the words of `u64` and byte contributions.
+const U64_KEY: u64 = 0x5eed_0000_0000_0064;
+const BYTES_KEY: u64 = 0x5eed_0000_0000_0b17;
+
+/// The SplitMix64 output function, a bijective mixer of all 64 bits.
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// A builder mixing several contributions of weak entropy, such as
+/// timestamps, process IDs or user input, into a seed.
+///
+/// This is intended for environments without a good source of entropy like
+/// `OsRng`: each contribution may only be slightly unpredictable, but their
+/// combination can be good enough to seed a non-cryptographic PRNG.
+/// Contributions are added with [`add_bytes`] and [`add_u64`], and absorbed
+/// into a 256-bit state with SplitMix64 mixing. Both their contents and their
+/// order affect the seed, and so do the boundaries between byte strings. The
+/// seed itself is squeezed out of the state with [`fill_seed`] or [`build`].
+///
+/// The result is only as unpredictable as the combined contributions, and the
+/// mixing is not a cryptographic hash, so this **is not suitable for
+/// cryptography**. The mixing is fixed: the same contributions yield the same
+/// seed in future versions, and changing it would be a value-breaking change.
+///
+/// # Example
+///
+/// ```
+/// use rand_core::{SeedableRng, SeedBuilder};
+/// # #[allow(dead_code)] struct MyRng([u8; 16]);
+/// # impl SeedableRng for MyRng {
+/// #     type Seed = [u8; 16];
+/// #     fn from_seed(seed: [u8; 16]) -> MyRng { MyRng(seed) }
+/// # }
+/// # let (timestamp, pid) = (1_541_000_000u64, 4242u64);
+///
+/// let rng: MyRng = SeedBuilder::new()
+///     .add_u64(timestamp)
+///     .add_u64(pid)
+///     .add_bytes(b"hostname")
+///     .build();
+/// ```
+///
+/// [`add_bytes`]: #method.add_bytes
+/// [`add_u64`]: #method.add_u64
+/// [`fill_seed`]: #method.fill_seed
+/// [`build`]: #method.build
+#[derive(Clone, Debug)]
+pub struct SeedBuilder {
+    state: [u64; 4],
+    /// Number of words absorbed so far.
+    words: u64,
+}
+
+impl SeedBuilder {
+    /// Construct a new `SeedBuilder` without any contributions.
+    pub fn new() -> SeedBuilder {
+        let mut state = [0; 4];
+        for (i, lane) in state.iter_mut().enumerate() {
+            *lane = GOLDEN_GAMMA.wrapping_mul(i as u64 + 1);
+        }
+        SeedBuilder { state, words: 0 }
+    }
+
+    /// Mix one word into the next lane of the state, chained with the lane
+    /// before it so that the order of the words matters. The `key` tells
+    /// the kinds of contributions apart.
+    fn absorb(&mut self, word: u64, key: u64) {
+        let i = (self.words % 4) as usize;
+        let previous = self.state[(i + 3) % 4];
+        self.state[i] = mix(self.state[i] ^ word ^ previous.rotate_left(23)).wrapping_add(key);
+        self.words = self.words.wrapping_add(1);
+    }
+
+    /// Add a contribution of arbitrary bytes.
+    ///
+    /// The length is mixed in as well, so that for example `b"ab"` followed
+    /// by `b"c"` and `b"a"` followed by `b"bc"` give different seeds.
+    pub fn add_bytes(&mut self, bytes: &[u8]) -> &mut SeedBuilder {
+        self.absorb(bytes.len() as u64, BYTES_KEY);
+        for chunk in bytes.chunks(8) {
+            let mut word = 0u64;
+            for (i, &byte) in chunk.iter().enumerate() {
+                word |= (byte as u64) << (8 * i);
+            }
+            self.absorb(word, BYTES_KEY);
+        }
+        self
+    }
+
+    /// Add a contribution of a single `u64`, such as a timestamp.
+    pub fn add_u64(&mut self, x: u64) -> &mut SeedBuilder {
+        self.absorb(x, U64_KEY);
+        self
+    }
+
+    /// Fill `seed` with bytes derived from all contributions so far.
+    ///
+    /// Every byte depends on every contribution. Further contributions may
+    /// be added afterwards.
+    pub fn fill_seed(&self, seed: &mut [u8]) {
+        // Finish by absorbing the number of words, so that trailing
+        // contributions of zero still change the seed.
+        let mut builder = self.clone();
+        builder.absorb(self.words, 0);
+        let state = builder.state;
+        let mut h = 0;
+        for (i, lane) in state.iter().enumerate() {
+            h = mix(h ^ lane ^ (i as u64 + 1).wrapping_mul(GOLDEN_GAMMA));
+        }
+        for (j, chunk) in seed.chunks_mut(8).enumerate() {
+            let x = mix(h ^ state[j % 4] ^ (j as u64).wrapping_mul(GOLDEN_GAMMA));
+            h = mix(h.wrapping_add(GOLDEN_GAMMA));
+            for (i, byte) in chunk.iter_mut().enumerate() {
+                *byte = (x >> (8 * i)) as u8;
+            }
+        }
+    }
+
+    /// Create a new PRNG from the seed given by all contributions so far.
+    pub fn build<R: SeedableRng>(&self) -> R {
+        let mut seed = R::Seed::default();
+        self.fill_seed(seed.as_mut());
+        R::from_seed(seed)
+    }
+}
+
+impl Default for SeedBuilder {
+    fn default() -> SeedBuilder {
+        SeedBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SeedBuilder;
+
+    fn seed(builder: &SeedBuilder) -> [u8; 32] {
+        let mut seed = [0; 32];
+        builder.fill_seed(&mut seed);
+        seed
+    }
+
+    #[test]
+    fn test_seed_builder_contributions() {
+        let seeds = [
+            seed(&SeedBuilder::new()),
+            seed(SeedBuilder::new().add_u64(0)),
+            seed(SeedBuilder::new().add_u64(0).add_u64(0)),
+            seed(SeedBuilder::new().add_u64(1)),
+            seed(SeedBuilder::new().add_u64(1).add_u64(2)),
+            seed(SeedBuilder::new().add_u64(2).add_u64(1)),
+            seed(SeedBuilder::new().add_bytes(b"")),
+            seed(SeedBuilder::new().add_bytes(b"abc")),
+            seed(SeedBuilder::new().add_bytes(b"ab").add_bytes(b"c")),
+            seed(SeedBuilder::new().add_bytes(b"a").add_bytes(b"bc")),
+            seed(SeedBuilder::new().add_bytes(b"abc").add_u64(7)),
+            seed(SeedBuilder::new().add_u64(7).add_bytes(b"abc")),
+            seed(SeedBuilder::new().add_bytes(&[0; 9])),
+            seed(SeedBuilder::new().add_bytes(&[0; 16])),
+        ];
+        for (i1, s1) in seeds.iter().enumerate() {
+            for (i2, s2) in seeds[i1 + 1..].iter().enumerate() {
+                let diff: u32 = s1.iter().zip(s2.iter())
+                    .map(|(a, b)| (a ^ b).count_ones()).sum();
+                // About 128 of the 256 bits should differ.
+                assert!(diff > 80, "{} {}: {}", i1, i1 + 1 + i2, diff);
+            }
+        }
+
+        // The same contributions give the same seed, and adding more changes it.
+        let mut builder = SeedBuilder::new();
+        builder.add_u64(1_541_000_000).add_bytes(b"pid 4242");
+        assert_eq!(seed(&builder), seed(&builder.clone()));
+        let before = seed(&builder);
+        builder.add_u64(0);
+        assert!(seed(&builder) != before);
+    }
+
+    #[test]
+    fn test_seed_builder_lengths() {
+        let mut builder = SeedBuilder::new();
+        builder.add_u64(5);
+        let mut short = [0u8; 5];
+        let mut long = [0u8; 37];
+        builder.fill_seed(&mut short);
+        builder.fill_seed(&mut long);
+        // A shorter seed is a prefix of a longer one.
+        assert_eq!(short, long[..5]);
+        assert!(long[32..].iter().any(|&b| b != 0));
+        assert!(long[..8] != long[8..16]);
+
+        // value-breakage test:
+        let mut x = [0u8; 8];
+        SeedBuilder::new().add_u64(42).add_bytes(b"rand").fill_seed(&mut x);
+        assert_eq!(x, [177, 199, 88, 86, 172, 16, 168, 173]);
+    }
+}
@@ -67,6 +67,7 @@ const SEED_WORDS: usize = 8; // 128 bit key followed by 128 bit iv
 /// [`BlockRng`]: ../rand_core/block/struct.BlockRng.html
 /// [`RngCore`]: ../rand_core/trait.RngCore.html
 #[derive(Clone, Debug)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
 pub struct Hc128Rng(BlockRng<Hc128Core>);
 
 impl RngCore for Hc128Rng {
@@ -105,7 +106,9 @@ impl CryptoRng for Hc128Rng {}
 
 /// The core of `Hc128Rng`, used with `BlockRng`.
 #[derive(Clone)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
 pub struct Hc128Core {
+    #[cfg_attr(feature="serde1", serde(with="table_serde"))]
     t: [u32; 1024],
     counter1024: usize,
 }
@@ -332,6 +335,50 @@ impl SeedableRng for Hc128Core {
 
 impl CryptoRng for Hc128Core {}
 
+// Arrays with more than 32 elements do not implement `Serialize` or
+// `Deserialize`, so the table is serialized as a tuple by hand.
+#[cfg(feature="serde1")]
+mod table_serde {
+    use serde::{Deserializer, Serializer};
+    use serde::de::{self, SeqAccess, Visitor};
+    use core::fmt;
+
+    const LEN: usize = 1024;
+
+    pub fn serialize<S: Serializer>(table: &[u32; LEN], ser: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+        let mut seq = ser.serialize_tuple(LEN)?;
+        for e in table.iter() {
+            seq.serialize_element(e)?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<[u32; LEN], D::Error> {
+        struct TableVisitor;
+        impl<'de> Visitor<'de> for TableVisitor {
+            type Value = [u32; LEN];
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("HC-128 state table")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u32; LEN], A::Error> {
+                let mut out = [0; LEN];
+                for i in 0..LEN {
+                    match seq.next_element()? {
+                        Some(val) => out[i] = val,
+                        None => return Err(de::Error::invalid_length(i, &self)),
+                    }
+                }
+                Ok(out)
+            }
+        }
+
+        de.deserialize_tuple(LEN, TableVisitor)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ::rand_core::{RngCore, SeedableRng};
@@ -459,4 +506,28 @@ mod test {
             assert_eq!(rng1.next_u32(), rng2.next_u32());
         }
     }
+
+    #[test]
+    #[cfg(feature="serde1")]
+    fn test_hc128_serde() {
+        use bincode;
+        use std::vec::Vec;
+
+        let seed = [55,0,0,0, 44,0,0,0, 33,0,0,0, 22,0,0,0,
+                    55,0,0,0, 44,0,0,0, 33,0,0,0, 22,0,0,0];
+        let mut rng = Hc128Rng::from_seed(seed);
+        // Save part way through a block of results.
+        for _ in 0..7 {
+            rng.next_u32();
+        }
+        let saved = bincode::serialize(&rng).expect("Could not serialize");
+
+        let expected: Vec<u32> = (0..1100).map(|_| rng.next_u32()).collect();
+        let mut restored: Hc128Rng = bincode::deserialize(&saved).expect("Could not deserialize");
+        // More than the 1024 words of the table.
+        for &x in expected.iter() {
+            assert_eq!(restored.next_u32(), x);
+        }
+        assert!(bincode::deserialize::<Hc128Rng>(&saved[..100]).is_err());
+    }
 }
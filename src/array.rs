@@ -0,0 +1,71 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arrays of random values, using const generics.
+//!
+//! This module is only compiled with Rust 1.51 or later: older compilers do
+//! not parse const generic parameters, even in items which are configured
+//! out, so they must not see this file at all.
+
+use Rng;
+use distributions::{Distribution, Standard};
+
+/// An extension trait of [`Rng`] generating arrays of random values.
+///
+/// This is implemented for all generators, and requires Rust 1.51 or later.
+///
+/// [`Rng`]: trait.Rng.html
+pub trait GenArray: Rng {
+    /// Return an array of random values supporting the [`Standard`]
+    /// distribution.
+    ///
+    /// The array is filled in place, without allocating and without
+    /// initializing it with default values first.
+    ///
+    /// [`Standard`]: distributions/struct.Standard.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, GenArray};
+    ///
+    /// let features = thread_rng().gen_array::<f64, 8>();
+    /// assert!(features.iter().all(|&x| 0.0 <= x && x < 1.0));
+    /// ```
+    fn gen_array<T, const N: usize>(&mut self) -> [T; N] where Standard: Distribution<T> {
+        use core::mem::MaybeUninit;
+        let mut array = MaybeUninit::<[T; N]>::uninit();
+        let ptr = array.as_mut_ptr() as *mut T;
+        for i in 0..N {
+            // Safe since `i < N`. Should sampling panic, the elements written
+            // so far are leaked, which is safe as well.
+            unsafe { ptr.add(i).write(self.gen()); }
+        }
+        // All `N` elements have been written.
+        unsafe { array.assume_init() }
+    }
+}
+
+impl<R: Rng + ?Sized> GenArray for R {}
+
+#[cfg(test)]
+mod test {
+    use super::GenArray;
+
+    #[test]
+    fn test_gen_array() {
+        let a = ::test::rng(114).gen_array::<f64, 8>();
+        let b = ::test::rng(114).gen_array::<f64, 8>();
+        assert_eq!(a, b);
+        assert!(a.iter().all(|&x| 0.0 <= x && x < 1.0));
+        assert!(a.iter().any(|&x| x != a[0]));
+
+        let empty: [u32; 0] = ::test::rng(115).gen_array();
+        assert_eq!(empty, []);
+    }
+}
@@ -136,6 +136,11 @@ impl RngCore for ChaChaRng {
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
         self.0.try_fill_bytes(dest)
     }
+
+    #[inline(always)]
+    fn discard_u32(&mut self, n: u64) {
+        self.0.discard_u32(n)
+    }
 }
 
 impl SeedableRng for ChaChaRng {
@@ -0,0 +1,83 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Benford's law for leading digits.
+
+use Rng;
+use distributions::Distribution;
+use core::f64::consts::{LN_2, LOG10_E};
+
+/// The distribution of leading decimal digits according to Benford's law.
+///
+/// Samples are digits `d` in `1..=9`, drawn with probability
+/// `log10(1 + 1/d)`. Digit `1` occurs about 30.1% of the time, digit `9`
+/// only about 4.6% of the time.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Benford, Distribution};
+///
+/// let digit = Benford.sample(&mut rand::thread_rng());
+/// println!("{} is a leading digit", digit);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Benford;
+
+/// `CDF[i] = log10(i + 2)`, the probability of a leading digit `<= i + 1`.
+const CDF: [f64; 8] = [
+    // `log10(2)`, written as a product to avoid an approximate literal of
+    // `LOG10_2`, which is not in `core` on older Rust versions.
+    LN_2 * LOG10_E,
+    0.47712125471966244,
+    0.6020599913279624,
+    0.6989700043360189,
+    0.7781512503836436,
+    0.8450980400142568,
+    0.9030899869919435,
+    0.9542425094393249,
+];
+
+impl Distribution<u8> for Benford {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        let u: f64 = rng.gen();
+        1 + CDF.iter().take_while(|&&c| c <= u).count() as u8
+    }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        Some(64.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::Distribution;
+    use super::Benford;
+
+    #[test]
+    fn test_benford() {
+        let mut rng = ::test::rng(381);
+        let n = 100_000;
+        let mut counts = [0u32; 10];
+        for _ in 0..n {
+            let d = Benford.sample(&mut rng);
+            assert!(d >= 1 && d <= 9);
+            counts[d as usize] += 1;
+        }
+        assert_eq!(counts[0], 0);
+
+        let freq_one = counts[1] as f64 / n as f64;
+        assert!((freq_one - 0.301).abs() < 0.005);
+
+        for d in 1..10 {
+            let expected = (1.0 + 1.0 / d as f64).log10();
+            let freq = counts[d] as f64 / n as f64;
+            assert!((freq - expected).abs() < 0.005);
+        }
+    }
+}
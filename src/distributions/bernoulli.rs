@@ -9,7 +9,8 @@
 //! The Bernoulli distribution.
 
 use Rng;
-use distributions::Distribution;
+use distributions::{Distribution, DistError};
+use core::fmt;
 
 /// The Bernoulli distribution.
 ///
@@ -20,7 +21,7 @@ use distributions::Distribution;
 /// ```rust
 /// use rand::distributions::{Bernoulli, Distribution};
 ///
-/// let d = Bernoulli::new(0.3);
+/// let d = Bernoulli::new(0.3).unwrap();
 /// let v = d.sample(&mut rand::thread_rng());
 /// println!("{} is from a Bernoulli distribution", v);
 /// ```
@@ -64,9 +65,7 @@ const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
 impl Bernoulli {
     /// Construct a new `Bernoulli` with the given probability of success `p`.
     ///
-    /// # Panics
-    ///
-    /// If `p < 0` or `p > 1`.
+    /// Returns an error if `p < 0`, `p > 1` or `p` is NaN.
     ///
     /// # Precision
     ///
@@ -77,12 +76,23 @@ impl Bernoulli {
     /// a multiple of 2<sup>-64</sup>. (Note that not all multiples of
     /// 2<sup>-64</sup> in `[0, 1]` can be represented as a `f64`.)
     #[inline]
-    pub fn new(p: f64) -> Bernoulli {
-        if p < 0.0 || p >= 1.0 {
-            if p == 1.0 { return Bernoulli { p_int: ALWAYS_TRUE } }
-            panic!("Bernoulli::new not called with 0.0 <= p <= 1.0");
+    pub fn new(p: f64) -> Result<Bernoulli, DistError> {
+        if !(p >= 0.0 && p < 1.0) {
+            if p == 1.0 { return Ok(Bernoulli { p_int: ALWAYS_TRUE }) }
+            return Err(DistError::ProbabilityOutOfRange);
         }
-        Bernoulli { p_int: (p * SCALE) as u64 }
+        Ok(Bernoulli { p_int: (p * SCALE) as u64 })
+    }
+
+    /// Construct a new `Bernoulli` with the given probability of success `p`,
+    /// like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0`, `p > 1` or `p` is NaN.
+    #[inline]
+    pub fn new_unchecked(p: f64) -> Bernoulli {
+        Bernoulli::new(p).expect("Bernoulli::new_unchecked called with invalid p")
     }
 
     /// Construct a new `Bernoulli` with the probability of success of
@@ -92,21 +102,59 @@ impl Bernoulli {
     /// If `numerator == denominator` then the returned `Bernoulli` will always
     /// return `true`. If `numerator == 0` it will always return `false`.
     ///
+    /// Returns an error if `denominator == 0` or `numerator > denominator`.
+    #[inline]
+    pub fn from_ratio(numerator: u32, denominator: u32)
+        -> Result<Bernoulli, DistError>
+    {
+        if denominator == 0 || numerator > denominator {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        if numerator == denominator {
+            return Ok(Bernoulli { p_int: ::core::u64::MAX })
+        }
+        let p_int = ((numerator as f64 / denominator as f64) * SCALE) as u64;
+        Ok(Bernoulli { p_int })
+    }
+
+    /// Construct a new `Bernoulli` with the probability of success of
+    /// `numerator`-in-`denominator`, like [`from_ratio`](#method.from_ratio).
+    ///
     /// # Panics
     ///
     /// If `denominator == 0` or `numerator > denominator`.
+    #[inline]
+    pub fn from_ratio_unchecked(numerator: u32, denominator: u32) -> Bernoulli {
+        Bernoulli::from_ratio(numerator, denominator)
+            .expect("Bernoulli::from_ratio_unchecked called with invalid ratio")
+    }
+
+    /// Return `if_true` with the probability of success, and `if_false`
+    /// otherwise.
+    ///
+    /// # Example
     ///
+    /// ```rust
+    /// use rand::distributions::WeightedBool;
+    ///
+    /// let coin = WeightedBool::new(0.9).unwrap();
+    /// let side = coin.select(&mut rand::thread_rng(), &"heads", &"tails");
+    /// println!("{}", side);
+    /// ```
     #[inline]
-    pub fn from_ratio(numerator: u32, denominator: u32) -> Bernoulli {
-        assert!(numerator <= denominator);
-        if numerator == denominator {
-            return Bernoulli { p_int: ::core::u64::MAX }
-        }
-        let p_int = ((numerator as f64 / denominator as f64) * SCALE) as u64;
-        Bernoulli { p_int }
+    pub fn select<'a, T, R: Rng + ?Sized>(&self, rng: &mut R, if_true: &'a T, if_false: &'a T)
+        -> &'a T
+    {
+        if self.sample(rng) { if_true } else { if_false }
     }
 }
 
+/// A two-outcome weighted choice; this is another name for [`Bernoulli`],
+/// for code which mostly uses [`select`](struct.Bernoulli.html#method.select).
+///
+/// [`Bernoulli`]: struct.Bernoulli.html
+pub type WeightedBool = Bernoulli;
+
 impl Distribution<bool> for Bernoulli {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
@@ -115,19 +163,30 @@ impl Distribution<bool> for Bernoulli {
         let v: u64 = rng.gen();
         v < self.p_int
     }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        Some(if self.p_int == ALWAYS_TRUE { 0.0 } else { 64.0 })
+    }
+}
+
+impl fmt::Display for Bernoulli {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p = if self.p_int == ALWAYS_TRUE { 1.0 } else { self.p_int as f64 / SCALE };
+        write!(f, "Bernoulli(p={})", p)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use Rng;
-    use distributions::Distribution;
-    use super::Bernoulli;
+    use distributions::{Distribution, DistError};
+    use super::{Bernoulli, WeightedBool};
 
     #[test]
     fn test_trivial() {
         let mut r = ::test::rng(1);
-        let always_false = Bernoulli::new(0.0);
-        let always_true = Bernoulli::new(1.0);
+        let always_false = Bernoulli::new(0.0).unwrap();
+        let always_true = Bernoulli::new(1.0).unwrap();
         for _ in 0..5 {
             assert_eq!(r.sample::<bool, _>(&always_false), false);
             assert_eq!(r.sample::<bool, _>(&always_true), true);
@@ -141,8 +200,8 @@ mod test {
         const P: f64 = 0.3;
         const NUM: u32 = 3;
         const DENOM: u32 = 10;
-        let d1 = Bernoulli::new(P);
-        let d2 = Bernoulli::from_ratio(NUM, DENOM);
+        let d1 = Bernoulli::new(P).unwrap();
+        let d2 = Bernoulli::from_ratio(NUM, DENOM).unwrap();
         const N: u32 = 100_000;
 
         let mut sum1: u32 = 0;
@@ -162,4 +221,39 @@ mod test {
         let avg2 = (sum2 as f64) / (N as f64);
         assert!((avg2 - (NUM as f64)/(DENOM as f64)).abs() < 5e-3);
     }
+
+    #[test]
+    fn test_select() {
+        let mut rng = ::test::rng(180);
+        let choice = WeightedBool::new(0.2).unwrap();
+        let (a, b) = ('a', 'b');
+        let n = 100_000;
+        let mut count_a = 0;
+        for _ in 0..n {
+            let x = choice.select(&mut rng, &a, &b);
+            assert!(*x == 'a' || *x == 'b');
+            if *x == 'a' { count_a += 1; }
+        }
+        assert!((count_a as f64 / n as f64 - 0.2).abs() < 5e-3);
+
+        let always = WeightedBool::new(1.0).unwrap();
+        let never = WeightedBool::new(0.0).unwrap();
+        for _ in 0..10 {
+            assert_eq!(*always.select(&mut rng, &1, &2), 1);
+            assert_eq!(*never.select(&mut rng, &1, &2), 2);
+        }
+        assert_eq!(WeightedBool::new(1.5).unwrap_err(), DistError::ProbabilityOutOfRange);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Bernoulli::new(-0.1).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Bernoulli::new(1.1).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Bernoulli::new(::core::f64::NAN).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(Bernoulli::from_ratio(3, 2).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(Bernoulli::from_ratio(0, 0).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+    }
 }
@@ -0,0 +1,106 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The beta-binomial distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Beta, Binomial};
+use std::fmt;
+
+/// The beta-binomial distribution `BetaBinomial(n, alpha, beta)`.
+///
+/// Each sample first draws a probability of success `p ~ Beta(alpha, beta)`,
+/// then the number of successes in `n` trials with probability `p`. Compared
+/// to a [`Binomial`] distribution with the same mean, the counts are
+/// overdispersed. With a Beta posterior for `p`, this is the predictive
+/// distribution of future successes; see [`Binomial::from_beta_posterior`].
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{BetaBinomial, Distribution};
+///
+/// let bb = BetaBinomial::new(10, 2.0, 3.0).unwrap();
+/// let v = bb.sample(&mut rand::thread_rng());
+/// assert!(v <= 10);
+/// ```
+///
+/// [`Binomial`]: struct.Binomial.html
+/// [`Binomial::from_beta_posterior`]: struct.Binomial.html#method.from_beta_posterior
+#[derive(Clone, Copy, Debug)]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+    beta_distr: Beta,
+}
+
+impl BetaBinomial {
+    /// Construct a new `BetaBinomial` with `n` trials and the Beta parameters
+    /// `alpha` and `beta`.
+    ///
+    /// Returns an error if `alpha <= 0` or `beta <= 0`.
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Result<BetaBinomial, DistError> {
+        Ok(BetaBinomial { n, alpha, beta, beta_distr: Beta::new(alpha, beta)? })
+    }
+
+    /// Construct a new `BetaBinomial`, like [`new`](#method.new).
+    ///
+    /// Panics if `alpha <= 0` or `beta <= 0`.
+    pub fn new_unchecked(n: u64, alpha: f64, beta: f64) -> BetaBinomial {
+        BetaBinomial::new(n, alpha, beta)
+            .expect("BetaBinomial::new_unchecked called with `alpha` or `beta` <= 0")
+    }
+
+    /// The mean of the distribution, `n alpha / (alpha + beta)`.
+    pub fn mean(&self) -> f64 {
+        self.n as f64 * self.alpha / (self.alpha + self.beta)
+    }
+}
+
+impl Distribution<u64> for BetaBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let p = self.beta_distr.sample(rng);
+        Binomial::sample_with_p(self.n, p, rng)
+    }
+}
+
+impl fmt::Display for BetaBinomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BetaBinomial(n={}, alpha={}, beta={})", self.n, self.alpha, self.beta)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::BetaBinomial;
+
+    #[test]
+    fn test_beta_binomial_moments() {
+        let mut rng = ::test::rng(193);
+        let (n, alpha, beta) = (20, 2.0, 3.0);
+        let distr = BetaBinomial::new(n, alpha, beta).unwrap();
+        let samples: Vec<f64> = (0..100_000).map(|_| {
+            let k = distr.sample(&mut rng);
+            assert!(k <= n);
+            k as f64
+        }).collect();
+        let n = n as f64;
+        let s = alpha + beta;
+        let var = n * alpha * beta * (s + n) / (s * s * (s + 1.0));
+        ::test::assert_moments(&samples, distr.mean(), var, 0.03);
+        assert_eq!(distr.mean(), 8.0);
+    }
+
+    #[test]
+    fn test_beta_binomial_invalid() {
+        assert_eq!(BetaBinomial::new(5, 0.0, 1.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(BetaBinomial::new(5, 1.0, -1.0).unwrap_err(), DistError::ShapeNotPositive);
+    }
+}
@@ -10,20 +10,27 @@
 //! The binomial distribution.
 
 use Rng;
-use distributions::{Distribution, Bernoulli, Cauchy};
-use distributions::utils::log_gamma;
+use distributions::{Distribution, DistError, Bernoulli, BetaBinomial, Cauchy,
+    Moments, StandardNormal, OpenClosed01};
+use distributions::utils::{log_gamma, normal_quantile, rejection_loop};
+use std::f64::{INFINITY, NEG_INFINITY};
+use std::fmt;
 
 /// The binomial distribution `Binomial(n, p)`.
 ///
 /// This distribution has density function:
 /// `f(k) = n!/(k! (n-k)!) p^k (1-p)^(n-k)` for `k >= 0`.
 ///
+/// For `n < 300` and `p = 2^-k` with `k <= 16`, such as `p = 0.5` or
+/// `0.25`, the trials are simulated exactly with random bits, without any
+/// floating-point arithmetic.
+///
 /// # Example
 ///
 /// ```
 /// use rand::distributions::{Binomial, Distribution};
 ///
-/// let bin = Binomial::new(20, 0.3);
+/// let bin = Binomial::new(20, 0.3).unwrap();
 /// let v = bin.sample(&mut rand::thread_rng());
 /// println!("{} is from a binomial distribution", v);
 /// ```
@@ -33,41 +40,541 @@ pub struct Binomial {
     n: u64,
     /// Probability of success.
     p: f64,
+    /// Probability of success as `(numerator, denominator)`, if constructed
+    /// with `from_ratio`.
+    ratio: Option<(u64, u64)>,
 }
 
 impl Binomial {
     /// Construct a new `Binomial` with the given shape parameters `n` (number
     /// of trials) and `p` (probability of success).
     ///
-    /// Panics if `p < 0` or `p > 1`.
-    pub fn new(n: u64, p: f64) -> Binomial {
-        assert!(p >= 0.0, "Binomial::new called with p < 0");
-        assert!(p <= 1.0, "Binomial::new called with p > 1");
-        Binomial { n, p }
+    /// Returns an error if `p < 0`, `p > 1` or `p` is NaN.
+    pub fn new(n: u64, p: f64) -> Result<Binomial, DistError> {
+        if !(p >= 0.0 && p <= 1.0) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        Ok(Binomial { n, p, ratio: None })
     }
-}
 
-impl Distribution<u64> for Binomial {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
-        // Handle these values directly.
+    /// Construct a new `Binomial`, like [`new`](#method.new).
+    ///
+    /// Panics if `p < 0`, `p > 1` or `p` is NaN.
+    pub fn new_unchecked(n: u64, p: f64) -> Binomial {
+        Binomial::new(n, p).expect("Binomial::new_unchecked called with invalid p")
+    }
+
+    /// Construct a new `Binomial` with `n` trials and a probability of
+    /// success of `numerator`-in-`denominator`.
+    ///
+    /// For `n < 300` each trial is then decided by an integer comparison,
+    /// using [`Rng::gen_below`], so samples are exactly reproducible across
+    /// platforms given the same random numbers. Larger `n` use the same
+    /// floating-point algorithm as [`new`](#method.new).
+    ///
+    /// Returns an error unless `0 < numerator < denominator`.
+    ///
+    /// [`Rng::gen_below`]: ../trait.Rng.html#method.gen_below
+    pub fn from_ratio(n: u64, numerator: u64, denominator: u64)
+        -> Result<Binomial, DistError>
+    {
+        if !(0 < numerator && numerator < denominator) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        let p = numerator as f64 / denominator as f64;
+        Ok(Binomial { n, p, ratio: Some((numerator, denominator)) })
+    }
+
+    /// Construct a new `Binomial` with a probability of success of
+    /// `numerator`-in-`denominator`, like [`from_ratio`](#method.from_ratio).
+    ///
+    /// Panics unless `0 < numerator < denominator`.
+    pub fn from_ratio_unchecked(n: u64, numerator: u64, denominator: u64) -> Binomial {
+        Binomial::from_ratio(n, numerator, denominator)
+            .expect("Binomial::from_ratio_unchecked called with invalid ratio")
+    }
+
+    /// Fit a `Binomial` with `n` trials to `samples` by moment matching,
+    /// i.e. with `p = mean(samples) / n`.
+    ///
+    /// The estimate is clamped into the open interval `(0, 1)`, so the fitted
+    /// distribution never degenerates to a constant.
+    ///
+    /// Returns an error if `n == 0` or `samples` is empty, or if any sample
+    /// exceeds `n`.
+    pub fn fit(samples: &[u64], n: u64) -> Result<Binomial, DistError> {
+        if n == 0 || samples.is_empty() {
+            return Err(DistError::SizeTooSmall);
+        }
+        if samples.iter().any(|&k| k > n) {
+            return Err(DistError::SampleOutOfRange);
+        }
+        let sum: f64 = samples.iter().map(|&k| k as f64).sum();
+        let p = sum / samples.len() as f64 / n as f64;
+        let eps = ::std::f64::EPSILON;
+        Binomial::new(n, p.max(eps).min(1.0 - eps))
+    }
+
+    /// The predictive distribution of the successes in `n` future trials,
+    /// after observing `successes` and `failures` in past trials with an
+    /// unknown probability of success.
+    ///
+    /// This starts from a uniform (Laplace) prior for the probability, so the
+    /// posterior is `Beta(successes + 1, failures + 1)` and the result is the
+    /// corresponding [`BetaBinomial`]. Any counts are valid, including no
+    /// observations at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{Binomial, Distribution};
+    ///
+    /// // 18 of 20 past trials succeeded; how many of the next 10 will?
+    /// let predictive = Binomial::from_beta_posterior(10, 18, 2);
+    /// let v = predictive.sample(&mut rand::thread_rng());
+    /// assert!(v <= 10);
+    /// ```
+    ///
+    /// [`BetaBinomial`]: struct.BetaBinomial.html
+    pub fn from_beta_posterior(n: u64, successes: u64, failures: u64) -> BetaBinomial {
+        BetaBinomial::new_unchecked(n, successes as f64 + 1.0, failures as f64 + 1.0)
+    }
+
+    /// The number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// The probability of success `p`.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// The mean of the distribution, `n p`.
+    pub fn mean(&self) -> f64 {
+        self.n as f64 * self.p
+    }
+
+    /// Natural logarithm of the probability mass function: the logarithm of
+    /// the probability of exactly `k` successes.
+    ///
+    /// This is computed in log-space, so it is accurate even when the
+    /// probability itself underflows.
+    pub fn ln_pmf(&self, k: u64) -> f64 {
+        if k > self.n {
+            return ::std::f64::NEG_INFINITY;
+        }
+        // Avoid `0 * ln(0)` for the end points.
+        if self.n == 0 {
+            return 0.0;
+        } else if self.p == 0.0 {
+            return if k == 0 { 0.0 } else { ::std::f64::NEG_INFINITY };
+        } else if self.p == 1.0 {
+            return if k == self.n { 0.0 } else { ::std::f64::NEG_INFINITY };
+        }
+        let (n, k) = (self.n as f64, k as f64);
+        log_gamma(n + 1.0) - log_gamma(k + 1.0) - log_gamma(n - k + 1.0)
+            + k * self.p.ln() + (n - k) * (-self.p).ln_1p()
+    }
+
+    /// Probability mass function: the probability of exactly `k` successes.
+    pub fn pmf(&self, k: u64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    /// Cumulative distribution function: the probability of at most `k`
+    /// successes.
+    ///
+    /// This sums the `pmf` over `0..=k`, and thus takes `O(k)` time.
+    pub fn cdf(&self, k: u64) -> f64 {
+        if k >= self.n {
+            return 1.0;
+        }
+        let sum: f64 = (0..(k + 1)).map(|i| self.pmf(i)).sum();
+        sum.min(1.0)
+    }
+
+    /// Sample `X ~ Binomial(n, p)` conditioned on `X >= k_min`.
+    ///
+    /// This is inverse-CDF sampling restricted to the tail: conceptually, `U`
+    /// is drawn from `[cdf(k_min - 1), 1]` and inverted. Probabilities are
+    /// taken relative to the largest one in the tail, so this remains
+    /// accurate even when `P(X >= k_min)` is too small for naive rejection
+    /// sampling (or even to be represented as an `f64`). The cost is
+    /// `O(n - k_min)` in the worst case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `X >= k_min` is impossible, i.e. if `k_min > n`, or if
+    /// `p == 0` and `k_min > 0`.
+    pub fn sample_conditional_min<R: Rng + ?Sized>(&self, rng: &mut R, k_min: u64) -> u64 {
+        assert!(k_min <= self.n && (k_min == 0 || self.p > 0.0),
+                "Binomial::sample_conditional_min: X >= k_min is impossible");
+        if k_min == 0 {
+            return self.sample(rng);
+        }
+        if self.p == 1.0 {
+            return self.n;
+        }
+
+        // The pmf is unimodal, so the largest term of the tail is at the mode
+        // or at `k_min`.
+        let mode = (((self.n as f64 + 1.0) * self.p).floor() as u64).min(self.n);
+        let ln_max = self.ln_pmf(mode.max(k_min));
+        let mut weights = Vec::new();
+        let mut total = 0.0;
+        // `k == n` ends the loop without computing `n + 1`, which may overflow.
+        let mut k = k_min;
+        loop {
+            let w = (self.ln_pmf(k) - ln_max).exp();
+            // Past the mode the terms only decrease; stop once negligible.
+            if k > mode && w < total * ::std::f64::EPSILON / 2.0 {
+                break;
+            }
+            weights.push(w);
+            total += w;
+            if k == self.n {
+                break;
+            }
+            k += 1;
+        }
+
+        let mut u = rng.gen::<f64>() * total;
+        for (i, w) in weights.iter().enumerate() {
+            if u < *w {
+                return k_min + i as u64;
+            }
+            u -= w;
+        }
+        // Only reachable through rounding errors.
+        k_min + weights.len() as u64 - 1
+    }
+
+    /// The probability that `X ~ Binomial(n, p)` is even, or odd if `even`
+    /// is false.
+    ///
+    /// This has the closed form `(1 ± (1 - 2p)^n) / 2`: expanding
+    /// `((1 - p) + p)^n` and `((1 - p) - p)^n` with the binomial theorem, the
+    /// sum of the two is twice the even terms of the pmf.
+    pub fn parity_probability(&self, even: bool) -> f64 {
+        let d = (1.0 - 2.0 * self.p).powf(self.n as f64);
+        if even { 0.5 * (1.0 + d) } else { 0.5 * (1.0 - d) }
+    }
+
+    /// Sample `X ~ Binomial(n, p)` conditioned on `X` being even, or odd if
+    /// `even` is false.
+    ///
+    /// This is inverse-CDF sampling over the values of the requested
+    /// parity, with probabilities taken relative to the largest one as in
+    /// [`sample_conditional_min`], so it works even when the probability of
+    /// the parity, [`parity_probability`], is tiny. The cost is `O(n)` in
+    /// the worst case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parity is impossible: odd for `n == 0` or `p == 0`, and
+    /// the parity other than that of `n` for `p == 1`.
+    ///
+    /// [`sample_conditional_min`]: #method.sample_conditional_min
+    /// [`parity_probability`]: #method.parity_probability
+    pub fn sample_with_parity<R: Rng + ?Sized>(&self, rng: &mut R, even: bool) -> u64 {
+        let start = if even { 0 } else { 1 };
+        assert!(even || (start <= self.n && self.p > 0.0),
+                "Binomial::sample_with_parity: the parity is impossible");
         if self.p == 0.0 {
             return 0;
-        } else if self.p == 1.0 {
+        }
+        if self.p == 1.0 {
+            assert!(self.n % 2 == start,
+                    "Binomial::sample_with_parity: the parity is impossible");
             return self.n;
         }
-        
+
+        // The pmf is unimodal, so the largest term of the parity is at the
+        // mode or next to it.
+        let mode = (((self.n as f64 + 1.0) * self.p).floor() as u64).min(self.n);
+        let ln_max = [mode.saturating_sub(1), mode, mode.saturating_add(1)].iter()
+            .filter(|&&k| k % 2 == start && k <= self.n)
+            .map(|&k| self.ln_pmf(k))
+            .fold(::std::f64::NEG_INFINITY, f64::max);
+        let mut weights = Vec::new();
+        let mut total = 0.0;
+        // `k` stops before exceeding `n`, so that `k + 2` cannot overflow.
+        let mut k = start;
+        loop {
+            let w = (self.ln_pmf(k) - ln_max).exp();
+            // Past the mode the terms only decrease; stop once negligible.
+            if k > mode && w < total * ::std::f64::EPSILON / 2.0 {
+                break;
+            }
+            weights.push(w);
+            total += w;
+            if self.n - k < 2 {
+                break;
+            }
+            k += 2;
+        }
+
+        let mut u = rng.gen::<f64>() * total;
+        for (i, w) in weights.iter().enumerate() {
+            if u < *w {
+                return start + 2 * i as u64;
+            }
+            u -= w;
+        }
+        // Only reachable through rounding errors.
+        start + 2 * (weights.len() as u64 - 1)
+    }
+
+    /// Sample from a proposal concentrated on the tail `X >= k_min`, and
+    /// return the sample `k` with its importance weight `pmf(k) / q(k)`,
+    /// where `q` is the pmf of the proposal.
+    ///
+    /// The proposal is the exponentially tilted distribution, with pmf
+    /// proportional to `e^(θ k) pmf(k)`. For the binomial distribution this
+    /// is again a binomial distribution, `Binomial(n, p')` with `p' = p e^θ /
+    /// (1 - p + p e^θ)`; `θ` is chosen so that its mean is `k_min`, that is
+    /// `p' = k_min / n`, unless `k_min` is below the mean `n p`, when no
+    /// tilting is needed. The weight is then `(p / p')^k ((1 - p) / (1 -
+    /// p'))^(n - k)`.
+    ///
+    /// The mean of the weight times the indicator `k >= k_min` over many
+    /// samples is an unbiased estimate of `P(X >= k_min)`, with a far smaller
+    /// relative error than counting hits among direct samples when the tail
+    /// probability is tiny.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::Binomial;
+    ///
+    /// let binomial = Binomial::new(1000, 0.01).unwrap();
+    /// let mut rng = rand::thread_rng();
+    /// let trials = 10_000;
+    /// let mut sum = 0.0;
+    /// for _ in 0..trials {
+    ///     let (k, weight) = binomial.importance_sample_tail(&mut rng, 40);
+    ///     if k >= 40 {
+    ///         sum += weight;
+    ///     }
+    /// }
+    /// println!("P(X >= 40) is about {:e}", sum / trials as f64);
+    /// ```
+    pub fn importance_sample_tail<R: Rng + ?Sized>(&self, rng: &mut R, k_min: u64)
+        -> (u64, f64)
+    {
+        let tilted = if self.n == 0 {
+            self.p
+        } else {
+            (k_min as f64 / self.n as f64).min(1.0).max(self.p)
+        };
+        let k = Binomial::new_unchecked(self.n, tilted).sample(rng);
+        // Skip the factors with a zero power, where the ratio may be NaN.
+        let ln_ratio = |count: u64, a: f64, b: f64| {
+            if count == 0 { 0.0 } else { count as f64 * (a.ln() - b.ln()) }
+        };
+        let ln_weight = ln_ratio(k, self.p, tilted)
+            + ln_ratio(self.n - k, 1.0 - self.p, 1.0 - tilted);
+        (k, ln_weight.exp())
+    }
+
+    /// Shannon entropy of the distribution, in nats.
+    ///
+    /// For `n` up to 10000 this sums `-pmf(k) ln pmf(k)` over the support.
+    /// Above that, the Gaussian approximation `0.5 ln(2 π e n p (1 - p))` is
+    /// used instead, which is accurate as long as `n p (1 - p)` is large too.
+    pub fn entropy(&self) -> f64 {
+        use std::f64::consts::{E, PI};
+
+        if self.p == 0.0 || self.p == 1.0 {
+            return 0.0;
+        }
+        if self.n > ENTROPY_EXACT_MAX_N {
+            let variance = self.n as f64 * self.p * (1.0 - self.p);
+            return 0.5 * (2.0 * PI * E * variance).ln();
+        }
+        let mut entropy = 0.0;
+        for k in 0..(self.n + 1) {
+            let ln_pmf = self.ln_pmf(k);
+            if ln_pmf > ::std::f64::NEG_INFINITY {
+                entropy -= ln_pmf.exp() * ln_pmf;
+            }
+        }
+        entropy
+    }
+
+    /// Estimate how many samples are needed for the sample mean to be within
+    /// a relative error `rel_err` of the true mean `n p`, with probability
+    /// `confidence`.
+    ///
+    /// By the central limit theorem, the mean of `m` samples is approximately
+    /// normal with variance `n p (1 - p) / m`; this is solved for `m` in
+    /// closed form. The result is at least one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mean is zero (`n == 0` or `p == 0`), if `rel_err <= 0`,
+    /// or if `confidence` is not in the open range `(0, 1)`.
+    pub fn samples_for_mean_rel_error(&self, rel_err: f64, confidence: f64) -> usize {
+        assert!(self.n > 0 && self.p > 0.0,
+                "Binomial::samples_for_mean_rel_error: mean is zero");
+        assert!(rel_err > 0.0,
+                "Binomial::samples_for_mean_rel_error: rel_err is not positive");
+        assert!(confidence > 0.0 && confidence < 1.0,
+                "Binomial::samples_for_mean_rel_error: confidence is not in (0, 1)");
+        // Two-sided interval: `P(|Z| <= z) = confidence`.
+        let z = normal_quantile(0.5 + confidence / 2.0);
+        let z_rel = z / rel_err;
+        let m = z_rel * z_rel * (1.0 - self.p) / (self.n as f64 * self.p);
+        (m.ceil() as usize).max(1)
+    }
+
+    /// Return whether `value` lies within `sigmas` standard deviations of
+    /// the mean `n p`, where the variance is `n p (1 - p)`. Bounds are
+    /// included.
+    pub fn is_within_sigma(&self, value: u64, sigmas: f64) -> bool {
+        let n = self.n as f64;
+        let mean = n * self.p;
+        let std_dev = (mean * (1.0 - self.p)).sqrt();
+        (value as f64 - mean).abs() <= sigmas * std_dev
+    }
+
+    /// Return whether the distribution is nearly constant: whether the
+    /// expected number of successes `n p` or of failures `n (1 - p)` is below
+    /// `DEGENERATE_MEAN = 0.001`.
+    ///
+    /// Samples then equal `0` respectively `n` with a probability of more
+    /// than 99.9%, which is far from a normal shape. This is purely
+    /// informational; sampling works as usual.
+    pub fn is_degenerate(&self) -> bool {
+        let n = self.n as f64;
+        n * self.p < DEGENERATE_MEAN || n * (1.0 - self.p) < DEGENERATE_MEAN
+    }
+}
+
+/// Expected number of successes or failures below which
+/// `Binomial::is_degenerate` returns `true`.
+const DEGENERATE_MEAN: f64 = 0.001;
+
+/// Largest `n` for which `Binomial::entropy` sums over the support.
+const ENTROPY_EXACT_MAX_N: u64 = 10_000;
+
+/// Smallest `n` for which `Binomial::sample_f64` uses the normal
+/// approximation.
+const NORMAL_APPROX_MIN_N: u64 = 1 << 32;
+
+/// Largest `n` for which `Binomial::sample_inverse` computes the pmf
+/// recursively.
+const RECURSIVE_PMF_MAX_N: u64 = 1000;
+
+/// Number of rejected attempts after which the rejection method falls back to
+/// inverse transform sampling.
+const REJECTION_MAX_ITERS: usize = 1000;
+
+/// Largest `k` for which `Binomial::sample_max` draws `k` samples.
+const MAX_DIRECT_K: u64 = 16;
+
+/// Largest `k` for which trials with `p = 2^-k` are simulated with random
+/// bits.
+const MAX_BITS_K: u32 = 16;
+
+/// The algorithm used to generate a sample of a [`Binomial`], as reported by
+/// [`Binomial::sample_with_stats`].
+///
+/// [`Binomial`]: struct.Binomial.html
+/// [`Binomial::sample_with_stats`]: struct.Binomial.html#method.sample_with_stats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleAlgorithm {
+    /// No randomness was used, since `n == 0`, `p == 0` or `p == 1`.
+    Constant,
+    /// The `n` Bernoulli trials were simulated, for small `n`.
+    Direct,
+    /// Rejection sampling with a Cauchy comparison distribution.
+    Rejection,
+    /// Inverse transform sampling, after too many rejections.
+    Inverse,
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+/// Statistics about the generation of a sample of a [`Binomial`], returned
+/// by [`Binomial::sample_with_stats`].
+///
+/// [`Binomial`]: struct.Binomial.html
+/// [`Binomial::sample_with_stats`]: struct.Binomial.html#method.sample_with_stats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SampleStats {
+    algorithm: SampleAlgorithm,
+    rejections: usize,
+}
+
+impl SampleStats {
+    /// The algorithm which generated the sample.
+    pub fn algorithm(&self) -> SampleAlgorithm {
+        self.algorithm
+    }
+
+    /// The number of attempts of the rejection method which were rejected;
+    /// zero for the other algorithms, unless the rejection method was tried
+    /// first.
+    pub fn rejections(&self) -> usize {
+        self.rejections
+    }
+}
+
+/// The sampling method, with all values which only depend on `n` and `p`
+/// precomputed.
+#[derive(Clone, Copy, Debug)]
+enum Method {
+    /// The result is always the same, for `p == 0` or `p == 1`.
+    Constant(u64),
+    /// Sum `n` Bernoulli trials.
+    Direct(Bernoulli),
+    /// Sum `n` trials with probability `numerator / denominator`, using
+    /// integer comparisons.
+    DirectRatio(u64, u64),
+    /// Sum `n` trials with probability `2^-k`: each trial succeeds if `k`
+    /// random bits are all set.
+    DirectBits(u32),
+    /// Rejection sampling, with a Cauchy comparison distribution.
+    Rejection(RejectionConsts),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RejectionConsts {
+    /// `min(p, 1 - p)`; the result is inverted if this differs from `p`.
+    p: f64,
+    float_n: f64,
+    ln_fact_n: f64,
+    log_p: f64,
+    log_pc: f64,
+    expected: f64,
+    sq: f64,
+}
+
+impl Binomial {
+    fn method(&self) -> Method {
+        // Handle these values directly.
+        if self.n == 0 || self.p == 0.0 {
+            return Method::Constant(0);
+        } else if self.p == 1.0 {
+            return Method::Constant(self.n);
+        }
+
         // For low n, it is faster to sample directly. For both methods,
         // performance is independent of p. On Intel Haswell CPU this method
         // appears to be faster for approx n < 300.
         if self.n < 300 {
-            let mut result = 0;
-            let d = Bernoulli::new(self.p);
-            for _ in 0 .. self.n {
-                result += rng.sample(d) as u32;
+            // `from_ratio` promises integer comparisons, even for dyadic `p`.
+            if let Some((numerator, denominator)) = self.ratio {
+                return Method::DirectRatio(numerator, denominator);
             }
-            return result as u64;
+            if let Some(k) = dyadic_exponent(self.p) {
+                return Method::DirectBits(k);
+            }
+            return Method::Direct(Bernoulli::new_unchecked(self.p));
         }
-        
+
         // binomial distribution is symmetrical with respect to p -> 1-p, k -> n-k
         // switch p so that it is less than 0.5 - this allows for lower expected values
         // we will just invert the result at the end
@@ -79,77 +586,524 @@ impl Distribution<u64> for Binomial {
 
         // prepare some cached values
         let float_n = self.n as f64;
-        let ln_fact_n = log_gamma(float_n + 1.0);
         let pc = 1.0 - p;
-        let log_p = p.ln();
-        let log_pc = pc.ln();
         let expected = self.n as f64 * p;
-        let sq = (expected * (2.0 * pc)).sqrt();
+        Method::Rejection(RejectionConsts {
+            p,
+            float_n,
+            ln_fact_n: log_gamma(float_n + 1.0),
+            log_p: p.ln(),
+            log_pc: pc.ln(),
+            expected,
+            sq: (expected * (2.0 * pc)).sqrt(),
+        })
+    }
 
-        let mut lresult;
+    #[inline]
+    fn sample_method<R: Rng + ?Sized>(&self, method: &Method, rng: &mut R) -> u64 {
+        self.sample_method_with_stats(method, rng).0
+    }
+
+    #[inline]
+    fn sample_method_with_stats<R: Rng + ?Sized>(&self, method: &Method, rng: &mut R)
+        -> (u64, SampleStats)
+    {
+        let stats = |algorithm| SampleStats { algorithm, rejections: 0 };
+        let c = match *method {
+            Method::Constant(k) => return (k, stats(SampleAlgorithm::Constant)),
+            Method::Direct(d) => {
+                let mut result = 0;
+                for _ in 0 .. self.n {
+                    result += rng.sample(d) as u32;
+                }
+                return (result as u64, stats(SampleAlgorithm::Direct));
+            }
+            Method::DirectRatio(numerator, denominator) => {
+                let mut result = 0;
+                for _ in 0 .. self.n {
+                    result += (rng.gen_below(denominator) < numerator) as u64;
+                }
+                return (result, stats(SampleAlgorithm::Direct));
+            }
+            Method::DirectBits(k) => {
+                // 64 trials at a time: the AND of `k` random words has each
+                // bit set with probability `2^-k`.
+                let mut result = 0;
+                let mut remaining = self.n;
+                while remaining > 0 {
+                    let mut bits = !0u64;
+                    for _ in 0..k {
+                        bits &= rng.next_u64();
+                    }
+                    if remaining < 64 {
+                        bits &= (1 << remaining) - 1;
+                    }
+                    result += bits.count_ones() as u64;
+                    remaining = remaining.saturating_sub(64);
+                }
+                return (result, stats(SampleAlgorithm::Direct));
+            }
+            Method::Rejection(ref c) => c,
+        };
 
         // we use the Cauchy distribution as the comparison distribution
         // f(x) ~ 1/(1+x^2)
-        let cauchy = Cauchy::new(0.0, 1.0);
-        loop {
-            let mut comp_dev: f64;
-            loop {
-                // draw from the Cauchy distribution
-                comp_dev = rng.sample(cauchy);
-                // shift the peak of the comparison ditribution
-                lresult = expected + sq * comp_dev;
-                // repeat the drawing until we are in the range of possible values
-                if lresult >= 0.0 && lresult < float_n + 1.0 {
-                    break;
-                }
+        let cauchy = Cauchy::new_unchecked(0.0, 1.0);
+        let mut attempts = 0;
+        let lresult = rejection_loop(REJECTION_MAX_ITERS, || {
+            attempts += 1;
+            // draw from the standard Cauchy distribution
+            let comp_dev = rng.sample(&cauchy);
+            // shift the peak of the comparison ditribution
+            let lresult = c.expected + c.sq * comp_dev;
+            // repeat the drawing until we are in the range of possible values
+            if !(lresult >= 0.0 && lresult < c.float_n + 1.0) {
+                return None;
             }
 
             // the result should be discrete
-            lresult = lresult.floor();
+            let lresult = lresult.floor();
 
-            let log_binomial_dist = ln_fact_n - log_gamma(lresult+1.0) -
-                log_gamma(float_n - lresult + 1.0) + lresult*log_p + (float_n - lresult)*log_pc;
+            let log_binomial_dist = c.ln_fact_n - log_gamma(lresult+1.0) -
+                log_gamma(c.float_n - lresult + 1.0) + lresult*c.log_p
+                + (c.float_n - lresult)*c.log_pc;
             // this is the binomial probability divided by the comparison probability
             // we will generate a uniform random value and if it is larger than this,
             // we interpret it as a value falling out of the distribution and repeat
-            let comparison_coeff = (log_binomial_dist.exp() * sq) * (1.2 * (1.0 + comp_dev*comp_dev));
+            let comparison_coeff = (log_binomial_dist.exp() * c.sq)
+                * (1.2 * (1.0 + comp_dev*comp_dev));
 
-            if comparison_coeff >= rng.gen() {
-                break;
+            if comparison_coeff >= rng.gen() { Some(lresult) } else { None }
+        });
+        let lresult = match lresult {
+            Some(x) => x,
+            // Each attempt is accepted with a probability of about one half,
+            // so this is only reached with a broken `rng`.
+            None => return (self.sample_inverse(rng), SampleStats {
+                algorithm: SampleAlgorithm::Inverse,
+                rejections: attempts,
+            }),
+        };
+        let stats = SampleStats {
+            algorithm: SampleAlgorithm::Rejection,
+            rejections: attempts - 1,
+        };
+
+        // invert the result for p < 0.5
+        if c.p != self.p {
+            (self.n - lresult as u64, stats)
+        } else {
+            (lresult as u64, stats)
+        }
+    }
+
+    /// Sample a value like [`sample`], and report how it was generated.
+    ///
+    /// This is meant for diagnosing unexpected slowness; [`sample`] itself
+    /// does not collect any statistics.
+    ///
+    /// [`sample`]: trait.Distribution.html#tymethod.sample
+    pub fn sample_with_stats<R: Rng + ?Sized>(&self, rng: &mut R) -> (u64, SampleStats) {
+        self.sample_method_with_stats(&self.method(), rng)
+    }
+
+    /// Sample a value like [`sample`], and return it with the number of
+    /// proposals of the rejection method which were rejected.
+    ///
+    /// This is a shorthand for [`sample_with_stats`] to monitor the
+    /// acceptance rate: the count is zero whenever the Bernoulli trials are
+    /// simulated directly, and about one per accepted sample otherwise.
+    ///
+    /// [`sample`]: trait.Distribution.html#tymethod.sample
+    /// [`sample_with_stats`]: #method.sample_with_stats
+    pub fn sample_counting_rejections<R: Rng + ?Sized>(&self, rng: &mut R) -> (u64, u32) {
+        let (k, stats) = self.sample_with_stats(rng);
+        // At most `REJECTION_MAX_ITERS` proposals are rejected.
+        (k, stats.rejections() as u32)
+    }
+
+    /// Sample once from `Binomial(n, p)`, for a `p` which changes between
+    /// calls.
+    ///
+    /// The algorithm is selected as by [`sample`]; since a `Binomial` caches
+    /// nothing beyond its parameters, this is as cheap as sampling from a
+    /// prepared distribution.
+    ///
+    /// Panics if `p < 0`, `p > 1` or `p` is NaN.
+    ///
+    /// [`sample`]: trait.Distribution.html#tymethod.sample
+    pub fn sample_with_p<R: Rng + ?Sized>(n: u64, p: f64, rng: &mut R) -> u64 {
+        let binomial = Binomial::new(n, p)
+            .expect("Binomial::sample_with_p called with invalid p");
+        binomial.sample_method(&binomial.method(), rng)
+    }
+
+    /// Sample the difference `X - Y` of independent draws `X` from this
+    /// distribution and `Y` from `other`, e.g. to compare the successes of
+    /// two variants of an experiment.
+    ///
+    /// Panics if the difference does not fit in an `i64`, which is only
+    /// possible if `n >= 2^63` for either distribution.
+    pub fn sample_difference<R: Rng + ?Sized>(&self, other: &Binomial, rng: &mut R) -> i64 {
+        let x = self.sample(rng);
+        let y = other.sample(rng);
+        let max = ::std::i64::MAX as u64;
+        if x >= y {
+            assert!(x - y <= max, "Binomial::sample_difference overflowed");
+            (x - y) as i64
+        } else {
+            assert!(y - x <= max + 1, "Binomial::sample_difference overflowed");
+            (y - x).wrapping_neg() as i64
+        }
+    }
+
+    /// Sample `count` values into a new `Vec`.
+    ///
+    /// This is equivalent to calling [`sample`] `count` times, but the values
+    /// which only depend on `n` and `p` are computed once for all draws.
+    ///
+    /// [`sample`]: trait.Distribution.html#tymethod.sample
+    pub fn sample_vec<R: Rng + ?Sized>(&self, rng: &mut R, count: usize) -> Vec<u64> {
+        let method = self.method();
+        (0..count).map(|_| self.sample_method(&method, rng)).collect()
+    }
+
+    /// Sample `batches` values and return their running totals: element `i`
+    /// is the sum of the first `i + 1` draws, which is `Binomial(n (i + 1),
+    /// p)` distributed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a total overflows `u64`.
+    pub fn running_totals<R: Rng + ?Sized>(&self, rng: &mut R, batches: usize) -> Vec<u64> {
+        let method = self.method();
+        let mut total = 0u64;
+        (0..batches).map(|_| {
+            total = total.checked_add(self.sample_method(&method, rng))
+                .expect("Binomial::running_totals overflowed");
+            total
+        }).collect()
+    }
+
+    /// Sample as an `f64`, using a normal approximation for huge `n`.
+    ///
+    /// For `n < 2^32` this is the same as [`sample`] converted to `f64`. For
+    /// larger `n` the result is drawn from `Normal(n p, n p (1 - p))`, rounded
+    /// to an integer and clamped to `[0, n]`, skipping the rejection method
+    /// entirely.
+    ///
+    /// By the Berry–Esseen theorem the approximation error of the cumulative
+    /// distribution function is at most `0.4748 (p^2 + (1-p)^2) / sqrt(n p
+    /// (1 - p))`; e.g. below `1e-4` for `n = 2^32` and `p = 0.3`. It is larger
+    /// when `n p` or `n (1 - p)` is small.
+    ///
+    /// [`sample`]: trait.Distribution.html#tymethod.sample
+    pub fn sample_f64<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        if self.n < NORMAL_APPROX_MIN_N || self.p == 0.0 || self.p == 1.0 {
+            return self.sample(rng) as f64;
+        }
+        let n = self.n as f64;
+        let mean = n * self.p;
+        let std_dev = (mean * (1.0 - self.p)).sqrt();
+        let z: f64 = rng.sample(StandardNormal);
+        (mean + std_dev * z).round().max(0.0).min(n)
+    }
+
+    /// Sample by inverse transform: draw a single uniform `u` in `[0, 1)`
+    /// using [`Rng::gen_unit_f64`], then return the smallest `k` with
+    /// `cdf(k) > u`.
+    ///
+    /// Unlike [`sample`], which picks an algorithm depending on `n` and `p`,
+    /// this always uses the same method, so the result is a fixed function of
+    /// the uniform draw. The search evaluates the `pmf` for `0..=k`, and thus
+    /// takes `O(n)` time in the worst case. For `n <= 1000` the terms are
+    /// computed by the recursion
+    /// `pmf(k + 1) = pmf(k) (n - k) / (k + 1) p / (1 - p)`, which is faster
+    /// than evaluating each one by itself.
+    ///
+    /// [`Rng::gen_unit_f64`]: ../trait.Rng.html#method.gen_unit_f64
+    /// [`sample`]: trait.Distribution.html#tymethod.sample
+    pub fn sample_inverse<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let u = rng.gen_unit_f64();
+        let mut cumulative = 0.0;
+        if let Some((mut pmf, ratio)) = self.recursive_pmf_start() {
+            for k in 0..self.n {
+                cumulative += pmf;
+                if cumulative > u {
+                    return k;
+                }
+                pmf *= ratio * (self.n - k) as f64 / (k + 1) as f64;
             }
+            return self.n;
         }
+        for k in 0..self.n {
+            cumulative += self.pmf(k);
+            if cumulative > u {
+                return k;
+            }
+        }
+        // Also reached if rounding leaves the sum of the pmf just below `u`.
+        self.n
+    }
 
-        // invert the result for p < 0.5
-        if p != self.p {
-            self.n - lresult as u64
+    /// Draw `count` stratified samples: `[0, 1)` is split into `count` equal
+    /// strata, and one uniform drawn from each is inverted like in
+    /// [`sample_inverse`].
+    ///
+    /// Element `i` comes from stratum `i`, so the samples are in
+    /// non-decreasing order and element `i` alone is not `Binomial(n, p)`
+    /// distributed. Only the multiset of samples is stratified: it is spread
+    /// more evenly than independent draws, with every value `k` occurring
+    /// within about one of `count pmf(k)` times. Hence the mean of the
+    /// samples has a much lower variance, which is useful for low-variance
+    /// Monte Carlo estimates; a uniformly chosen element, or any element
+    /// after shuffling, is `Binomial(n, p)` distributed. Since the uniforms
+    /// are sorted, the distribution function is swept only once, in
+    /// `O(n + count)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::Binomial;
+    ///
+    /// let binomial = Binomial::new(20, 0.3).unwrap();
+    /// let samples = binomial.stratified_samples(&mut rand::thread_rng(), 1000);
+    /// let mean = samples.iter().sum::<u64>() as f64 / 1000.0;
+    /// assert!((mean - 6.0).abs() < 0.1);
+    /// ```
+    ///
+    /// [`sample_inverse`]: #method.sample_inverse
+    pub fn stratified_samples<R: Rng + ?Sized>(&self, rng: &mut R, count: usize) -> Vec<u64> {
+        let recursive = self.recursive_pmf_start();
+        let mut k = 0;
+        let mut pmf = match recursive {
+            Some((pmf_0, _)) => pmf_0,
+            None => self.pmf(0),
+        };
+        let mut cumulative = pmf;
+        (0..count).map(|i| {
+            let u = (i as f64 + rng.gen_unit_f64()) / count as f64;
+            while cumulative <= u && k < self.n {
+                k += 1;
+                pmf = match recursive {
+                    Some((_, ratio)) => pmf * ratio * (self.n - k + 1) as f64 / k as f64,
+                    None => self.pmf(k),
+                };
+                cumulative += pmf;
+            }
+            k
+        }).collect()
+    }
+
+    /// The starting term `pmf(0) = (1 - p)^n` and the ratio `p / (1 - p)` of
+    /// the pmf recursion, if it is applicable: for `n` up to
+    /// `RECURSIVE_PMF_MAX_N`, as long as `pmf(0)` doesn't underflow.
+    fn recursive_pmf_start(&self) -> Option<(f64, f64)> {
+        if self.n > RECURSIVE_PMF_MAX_N {
+            return None;
+        }
+        let q = 1.0 - self.p;
+        let pmf_0 = q.powi(self.n as i32);
+        if pmf_0 > 0.0 && pmf_0.is_normal() {
+            Some((pmf_0, self.p / q))
+        } else {
+            None
+        }
+    }
+
+    /// Sample the maximum of `k` independent draws from this distribution.
+    ///
+    /// For `k <= 16` this simply draws `k` samples. For larger `k` a single
+    /// uniform `u` is inverted through the distribution function of the
+    /// maximum, `cdf(m)^k`: the result is the smallest `m` with
+    /// `P(X > m) <= 1 - u^(1/k)`. The upper tail is searched from the point
+    /// where the `pmf` becomes negligible, so the cost grows with the
+    /// standard deviation rather than with `k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k == 0`.
+    pub fn sample_max<R: Rng + ?Sized>(&self, rng: &mut R, k: u64) -> u64 {
+        assert!(k > 0, "Binomial::sample_max called with k == 0");
+        if k <= MAX_DIRECT_K {
+            let method = self.method();
+            return (0..k).map(|_| self.sample_method(&method, rng)).max().unwrap();
+        }
+        if self.p == 0.0 || self.p == 1.0 {
+            return self.sample(rng);
+        }
+
+        // `1 - u^(1/k)`, computed without cancellation; `u` is drawn from
+        // `(0, 1]` so that the logarithm is finite.
+        let u: f64 = rng.sample(OpenClosed01);
+        let tail = -(u.ln() / k as f64).exp_m1();
+
+        // Above the mode the pmf decreases, so binary search for the last
+        // term which can still contribute to the tail sum.
+        let mode = (((self.n as f64 + 1.0) * self.p).floor() as u64).min(self.n);
+        let ln_min = tail.ln() + ::std::f64::EPSILON.ln() - (self.n as f64 + 1.0).ln();
+        let mut m = if self.ln_pmf(self.n) >= ln_min {
+            self.n
         } else {
-            lresult as u64
+            let (mut lo, mut hi) = (mode, self.n);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if self.ln_pmf(mid) >= ln_min { lo = mid; } else { hi = mid; }
+            }
+            lo
+        };
+
+        // Walk down while `P(X > m - 1) <= tail`.
+        let mut survival = 0.0;
+        while m > 0 {
+            let next = survival + self.pmf(m);
+            if next > tail {
+                break;
+            }
+            survival = next;
+            m -= 1;
         }
+        m
     }
 }
 
+/// Return `k` if `p = 2^-k` exactly, for `1 <= k <= MAX_BITS_K`.
+fn dyadic_exponent(p: f64) -> Option<u32> {
+    // A power of two has no mantissa bits; this also excludes zero, whose
+    // exponent is not in range either.
+    let bits = p.to_bits();
+    if bits & ((1 << 52) - 1) != 0 {
+        return None;
+    }
+    let k = 1023 - (bits >> 52) as i64;
+    if k >= 1 && k <= MAX_BITS_K as i64 { Some(k as u32) } else { None }
+}
+
+impl Moments for Binomial {
+    /// The mean `n p`.
+    fn mean(&self) -> f64 {
+        Binomial::mean(self)
+    }
+
+    /// The variance `n p (1 - p)`.
+    fn variance(&self) -> f64 {
+        self.n as f64 * self.p * (1.0 - self.p)
+    }
+}
+
+impl Distribution<u64> for Binomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.sample_method(&self.method(), rng)
+    }
+
+    /// Each of the `n` Bernoulli trials of the direct method uses 64 bits,
+    /// or `k` bits if `p = 2^-k`.
+    /// The rejection method used for large `n` draws a variable number of
+    /// values, so no estimate is given.
+    fn entropy_bits(&self) -> Option<f64> {
+        match self.method() {
+            Method::Constant(_) => Some(0.0),
+            Method::Direct(_) | Method::DirectRatio(..) => Some(64.0 * self.n as f64),
+            Method::DirectBits(k) => Some((64 * k as u64 * ((self.n + 63) / 64)) as f64),
+            Method::Rejection(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Binomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Binomial(n={}, p={})", self.n, self.p)
+    }
+}
+
+/// The Kullback-Leibler divergence `KL(p || q)` of the binomial distribution
+/// `q` from `p`, in nats.
+///
+/// This is `sum_k p.pmf(k) ln(p.pmf(k) / q.pmf(k))` over the support of `p`,
+/// computed from the logarithms of the probabilities; terms with
+/// `p.pmf(k) == 0` contribute zero. The result is infinite if `p` gives a
+/// positive probability to an outcome which is impossible under `q`, in
+/// particular if `p` has more trials than `q`.
+///
+/// Like [`Binomial::entropy`], this sums over the support of `p` only for up
+/// to 10000 trials. For more trials the divergence is exact if `p` is
+/// constant or both distributions have the same number of trials `n`, where
+/// it is `n` times that of the Bernoulli distributions. Otherwise the
+/// divergence of the Gaussian approximations is used instead.
+///
+/// [`Binomial::entropy`]: struct.Binomial.html#method.entropy
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{binomial_kl_divergence, Binomial};
+///
+/// let p = Binomial::new(10, 0.5).unwrap();
+/// let q = Binomial::new(10, 0.6).unwrap();
+/// assert_eq!(binomial_kl_divergence(&p, &p), 0.0);
+/// assert!(binomial_kl_divergence(&p, &q) > 0.0);
+/// ```
+pub fn binomial_kl_divergence(p: &Binomial, q: &Binomial) -> f64 {
+    if p.n > ENTROPY_EXACT_MAX_N {
+        return binomial_kl_divergence_large(p, q);
+    }
+    let mut divergence = 0.0;
+    for k in 0..(p.n + 1) {
+        let ln_p = p.ln_pmf(k);
+        if ln_p == NEG_INFINITY {
+            continue;
+        }
+        let ln_q = q.ln_pmf(k);
+        if ln_q == NEG_INFINITY {
+            return INFINITY;
+        }
+        divergence += ln_p.exp() * (ln_p - ln_q);
+    }
+    // Rounding may leave a tiny negative sum for nearly equal distributions.
+    divergence.max(0.0)
+}
+
+/// `binomial_kl_divergence` for `p.n > ENTROPY_EXACT_MAX_N`, in `O(1)` time.
+fn binomial_kl_divergence_large(p: &Binomial, q: &Binomial) -> f64 {
+    // A constant `p` only has one outcome.
+    if p.p == 0.0 {
+        return -q.ln_pmf(0);
+    } else if p.p == 1.0 {
+        return -q.ln_pmf(p.n);
+    }
+    // Otherwise every outcome of `p` is possible, and a constant `q` or one
+    // with fewer trials rules some of them out.
+    if q.p == 0.0 || q.p == 1.0 || q.n < p.n {
+        return INFINITY;
+    }
+    let divergence = if q.n == p.n {
+        let (a, b) = (p.p, q.p);
+        p.n as f64 * (a * (a / b).ln() + (1.0 - a) * ((1.0 - a) / (1.0 - b)).ln())
+    } else {
+        let (mean_p, mean_q) = (p.n as f64 * p.p, q.n as f64 * q.p);
+        let var_p = p.n as f64 * p.p * (1.0 - p.p);
+        let var_q = q.n as f64 * q.p * (1.0 - q.p);
+        0.5 * ((var_q / var_p).ln() + (var_p + (mean_p - mean_q).powi(2)) / var_q - 1.0)
+    };
+    divergence.max(0.0)
+}
+
 #[cfg(test)]
 mod test {
     use Rng;
-    use distributions::Distribution;
-    use super::Binomial;
+    use distributions::{Distribution, DistError, Moments};
+    use super::{Binomial, Method, SampleAlgorithm, binomial_kl_divergence};
 
     fn test_binomial_mean_and_variance<R: Rng>(n: u64, p: f64, rng: &mut R) {
-        let binomial = Binomial::new(n, p);
+        let binomial = Binomial::new(n, p).unwrap();
 
         let expected_mean = n as f64 * p;
         let expected_variance = n as f64 * p * (1.0 - p);
+        assert_eq!(Moments::mean(&binomial), expected_mean);
+        assert!((Moments::variance(&binomial) - expected_variance).abs() < 1e-12);
 
-        let mut results = [0.0; 1000];
-        for i in results.iter_mut() { *i = binomial.sample(rng) as f64; }
-
-        let mean = results.iter().sum::<f64>() / results.len() as f64;
-        assert!((mean as f64 - expected_mean).abs() < expected_mean / 50.0);
-
-        let variance =
-            results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>()
-            / results.len() as f64;
-        assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+        let results: Vec<f64> = (0..100_000).map(|_| binomial.sample(rng) as f64).collect();
+        ::test::assert_moments(&results, expected_mean, expected_variance, 0.02);
     }
 
     #[test]
@@ -162,16 +1116,791 @@ mod test {
         test_binomial_mean_and_variance(20, 0.5, &mut rng);
     }
 
+    #[test]
+    fn test_binomial_direct_bits() {
+        let mut rng = ::test::rng(225);
+        let binomial = Binomial::new(64, 0.25).unwrap();
+        assert_eq!(binomial.entropy_bits(), Some(128.0));
+        let trials = 100_000;
+        let mut counts = [0u32; 65];
+        let mut general = [0u32; 65];
+        for _ in 0..trials {
+            counts[binomial.sample(&mut rng) as usize] += 1;
+            general[binomial.sample_inverse(&mut rng) as usize] += 1;
+        }
+        for k in 0..65 {
+            let expected = binomial.pmf(k as u64);
+            let sd = (expected * (1.0 - expected) / trials as f64).sqrt();
+            let freq = counts[k] as f64 / trials as f64;
+            let general_freq = general[k] as f64 / trials as f64;
+            assert!((freq - expected).abs() <= 5.0 * sd + 1e-5, "k = {}", k);
+            assert!((freq - general_freq).abs() <= 7.0 * sd + 1e-5, "k = {}", k);
+        }
+
+        // Any length, not only multiples of 64 trials.
+        for &(n, p) in [(1, 0.5), (100, 0.5), (299, 0.125), (200, 1.0 / 65536.0)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| {
+                let k = binomial.sample(&mut rng);
+                assert!(k <= n);
+                k as f64
+            }).collect();
+            if n * 1000 >= (1.0 / p) as u64 {
+                ::test::assert_moments(&samples, n as f64 * p, n as f64 * p * (1.0 - p), 0.1);
+            }
+        }
+
+        // `from_ratio` keeps using `gen_below`, even for dyadic ratios.
+        match Binomial::from_ratio(64, 1, 4).unwrap().method() {
+            Method::DirectRatio(1, 4) => (),
+            method => panic!("unexpected method {:?}", method),
+        }
+    }
+
+    #[test]
+    fn test_binomial_rejection_pmf() {
+        // The rejection method samples its Cauchy comparison distribution
+        // with `Cauchy`; check it still matches the pmf.
+        let mut rng = ::test::rng(217);
+        for &(n, p) in [(400, 0.01), (500, 0.2), (1000, 0.7)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            assert_eq!(binomial.sample_with_stats(&mut rng).1.algorithm(),
+                       SampleAlgorithm::Rejection);
+            let trials = 100_000;
+            let mut counts = vec![0u32; n as usize + 1];
+            for _ in 0..trials {
+                counts[binomial.sample(&mut rng) as usize] += 1;
+            }
+            for (k, &count) in counts.iter().enumerate() {
+                let expected = binomial.pmf(k as u64);
+                let freq = count as f64 / trials as f64;
+                let sd = (expected * (1.0 - expected) / trials as f64).sqrt();
+                assert!((freq - expected).abs() <= 5.0 * sd + 1e-4,
+                        "{}, k = {}: {} != {}", binomial, k, freq, expected);
+            }
+            let samples: Vec<f64> = counts.iter().enumerate()
+                .flat_map(|(k, &count)| ::std::iter::repeat(k as f64).take(count as usize))
+                .collect();
+            ::test::assert_moments(&samples, n as f64 * p, n as f64 * p * (1.0 - p), 0.02);
+        }
+    }
+
+    #[test]
+    fn test_binomial_sample_vec() {
+        let mut rng = ::test::rng(353);
+        for &(n, p) in [(0, 0.5), (20, 0.0), (40, 0.3), (1000, 0.8)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let count = 2000;
+            let batch = binomial.sample_vec(&mut rng, count);
+            assert_eq!(batch.len(), count);
+            assert!(batch.iter().all(|&k| k <= n));
+
+            let batch_mean = batch.iter().sum::<u64>() as f64 / count as f64;
+            let single_mean = (0..count).map(|_| binomial.sample(&mut rng))
+                .sum::<u64>() as f64 / count as f64;
+            let expected_mean = n as f64 * p;
+            let sd = (expected_mean * (1.0 - p)).sqrt();
+            assert!((batch_mean - expected_mean).abs() <= 5.0 * sd / (count as f64).sqrt());
+            assert!((batch_mean - single_mean).abs() <= 7.0 * sd / (count as f64).sqrt());
+        }
+        assert!(Binomial::new(20, 0.5).unwrap().sample_vec(&mut rng, 0).is_empty());
+    }
+
+    #[test]
+    fn test_binomial_importance_sample_tail() {
+        let mut rng = ::test::rng(214);
+        for &(n, p, k_min) in [(100, 0.1, 30), (500, 0.3, 200), (50, 0.5, 10)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let exact = 1.0 - binomial.cdf(k_min - 1);
+            let trials = 20_000;
+            let mut sum = 0.0;
+            for _ in 0..trials {
+                let (k, weight) = binomial.importance_sample_tail(&mut rng, k_min);
+                assert!(k <= n && weight >= 0.0);
+                if k >= k_min {
+                    sum += weight;
+                }
+            }
+            let estimate = sum / trials as f64;
+            assert!((estimate - exact).abs() < 0.03 * exact,
+                    "{}, k_min = {}: {} != {}", binomial, k_min, estimate, exact);
+        }
+
+        // Impossible and certain tails.
+        let binomial = Binomial::new(10, 0.5).unwrap();
+        let (k, weight) = binomial.importance_sample_tail(&mut rng, 10);
+        assert!(k <= 10 && weight > 0.0);
+        let (k, weight) = binomial.importance_sample_tail(&mut rng, 11);
+        assert_eq!(k, 10);
+        assert!((weight - 0.5f64.powi(10)).abs() < 1e-15);
+        for _ in 0..100 {
+            let (k, weight) = Binomial::new(10, 0.0).unwrap().importance_sample_tail(&mut rng, 3);
+            assert!(k == 0 || weight == 0.0);
+        }
+    }
+
+    #[test]
+    fn test_binomial_running_totals() {
+        let mut rng = ::test::rng(211);
+        let binomial = Binomial::new(10, 0.3).unwrap();
+        let (batches, trials) = (50, 2000);
+        let mut sum = 0;
+        for _ in 0..trials {
+            let totals = binomial.running_totals(&mut rng, batches);
+            assert_eq!(totals.len(), batches);
+            assert!(totals[0] <= 10);
+            for w in totals.windows(2) {
+                assert!(w[0] <= w[1] && w[1] - w[0] <= 10);
+            }
+            sum += totals[batches - 1];
+        }
+        // The final total is `Binomial(500, 0.3)`, with mean 150 and
+        // standard deviation `sqrt(105)`.
+        let mean = sum as f64 / trials as f64;
+        assert!((mean - 150.0).abs() <= 5.0 * 105f64.sqrt() / (trials as f64).sqrt());
+        assert!(binomial.running_totals(&mut rng, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binomial_running_totals_overflow() {
+        let mut rng = ::test::rng(212);
+        Binomial::new(::core::u64::MAX, 1.0).unwrap().running_totals(&mut rng, 2);
+    }
+
+    #[test]
+    fn test_binomial_sample_with_p() {
+        let mut rng = ::test::rng(165);
+        for &(n, p) in [(20, 0.3), (1000, 0.01), (1000, 0.7)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let with_p: Vec<f64> = (0..50_000)
+                .map(|_| Binomial::sample_with_p(n, p, &mut rng) as f64).collect();
+            let reference: Vec<f64> = (0..50_000)
+                .map(|_| binomial.sample(&mut rng) as f64).collect();
+            let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+            let expected_mean = n as f64 * p;
+            let expected_variance = expected_mean * (1.0 - p);
+            ::test::assert_moments(&with_p, expected_mean, expected_variance, 0.03);
+            // Each mean has a standard error of sqrt(variance / 50_000).
+            let std_err = (expected_variance / 50_000.0).sqrt();
+            assert!((mean(&with_p) - mean(&reference)).abs() < 5.0 * std_err);
+        }
+        assert_eq!(Binomial::sample_with_p(10, 0.0, &mut rng), 0);
+        assert_eq!(Binomial::sample_with_p(10, 1.0, &mut rng), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binomial_sample_with_p_invalid() {
+        Binomial::sample_with_p(10, 1.5, &mut ::test::rng(166));
+    }
+
+    #[test]
+    fn test_binomial_sample_difference() {
+        let mut rng = ::test::rng(179);
+        let a = Binomial::new(1000, 0.3).unwrap();
+        let b = Binomial::new(500, 0.7).unwrap();
+        let samples: Vec<f64> = (0..50_000)
+            .map(|_| a.sample_difference(&b, &mut rng) as f64).collect();
+        // Mean `300 - 350`, and the variances add: `210 + 105`.
+        ::test::assert_moments(&samples, -50.0, 315.0, 0.03);
+
+        let zero = Binomial::new(0, 0.5).unwrap();
+        assert_eq!(Binomial::new(5, 1.0).unwrap().sample_difference(&zero, &mut rng), 5);
+        assert_eq!(zero.sample_difference(&Binomial::new(5, 1.0).unwrap(), &mut rng), -5);
+        let half = Binomial::new(1 << 63, 1.0).unwrap();
+        assert_eq!(zero.sample_difference(&half, &mut rng), i64::min_value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binomial_sample_difference_overflow() {
+        let all = Binomial::new(u64::max_value(), 1.0).unwrap();
+        all.sample_difference(&Binomial::new(0, 0.5).unwrap(), &mut ::test::rng(180));
+    }
+
+    #[test]
+    fn test_binomial_recursive_pmf() {
+        for &(n, p) in [(10, 0.3), (100, 0.05), (1000, 0.5), (300, 0.9), (7, 0.0)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let (mut pmf, ratio) = binomial.recursive_pmf_start().unwrap();
+            for k in 0..(n + 1) {
+                let expected = binomial.pmf(k);
+                assert!((pmf - expected).abs() <= 1e-9 * expected + 1e-300,
+                        "n = {}, p = {}, k = {}: {} != {}", n, p, k, pmf, expected);
+                pmf *= ratio * (n - k) as f64 / (k + 1) as f64;
+            }
+        }
+        // Not applicable when `(1 - p)^n` underflows or for large `n`.
+        assert!(Binomial::new(1000, 0.9999).unwrap().recursive_pmf_start().is_none());
+        assert!(Binomial::new(1001, 0.5).unwrap().recursive_pmf_start().is_none());
+        assert!(Binomial::new(10, 1.0).unwrap().recursive_pmf_start().is_none());
+    }
+
+    #[test]
+    fn test_binomial_sample_inverse() {
+        use rngs::mock::StepRng;
+
+        let mut rng = ::test::rng(363);
+        for &(n, p) in [(20, 0.3), (100, 0.9), (500, 0.5)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let inverse: Vec<f64> = (0..50_000)
+                .map(|_| binomial.sample_inverse(&mut rng) as f64).collect();
+            let default: Vec<f64> = (0..50_000)
+                .map(|_| binomial.sample(&mut rng) as f64).collect();
+            let mean = n as f64 * p;
+            let var = mean * (1.0 - p);
+            ::test::assert_moments(&inverse, mean, var, 0.03);
+            ::test::assert_moments(&default, mean, var, 0.03);
+        }
+
+        // The smallest and largest uniform draws map to the end points.
+        let binomial = Binomial::new(10, 0.5).unwrap();
+        assert_eq!(binomial.sample_inverse(&mut StepRng::new(0, 0)), 0);
+        assert_eq!(binomial.sample_inverse(&mut StepRng::new(u64::max_value(), 0)), 10);
+        let constant = Binomial::new(10, 1.0).unwrap();
+        assert_eq!(constant.sample_inverse(&mut rng), 10);
+        let empty = Binomial::new(0, 0.5).unwrap();
+        assert_eq!(empty.sample_inverse(&mut rng), 0);
+    }
+
+    #[test]
+    fn test_binomial_sample_f64() {
+        let mut rng = ::test::rng(364);
+        for &(n, p) in [(1000, 0.3), (1 << 33, 0.3), (1_000_000_000_000, 0.01),
+                        (u64::max_value(), 0.5)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let samples: Vec<f64> = (0..50_000).map(|_| binomial.sample_f64(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x == x.round()));
+            let mean = n as f64 * p;
+            ::test::assert_moments(&samples, mean, mean * (1.0 - p), 0.03);
+        }
+
+        // Compare against the exact sampler just above the threshold.
+        let binomial = Binomial::new(super::NORMAL_APPROX_MIN_N, 0.01).unwrap();
+        let approx: Vec<f64> = (0..50_000).map(|_| binomial.sample_f64(&mut rng)).collect();
+        let exact: Vec<f64> = (0..50_000).map(|_| binomial.sample(&mut rng) as f64).collect();
+        let mean = super::NORMAL_APPROX_MIN_N as f64 * 0.01;
+        ::test::assert_moments(&approx, mean, mean * 0.99, 0.03);
+        ::test::assert_moments(&exact, mean, mean * 0.99, 0.03);
+    }
+
+    #[test]
+    fn test_binomial_pmf() {
+        let binomial = Binomial::new(10, 0.3).unwrap();
+        assert!((binomial.pmf(0) - 0.7f64.powi(10)).abs() < 1e-12);
+        assert!((binomial.pmf(3) - 120.0 * 0.3f64.powi(3) * 0.7f64.powi(7)).abs() < 1e-10);
+        assert_eq!(binomial.pmf(11), 0.0);
+        let sum: f64 = (0..11).map(|k| binomial.pmf(k)).sum();
+        assert!((sum - 1.0).abs() < 1e-10);
+
+        // far in the tail, the pmf underflows but its logarithm does not
+        let binomial = Binomial::new(10_000, 0.5).unwrap();
+        assert_eq!(binomial.pmf(0), 0.0);
+        assert!((binomial.ln_pmf(0) - 10_000.0 * 0.5f64.ln()).abs() < 1e-6);
+
+        assert_eq!(Binomial::new(5, 0.0).unwrap().pmf(0), 1.0);
+        assert_eq!(Binomial::new(5, 1.0).unwrap().pmf(5), 1.0);
+        assert_eq!(Binomial::new(5, 1.0).unwrap().pmf(4), 0.0);
+    }
+
+    #[test]
+    fn test_binomial_kl_divergence() {
+        for &(n, p) in [(0, 0.5), (10, 0.3), (1000, 0.01), (5, 0.0), (5, 1.0)].iter() {
+            let b = Binomial::new(n, p).unwrap();
+            assert_eq!(binomial_kl_divergence(&b, &b), 0.0);
+        }
+
+        // A single trial is a Bernoulli distribution.
+        let (p, q) = (Binomial::new(1, 0.5).unwrap(), Binomial::new(1, 0.25).unwrap());
+        let expected = 0.5 * (0.5f64 / 0.25).ln() + 0.5 * (0.5f64 / 0.75).ln();
+        assert!((binomial_kl_divergence(&p, &q) - expected).abs() < 1e-12);
+        // For `n` trials the divergence is `n` times larger.
+        let (p, q) = (Binomial::new(20, 0.5).unwrap(), Binomial::new(20, 0.25).unwrap());
+        assert!((binomial_kl_divergence(&p, &q) - 20.0 * expected).abs() < 1e-9);
+        // The divergence is not symmetric, but both directions are positive.
+        let reverse = binomial_kl_divergence(&q, &p);
+        assert!(reverse > 0.0 && (reverse - 20.0 * expected).abs() > 1e-3);
+
+        // Outcomes impossible under `q`.
+        let certain = Binomial::new(20, 0.0).unwrap();
+        assert_eq!(binomial_kl_divergence(&p, &certain), ::std::f64::INFINITY);
+        assert!(binomial_kl_divergence(&certain, &p).is_finite());
+        let more_trials = Binomial::new(21, 0.5).unwrap();
+        assert_eq!(binomial_kl_divergence(&more_trials, &p), ::std::f64::INFINITY);
+        assert!(binomial_kl_divergence(&p, &more_trials).is_finite());
+    }
+
+    #[test]
+    fn test_binomial_kl_divergence_large() {
+        use core::u64::MAX;
+        use std::f64::INFINITY;
+        let expected = 0.5 * (0.5f64 / 0.25).ln() + 0.5 * (0.5f64 / 0.75).ln();
+        for &n in [20_000, MAX].iter() {
+            let (p, q) = (Binomial::new(n, 0.5).unwrap(), Binomial::new(n, 0.25).unwrap());
+            assert_eq!(binomial_kl_divergence(&p, &p), 0.0);
+            let divergence = binomial_kl_divergence(&p, &q);
+            assert!((divergence - n as f64 * expected).abs() < 1e-12 * n as f64 * expected);
+        }
+
+        // The Gaussian approximation, for trials which differ a little.
+        let p = Binomial::new(20_000, 0.5).unwrap();
+        let q = Binomial::new(20_002, 0.5).unwrap();
+        let divergence = binomial_kl_divergence(&p, &q);
+        assert!(divergence > 0.0 && divergence < 1e-3);
+        assert_eq!(binomial_kl_divergence(&q, &p), INFINITY);
+
+        // Constant distributions.
+        let zero = Binomial::new(MAX, 0.0).unwrap();
+        assert_eq!(binomial_kl_divergence(&zero, &zero), 0.0);
+        assert_eq!(binomial_kl_divergence(&zero, &Binomial::new(MAX, 1.0).unwrap()), INFINITY);
+        assert_eq!(binomial_kl_divergence(&Binomial::new(MAX, 0.5).unwrap(), &zero), INFINITY);
+        let divergence = binomial_kl_divergence(&Binomial::new(20_000, 0.0).unwrap(), &p);
+        assert!((divergence - 20_000.0 * 2f64.ln()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_binomial_accessors() {
+        let binomial = Binomial::new(20, 0.3).unwrap();
+        assert_eq!((binomial.n(), binomial.p()), (20, 0.3));
+        assert!((binomial.mean() - 6.0).abs() < 1e-12);
+        let ratio = Binomial::from_ratio(9, 1, 3).unwrap();
+        assert_eq!(ratio.p(), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_binomial_cdf() {
+        let binomial = Binomial::new(10, 0.3).unwrap();
+        let mut sum = 0.0;
+        for k in 0..10 {
+            sum += binomial.pmf(k);
+            assert!((binomial.cdf(k) - sum).abs() < 1e-12);
+        }
+        assert_eq!(binomial.cdf(10), 1.0);
+        assert_eq!(binomial.cdf(100), 1.0);
+        assert_eq!(Binomial::new(10, 0.0).unwrap().cdf(0), 1.0);
+    }
+
+    #[test]
+    fn test_binomial_conditional_min() {
+        let mut rng = ::test::rng(355);
+        // P(X >= 30) ~ 2e-8 and P(X >= 5) ~ 0.98 for Binomial(100, 0.1)
+        for &k_min in [5, 30].iter() {
+            let binomial = Binomial::new(100, 0.1).unwrap();
+            let tail_mass: f64 = (k_min..101).map(|k| binomial.pmf(k)).sum();
+            let expected_mean = (k_min..101).map(|k| k as f64 * binomial.pmf(k))
+                .sum::<f64>() / tail_mass;
+
+            let n = 10_000;
+            let mut sum = 0;
+            for _ in 0..n {
+                let x = binomial.sample_conditional_min(&mut rng, k_min);
+                assert!(x >= k_min && x <= 100);
+                sum += x;
+            }
+            let mean = sum as f64 / n as f64;
+            assert!((mean - expected_mean).abs() < 0.05 * expected_mean);
+        }
+
+        // The tail probability underflows, but sampling still works.
+        let binomial = Binomial::new(2000, 0.01).unwrap();
+        assert_eq!(binomial.pmf(1500), 0.0);
+        let x = binomial.sample_conditional_min(&mut rng, 1500);
+        assert!(x >= 1500 && x < 1510);
+
+        assert_eq!(Binomial::new(10, 1.0).unwrap().sample_conditional_min(&mut rng, 3), 10);
+        assert!(Binomial::new(10, 0.5).unwrap().sample_conditional_min(&mut rng, 0) <= 10);
+    }
+
+    #[test]
+    fn test_binomial_conditional_min_max_u64() {
+        use core::u64::MAX;
+        let mut rng = ::test::rng(357);
+        let binomial = Binomial::new(MAX, 0.5).unwrap();
+        for _ in 0..100 {
+            assert!(binomial.sample_conditional_min(&mut rng, MAX - 10) >= MAX - 10);
+        }
+        assert_eq!(binomial.sample_conditional_min(&mut rng, MAX), MAX);
+    }
+
+    #[test]
+    fn test_binomial_sample_with_parity() {
+        let mut rng = ::test::rng(236);
+        for &(n, p) in [(10, 0.5), (25, 0.1), (7, 0.9), (1000, 0.3), (1, 0.2)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            for &even in [true, false].iter() {
+                let parity = if even { 0 } else { 1 };
+                let values = (0..(n + 1)).filter(|k| k % 2 == parity);
+                let mass: f64 = values.clone().map(|k| binomial.pmf(k)).sum();
+                assert!((binomial.parity_probability(even) - mass).abs() < 1e-10);
+                let expected_mean = values.map(|k| k as f64 * binomial.pmf(k))
+                    .sum::<f64>() / mass;
+
+                let trials = 10_000;
+                let mut sum = 0;
+                for _ in 0..trials {
+                    let x = binomial.sample_with_parity(&mut rng, even);
+                    assert!(x % 2 == parity && x <= n);
+                    sum += x;
+                }
+                let mean = sum as f64 / trials as f64;
+                assert!((mean - expected_mean).abs() < 0.02 * expected_mean.max(1.0),
+                        "{}, even = {}: {} != {}", binomial, even, mean, expected_mean);
+            }
+        }
+
+        // Odd values are very unlikely, but can still be sampled.
+        let binomial = Binomial::new(10, 1e-40).unwrap();
+        assert!(binomial.parity_probability(false) < 1e-38);
+        assert_eq!(binomial.sample_with_parity(&mut rng, false), 1);
+
+        assert_eq!(Binomial::new(10, 0.0).unwrap().sample_with_parity(&mut rng, true), 0);
+        assert_eq!(Binomial::new(9, 1.0).unwrap().sample_with_parity(&mut rng, false), 9);
+        assert_eq!(Binomial::new(0, 0.5).unwrap().sample_with_parity(&mut rng, true), 0);
+
+        // The mode and the loop bounds do not overflow for the largest `n`.
+        let binomial = Binomial::new(::core::u64::MAX, 1e-18).unwrap();
+        for &even in [true, false].iter() {
+            let parity = if even { 0 } else { 1 };
+            for _ in 0..100 {
+                assert_eq!(binomial.sample_with_parity(&mut rng, even) % 2, parity);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binomial_sample_with_parity_impossible() {
+        let mut rng = ::test::rng(237);
+        Binomial::new(10, 1.0).unwrap().sample_with_parity(&mut rng, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binomial_conditional_min_impossible() {
+        let mut rng = ::test::rng(356);
+        Binomial::new(10, 0.5).unwrap().sample_conditional_min(&mut rng, 11);
+    }
+
+    #[test]
+    fn test_binomial_entropy() {
+        use std::f64::consts::{E, PI};
+
+        assert!((Binomial::new(1, 0.5).unwrap().entropy() - 2f64.ln()).abs() < 1e-10);
+        assert_eq!(Binomial::new(20, 0.0).unwrap().entropy(), 0.0);
+        assert_eq!(Binomial::new(20, 1.0).unwrap().entropy(), 0.0);
+
+        // the exact sum converges to the Gaussian approximation
+        for &(n, p) in [(1000, 0.3), (5000, 0.5)].iter() {
+            let exact = Binomial::new(n, p).unwrap().entropy();
+            let approx = 0.5 * (2.0 * PI * E * n as f64 * p * (1.0 - p)).ln();
+            assert!((exact - approx).abs() < 1e-2);
+        }
+        let large = Binomial::new(1_000_000, 0.3).unwrap();
+        let approx = 0.5 * (2.0 * PI * E * 1e6 * 0.3 * 0.7).ln();
+        assert!((large.entropy() - approx).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_binomial_zero_trials() {
+        let mut rng = ::test::rng(354);
+        for &p in [0.0, 0.3, 0.5, 0.7, 1.0].iter() {
+            let binomial = Binomial::new(0, p).unwrap();
+            for _ in 0..10 {
+                assert_eq!(binomial.sample(&mut rng), 0);
+            }
+            assert_eq!(binomial.sample_vec(&mut rng, 5), vec![0; 5]);
+            assert_eq!(binomial.pmf(0), 1.0);
+            assert_eq!(binomial.pmf(1), 0.0);
+            assert_eq!(binomial.entropy(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_binomial_samples_for_mean_rel_error() {
+        // z = 1.959964 for 95%, so m = (z / 0.01)^2 * 0.5 / 5 = 3841.46.
+        let binomial = Binomial::new(10, 0.5).unwrap();
+        assert_eq!(binomial.samples_for_mean_rel_error(0.01, 0.95), 3842);
+        // z = 2.575829 for 99%, so m = (z / 0.1)^2 * 0.9 / 10 = 59.71.
+        let binomial = Binomial::new(100, 0.1).unwrap();
+        assert_eq!(binomial.samples_for_mean_rel_error(0.1, 0.99), 60);
+        // Without variance a single sample suffices.
+        let binomial = Binomial::new(10, 1.0).unwrap();
+        assert_eq!(binomial.samples_for_mean_rel_error(0.01, 0.95), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binomial_samples_for_mean_rel_error_zero_mean() {
+        Binomial::new(10, 0.0).unwrap().samples_for_mean_rel_error(0.01, 0.95);
+    }
+
+    #[test]
+    fn test_binomial_is_within_sigma() {
+        // Mean 20 and standard deviation 4.
+        let binomial = Binomial::new(100, 0.2).unwrap();
+        assert!(binomial.is_within_sigma(20, 0.0));
+        assert!(binomial.is_within_sigma(24, 1.0));
+        assert!(!binomial.is_within_sigma(25, 1.0));
+        assert!(binomial.is_within_sigma(12, 2.0));
+        assert!(!binomial.is_within_sigma(11, 2.0));
+        // A constant distribution only contains its value.
+        let constant = Binomial::new(10, 1.0).unwrap();
+        assert!(constant.is_within_sigma(10, 3.0));
+        assert!(!constant.is_within_sigma(9, 3.0));
+    }
+
+    #[test]
+    fn test_binomial_is_degenerate() {
+        assert!(Binomial::new(10, 1e-9).unwrap().is_degenerate());
+        assert!(Binomial::new(10, 1.0 - 1e-9).unwrap().is_degenerate());
+        assert!(Binomial::new(10, 0.0).unwrap().is_degenerate());
+        assert!(Binomial::new(0, 0.5).unwrap().is_degenerate());
+        assert!(!Binomial::new(10, 0.5).unwrap().is_degenerate());
+        assert!(!Binomial::new(1_000_000_000, 1e-9).unwrap().is_degenerate());
+    }
+
+    #[test]
+    fn test_binomial_rejection_guard() {
+        use rngs::mock::StepRng;
+        // The largest possible values reject every attempt of the rejection
+        // method, which must then fall back instead of hanging.
+        let mut rng = StepRng::new(!0, 0);
+        let binomial = Binomial::new(1000, 0.3).unwrap();
+        assert_eq!(binomial.sample(&mut rng), binomial.sample_inverse(&mut rng));
+        assert!(binomial.sample(&mut rng) <= 1000);
+
+        let (_, stats) = binomial.sample_with_stats(&mut rng);
+        assert_eq!(stats.algorithm(), SampleAlgorithm::Inverse);
+        assert_eq!(stats.rejections(), super::REJECTION_MAX_ITERS);
+    }
+
+    #[test]
+    fn test_binomial_sample_with_stats() {
+        let mut rng = ::test::rng(176);
+        let (k, stats) = Binomial::new(20, 0.3).unwrap().sample_with_stats(&mut rng);
+        assert!(k <= 20);
+        assert_eq!(stats.algorithm(), SampleAlgorithm::Direct);
+        assert_eq!(stats.rejections(), 0);
+
+        let (_, stats) = Binomial::from_ratio(20, 1, 3).unwrap().sample_with_stats(&mut rng);
+        assert_eq!(stats.algorithm(), SampleAlgorithm::Direct);
+
+        let (k, stats) = Binomial::new(7, 1.0).unwrap().sample_with_stats(&mut rng);
+        assert_eq!((k, stats.algorithm()), (7, SampleAlgorithm::Constant));
+
+        let binomial = Binomial::new(10_000, 0.4).unwrap();
+        let mut total_rejections = 0;
+        for _ in 0..1000 {
+            let (k, stats) = binomial.sample_with_stats(&mut rng);
+            assert!(k <= 10_000);
+            assert_eq!(stats.algorithm(), SampleAlgorithm::Rejection);
+            total_rejections += stats.rejections();
+        }
+        // About half of the attempts are accepted.
+        assert!(total_rejections > 100 && total_rejections < 3000);
+    }
+
+    #[test]
+    fn test_binomial_sample_counting_rejections() {
+        let mut rng = ::test::rng(229);
+        let direct = Binomial::new(20, 0.3).unwrap();
+        for _ in 0..100 {
+            let (k, rejections) = direct.sample_counting_rejections(&mut rng);
+            assert!(k <= 20);
+            assert_eq!(rejections, 0);
+        }
+
+        let binomial = Binomial::new(10_000, 0.4).unwrap();
+        let mut a = ::test::rng(230);
+        let mut b = ::test::rng(230);
+        let mut total_rejections = 0;
+        for _ in 0..1000 {
+            let (k, rejections) = binomial.sample_counting_rejections(&mut a);
+            let (expected, stats) = binomial.sample_with_stats(&mut b);
+            assert_eq!((k, rejections as usize), (expected, stats.rejections()));
+            total_rejections += rejections;
+        }
+        assert!(total_rejections > 100 && total_rejections < 3000);
+    }
+
+    #[test]
+    fn test_binomial_stratified_samples() {
+        let mut rng = ::test::rng(238);
+        // The variance of the sample mean is much lower than for independent
+        // draws, where it is `n p (1 - p) / count = 0.042`.
+        let binomial = Binomial::new(20, 0.3).unwrap();
+        let runs = 200;
+        let mut iid_means = Vec::new();
+        let mut stratified_means = Vec::new();
+        for _ in 0..runs {
+            let iid = binomial.sample_vec(&mut rng, 100);
+            let stratified = binomial.stratified_samples(&mut rng, 100);
+            iid_means.push(iid.iter().sum::<u64>() as f64 / 100.0);
+            stratified_means.push(stratified.iter().sum::<u64>() as f64 / 100.0);
+        }
+        let variance = |means: &[f64]| {
+            let mean = means.iter().sum::<f64>() / runs as f64;
+            means.iter().map(|m| (m - mean) * (m - mean)).sum::<f64>() / (runs - 1) as f64
+        };
+        let (iid_var, stratified_var) = (variance(&iid_means), variance(&stratified_means));
+        assert!(iid_var > 0.03 && iid_var < 0.06, "{}", iid_var);
+        assert!(stratified_var < 0.1 * iid_var, "{} {}", stratified_var, iid_var);
+
+        // Each value occurs about `count pmf(k)` times, also without the pmf
+        // recursion for large `n`.
+        for &(n, p) in [(20, 0.3), (5000, 0.5), (10, 0.0), (10, 1.0)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let samples = binomial.stratified_samples(&mut rng, 1000);
+            assert_eq!(samples.len(), 1000);
+            assert!(samples.windows(2).all(|w| w[0] <= w[1]) && samples[999] <= n);
+            for k in samples[0]..(samples[999] + 1) {
+                let count = samples.iter().filter(|&&x| x == k).count() as f64;
+                assert!((count - 1000.0 * binomial.pmf(k)).abs() <= 2.0, "{} {}", binomial, k);
+            }
+        }
+        assert!(binomial.stratified_samples(&mut rng, 0).is_empty());
+
+        // A single stratum is the same as `sample_inverse`.
+        let mut r1 = ::test::rng(239);
+        let mut r2 = ::test::rng(239);
+        for _ in 0..100 {
+            assert_eq!(binomial.stratified_samples(&mut r1, 1),
+                       vec![binomial.sample_inverse(&mut r2)]);
+        }
+    }
+
+    #[test]
+    fn test_binomial_sample_max() {
+        let mut rng = ::test::rng(381);
+        let binomial = Binomial::new(20, 0.3).unwrap();
+        let mut last_mean = 0.0;
+        for &k in [1, 4, 16, 17, 64, 1024, 1 << 20].iter() {
+            // `E[max] = sum_m P(max > m) = sum_m 1 - cdf(m)^k`.
+            let expected: f64 = (0..20)
+                .map(|m| 1.0 - binomial.cdf(m).powf(k as f64)).sum();
+            let n = 5_000;
+            let mut sum = 0;
+            for _ in 0..n {
+                let x = binomial.sample_max(&mut rng, k);
+                assert!(x <= 20);
+                sum += x;
+            }
+            let mean = sum as f64 / n as f64;
+            assert!((mean - expected).abs() < 0.05 * expected, "k = {}", k);
+            assert!(mean > last_mean);
+            last_mean = mean;
+        }
+
+        // The search must also work when the tail reaches `n`.
+        let binomial = Binomial::new(3, 0.5).unwrap();
+        let max = (0..1000).map(|_| binomial.sample_max(&mut rng, 1000)).min().unwrap();
+        assert_eq!(max, 3);
+        assert_eq!(Binomial::new(10, 0.0).unwrap().sample_max(&mut rng, 100), 0);
+
+        // The mode is computed without overflow.
+        let binomial = Binomial::new(::core::u64::MAX, 0.5).unwrap();
+        for _ in 0..10 {
+            assert!(binomial.sample_max(&mut rng, 1000) >= 1 << 63);
+        }
+    }
+
+    #[test]
+    fn test_binomial_entropy_bits() {
+        let bits = |n| Binomial::new(n, 0.3).unwrap().entropy_bits().unwrap();
+        assert_eq!(bits(10), 640.0);
+        assert_eq!(bits(20), 2.0 * bits(10));
+        assert_eq!(bits(0), 0.0);
+        assert_eq!(Binomial::new(10, 1.0).unwrap().entropy_bits(), Some(0.0));
+        assert_eq!(Binomial::new(1000, 0.3).unwrap().entropy_bits(), None);
+    }
+
+    #[test]
+    fn test_binomial_from_ratio() {
+        let mut rng = ::test::rng(357);
+        let ratio = Binomial::from_ratio(10, 1, 3).unwrap();
+        let float = Binomial::new(10, 1.0 / 3.0).unwrap();
+        let count = 100_000;
+        let ratio_mean = (0..count).map(|_| ratio.sample(&mut rng)).sum::<u64>() as f64
+            / count as f64;
+        let float_mean = (0..count).map(|_| float.sample(&mut rng)).sum::<u64>() as f64
+            / count as f64;
+        assert!((ratio_mean - 10.0 / 3.0).abs() < 0.02);
+        assert!((ratio_mean - float_mean).abs() < 0.03);
+
+        // The integer path only depends on the random numbers.
+        let mut rng = ::test::rng(358);
+        let v = ratio.sample_vec(&mut rng, 10);
+        assert_eq!(v, [3, 3, 4, 5, 3, 3, 3, 5, 4, 7]);
+
+        // large n falls back to the general algorithm
+        let large = Binomial::from_ratio(1000, 1, 4).unwrap();
+        let mean = (0..1000).map(|_| large.sample(&mut rng)).sum::<u64>() as f64 / 1000.0;
+        assert!((mean - 250.0).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_binomial_from_ratio_invalid() {
+        assert_eq!(Binomial::from_ratio(10, 0, 3).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Binomial::from_ratio(10, 3, 3).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Binomial::from_ratio(10, 4, 3).unwrap_err(), DistError::ProbabilityOutOfRange);
+    }
+
+    #[test]
+    fn test_binomial_fit() {
+        let mut rng = ::test::rng(359);
+        for &(n, p) in [(10, 0.3), (50, 0.05), (1000, 0.9)].iter() {
+            let samples = Binomial::new(n, p).unwrap().sample_vec(&mut rng, 10_000);
+            let fitted = Binomial::fit(&samples, n).unwrap();
+            assert_eq!(fitted.n, n);
+            assert!((fitted.p - p).abs() < 0.01);
+        }
+
+        // Estimates on the end points are clamped into `(0, 1)`.
+        let fitted = Binomial::fit(&[0, 0, 0], 5).unwrap();
+        assert!(fitted.p > 0.0 && fitted.p < 1e-10);
+        let fitted = Binomial::fit(&[5, 5], 5).unwrap();
+        assert!(fitted.p < 1.0 && fitted.p > 1.0 - 1e-10);
+    }
+
+    #[test]
+    fn test_binomial_from_beta_posterior() {
+        let mut rng = ::test::rng(194);
+        let mean = |distr: &::distributions::BetaBinomial, rng: &mut ::test::TestRng<_>| {
+            (0..20_000).map(|_| distr.sample(rng) as f64).sum::<f64>() / 20_000.0
+        };
+        // No observations give a uniform prior, with mean `n / 2`.
+        let prior = Binomial::from_beta_posterior(10, 0, 0);
+        assert_eq!(prior.mean(), 5.0);
+        assert!((mean(&prior, &mut rng) - 5.0).abs() < 0.1);
+
+        // Many successes shift the predictive mean towards `n`: the
+        // posterior mean of `p` is `(990 + 1) / (1000 + 2)`.
+        let posterior = Binomial::from_beta_posterior(10, 990, 10);
+        assert!((posterior.mean() - 10.0 * 991.0 / 1002.0).abs() < 1e-12);
+        let m = mean(&posterior, &mut rng);
+        assert!(m > 9.8 && m < 9.95, "{}", m);
+    }
+
+    #[test]
+    fn test_binomial_fit_invalid() {
+        assert_eq!(Binomial::fit(&[0, 1], 0).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(Binomial::fit(&[], 10).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(Binomial::fit(&[3, 11], 10).unwrap_err(), DistError::SampleOutOfRange);
+    }
+
     #[test]
     fn test_binomial_end_points() {
         let mut rng = ::test::rng(352);
-        assert_eq!(rng.sample(Binomial::new(20, 0.0)), 0);
-        assert_eq!(rng.sample(Binomial::new(20, 1.0)), 20);
+        assert_eq!(rng.sample(Binomial::new(20, 0.0).unwrap()), 0);
+        assert_eq!(rng.sample(Binomial::new(20, 1.0).unwrap()), 20);
     }
 
     #[test]
-    #[should_panic]
-    fn test_binomial_invalid_lambda_neg() {
-        Binomial::new(20, -10.0);
+    fn test_binomial_invalid_p() {
+        assert_eq!(Binomial::new(20, -10.0).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Binomial::new(20, 1.5).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Binomial::new(20, ::std::f64::NAN).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
     }
 }
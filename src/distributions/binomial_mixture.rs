@@ -0,0 +1,182 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A mixture of binomial distributions.
+
+use Rng;
+use distributions::{Distribution, DistError, Binomial, WeightedIndex};
+use distributions::dirichlet::fmt_list;
+use std::fmt;
+
+/// A finite mixture of [`Binomial`] distributions with the same number of
+/// trials `n`.
+///
+/// Each sample first chooses a component with probability proportional to
+/// its weight, then samples the number of successes from that component.
+/// This models count data from a population of a few groups with different
+/// probabilities of success, e.g. the number of defective items in batches
+/// from several machines.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Binomial, BinomialMixture, Distribution};
+///
+/// let mixture = BinomialMixture::new(vec![
+///     (3.0, Binomial::new(10, 0.1).unwrap()),
+///     (1.0, Binomial::new(10, 0.8).unwrap()),
+/// ]).unwrap();
+/// let v = mixture.sample(&mut rand::thread_rng());
+/// assert!(v <= 10);
+/// ```
+///
+/// [`Binomial`]: struct.Binomial.html
+#[derive(Clone, Debug)]
+pub struct BinomialMixture {
+    /// Weight of each component, summing to 1.
+    weights: Vec<f64>,
+    components: Vec<Binomial>,
+    index: WeightedIndex<f64>,
+}
+
+impl BinomialMixture {
+    /// Construct a new `BinomialMixture` from `(weight, component)` pairs.
+    /// The weights are normalized to sum to 1.
+    ///
+    /// # Errors
+    /// - if `components` is empty
+    /// - if any weight is negative, infinite or NaN, or all are zero
+    /// - if the components don't all have the same number of trials
+    ///
+    pub fn new(components: Vec<(f64, Binomial)>) -> Result<BinomialMixture, DistError> {
+        if components.is_empty() {
+            return Err(DistError::SizeTooSmall);
+        }
+        if !components.iter().all(|&(w, _)| w >= 0.0 && w.is_finite()) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        let total: f64 = components.iter().map(|&(w, _)| w).sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        let n = components[0].1.n();
+        if components.iter().any(|&(_, ref c)| c.n() != n) {
+            return Err(DistError::DimensionMismatch);
+        }
+        let weights: Vec<f64> = components.iter().map(|&(w, _)| w / total).collect();
+        let index = WeightedIndex::new(&weights)
+            .map_err(|_| DistError::ProbabilityOutOfRange)?;
+        Ok(BinomialMixture {
+            weights,
+            components: components.into_iter().map(|(_, c)| c).collect(),
+            index,
+        })
+    }
+
+    /// Construct a new `BinomialMixture`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    /// - if `components` is empty
+    /// - if any weight is negative, infinite or NaN, or all are zero
+    /// - if the components don't all have the same number of trials
+    ///
+    pub fn new_unchecked(components: Vec<(f64, Binomial)>) -> BinomialMixture {
+        BinomialMixture::new(components)
+            .expect("BinomialMixture::new_unchecked called with invalid components")
+    }
+
+    /// Probability mass function: the probability of exactly `k` successes,
+    /// the weighted sum of the probabilities under each component.
+    pub fn pmf(&self, k: u64) -> f64 {
+        self.weights.iter().zip(self.components.iter())
+            .map(|(&w, c)| w * c.pmf(k))
+            .sum()
+    }
+
+    /// The mean of the distribution, the weighted mean of the means of the
+    /// components.
+    pub fn mean(&self) -> f64 {
+        self.weights.iter().zip(self.components.iter())
+            .map(|(&w, c)| w * c.mean())
+            .sum()
+    }
+}
+
+impl Distribution<u64> for BinomialMixture {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.components[self.index.sample(rng)].sample(rng)
+    }
+}
+
+impl fmt::Display for BinomialMixture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p: Vec<f64> = self.components.iter().map(|c| c.p()).collect();
+        write!(f, "BinomialMixture(n={}, weights=", self.components[0].n())?;
+        fmt_list(f, &self.weights)?;
+        write!(f, ", p=")?;
+        fmt_list(f, &p)?;
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError, Binomial};
+    use super::BinomialMixture;
+
+    fn mixture() -> BinomialMixture {
+        BinomialMixture::new(vec![
+            (1.0, Binomial::new(20, 0.1).unwrap()),
+            (2.0, Binomial::new(20, 0.5).unwrap()),
+            (1.0, Binomial::new(20, 0.9).unwrap()),
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_binomial_mixture_pmf() {
+        let mixture = mixture();
+        let total: f64 = (0..21).map(|k| mixture.pmf(k)).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(mixture.pmf(21), 0.0);
+        let mean: f64 = (0..21).map(|k| k as f64 * mixture.pmf(k)).sum();
+        // `(2 + 2 * 10 + 18) / 4`
+        assert!((mixture.mean() - 10.0).abs() < 1e-12);
+        assert!((mean - mixture.mean()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_binomial_mixture_sample() {
+        let mixture = mixture();
+        let mut rng = ::test::rng(206);
+        let n = 40_000;
+        let mut counts = [0u32; 21];
+        for _ in 0..n {
+            counts[mixture.sample(&mut rng) as usize] += 1;
+        }
+        for (k, &count) in counts.iter().enumerate() {
+            let expected = mixture.pmf(k as u64) * n as f64;
+            assert!((count as f64 - expected).abs() < 5.0 * expected.sqrt() + 5.0,
+                    "{}: {} != {}", k, count, expected);
+        }
+    }
+
+    #[test]
+    fn test_binomial_mixture_invalid() {
+        let b = Binomial::new(10, 0.5).unwrap();
+        assert_eq!(BinomialMixture::new(vec![]).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(BinomialMixture::new(vec![(1.0, b), (-1.0, b)]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(BinomialMixture::new(vec![(0.0, b), (0.0, b)]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(BinomialMixture::new(vec![(::std::f64::NAN, b)]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        let other = Binomial::new(11, 0.5).unwrap();
+        assert_eq!(BinomialMixture::new(vec![(1.0, b), (1.0, other)]).unwrap_err(),
+                   DistError::DimensionMismatch);
+    }
+}
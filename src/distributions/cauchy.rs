@@ -10,8 +10,9 @@
 //! The Cauchy distribution.
 
 use Rng;
-use distributions::Distribution;
+use distributions::{Distribution, AntitheticDistribution, InverseCdf, DistError, Open01};
 use std::f64::consts::PI;
+use std::fmt;
 
 /// The Cauchy distribution `Cauchy(median, scale)`.
 ///
@@ -23,7 +24,7 @@ use std::f64::consts::PI;
 /// ```
 /// use rand::distributions::{Cauchy, Distribution};
 ///
-/// let cau = Cauchy::new(2.0, 5.0);
+/// let cau = Cauchy::new(2.0, 5.0).unwrap();
 /// let v = cau.sample(&mut rand::thread_rng());
 /// println!("{} is from a Cauchy(2, 5) distribution", v);
 /// ```
@@ -36,32 +37,60 @@ pub struct Cauchy {
 impl Cauchy {
     /// Construct a new `Cauchy` with the given shape parameters
     /// `median` the peak location and `scale` the scale factor.
-    /// Panics if `scale <= 0`.
-    pub fn new(median: f64, scale: f64) -> Cauchy {
-        assert!(scale > 0.0, "Cauchy::new called with scale factor <= 0");
-        Cauchy {
+    /// Returns an error if `scale <= 0`.
+    pub fn new(median: f64, scale: f64) -> Result<Cauchy, DistError> {
+        if !(scale > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        Ok(Cauchy {
             median,
             scale
-        }
+        })
+    }
+
+    /// Construct a new `Cauchy`, like [`new`](#method.new).
+    ///
+    /// Panics if `scale <= 0`.
+    pub fn new_unchecked(median: f64, scale: f64) -> Cauchy {
+        Cauchy::new(median, scale).expect("Cauchy::new_unchecked called with scale <= 0")
     }
 }
 
 impl Distribution<f64> for Cauchy {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        // sample from [0, 1)
-        let x = rng.gen::<f64>();
+        self.inverse_cdf(rng.sample(Open01))
+    }
+}
+
+impl InverseCdf for Cauchy {
+    fn inverse_cdf(&self, p: f64) -> f64 {
         // get standard cauchy random number
-        // note that π/2 is not exactly representable, even if x=0.5 the result is finite
-        let comp_dev = (PI * x).tan();
+        // note that π/2 is not exactly representable, so the result is finite
+        // for all `p` in (0, 1)
+        let comp_dev = (PI * (p - 0.5)).tan();
         // shift and scale according to parameters
-        let result = self.median + self.scale * comp_dev;
-        result
+        self.median + self.scale * comp_dev
+    }
+}
+
+impl AntitheticDistribution for Cauchy {
+    fn sample_antithetic<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        // `1 - u` is exact for `u` from `Open01`, so the pair is mirrored
+        // about the median.
+        let u: f64 = rng.sample(Open01);
+        (self.inverse_cdf(u), self.inverse_cdf(1.0 - u))
+    }
+}
+
+impl fmt::Display for Cauchy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cauchy(median={}, scale={})", self.median, self.scale)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use distributions::Distribution;
+    use distributions::{Distribution, DistError};
     use super::Cauchy;
 
     fn median(mut numbers: &mut [f64]) -> f64 {
@@ -76,7 +105,7 @@ mod test {
 
     #[test]
     fn test_cauchy_median() {
-        let cauchy = Cauchy::new(10.0, 5.0);
+        let cauchy = Cauchy::new(10.0, 5.0).unwrap();
         let mut rng = ::test::rng(123);
         let mut numbers: [f64; 1000] = [0.0; 1000];
         for i in 0..1000 {
@@ -89,7 +118,7 @@ mod test {
 
     #[test]
     fn test_cauchy_mean() {
-        let cauchy = Cauchy::new(10.0, 5.0);
+        let cauchy = Cauchy::new(10.0, 5.0).unwrap();
         let mut rng = ::test::rng(123);
         let mut sum = 0.0;
         for _ in 0..1000 {
@@ -102,14 +131,34 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_cauchy_invalid_scale_zero() {
-        Cauchy::new(0.0, 0.0);
+        assert_eq!(Cauchy::new(0.0, 0.0).unwrap_err(), DistError::ScaleNotPositive);
+    }
+
+    #[test]
+    fn test_cauchy_antithetic() {
+        use distributions::AntitheticDistribution;
+        // The variance is infinite, so check that the pairs are mirrored
+        // about the median, which makes them perfectly anti-monotone.
+        let cauchy = Cauchy::new(10.0, 5.0).unwrap();
+        let mut rng = ::test::rng(183);
+        let mut below = 0;
+        for _ in 0..1000 {
+            let (x, y) = cauchy.sample_antithetic(&mut rng);
+            assert!((x - 10.0) * (y - 10.0) <= 0.0);
+            assert!(((x - 10.0) + (y - 10.0)).abs() <= 1e-9 * (x - 10.0).abs());
+            if x < 10.0 { below += 1; }
+        }
+        assert!(below > 400 && below < 600);
     }
 
     #[test]
-    #[should_panic]
-    fn test_cauchy_invalid_scale_neg() {
-        Cauchy::new(0.0, -10.0);
+    fn test_cauchy_inverse_cdf() {
+        use distributions::InverseCdf;
+        let cauchy = Cauchy::new(10.0, 5.0).unwrap();
+        assert_eq!(cauchy.inverse_cdf(0.5), 10.0);
+        // The quartiles are one scale from the median.
+        assert!((cauchy.inverse_cdf(0.25) - 5.0).abs() < 1e-12);
+        assert!((cauchy.inverse_cdf(0.75) - 15.0).abs() < 1e-12);
     }
 }
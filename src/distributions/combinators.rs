@@ -0,0 +1,391 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Distributions built by transforming other distributions.
+
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use Rng;
+use distributions::{Distribution, DistError};
+
+/// Maximum number of samples drawn by `Filter` for a single result before
+/// giving up.
+const MAX_FILTER_ITERATIONS: u32 = 1_000_000;
+
+/// A distribution scaling and shifting the samples of another distribution,
+/// returning `scale * x + shift` for each sample `x`.
+///
+/// This `struct` is created by the [`affine`] method on [`Distribution`].
+/// See its documentation for more.
+///
+/// [`Distribution`]: trait.Distribution.html
+/// [`affine`]: trait.Distribution.html#method.affine
+#[derive(Clone, Copy, Debug)]
+pub struct Affine<D, T> {
+    pub(crate) distr: D,
+    pub(crate) scale: T,
+    pub(crate) shift: T,
+}
+
+impl<D, T> Distribution<T> for Affine<D, T>
+    where D: Distribution<T>, T: Copy + Mul<Output = T> + Add<Output = T>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.scale * self.distr.sample(rng) + self.shift
+    }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        self.distr.entropy_bits()
+    }
+}
+
+/// A distribution negating the samples of another distribution with
+/// probability one half.
+///
+/// This `struct` is created by the [`with_random_sign`] method on
+/// [`Distribution`]. See its documentation for more.
+///
+/// [`Distribution`]: trait.Distribution.html
+/// [`with_random_sign`]: trait.Distribution.html#method.with_random_sign
+#[derive(Clone, Copy, Debug)]
+pub struct RandomSign<D> {
+    pub(crate) distr: D,
+}
+
+impl<D, T> Distribution<T> for RandomSign<D>
+    where D: Distribution<T>, T: Neg<Output = T>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let x = self.distr.sample(rng);
+        if rng.gen_sign() < 0 { -x } else { x }
+    }
+
+    /// The sign uses one `u32`.
+    fn entropy_bits(&self) -> Option<f64> {
+        self.distr.entropy_bits().map(|bits| bits + 32.0)
+    }
+}
+
+/// A distribution mirroring the samples of another distribution about a
+/// center with probability one half.
+///
+/// This `struct` is created by the [`reflect`] method on [`Distribution`].
+/// See its documentation for more.
+///
+/// [`Distribution`]: trait.Distribution.html
+/// [`reflect`]: trait.Distribution.html#method.reflect
+#[derive(Clone, Copy, Debug)]
+pub struct Reflect<D, T> {
+    pub(crate) distr: D,
+    pub(crate) center: T,
+}
+
+impl<D, T> Distribution<T> for Reflect<D, T>
+    where D: Distribution<T>, T: Copy + Sub<Output = T>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let x = self.distr.sample(rng);
+        if rng.gen_sign() < 0 { self.center - (x - self.center) } else { x }
+    }
+
+    /// The choice uses one `u32`.
+    fn entropy_bits(&self) -> Option<f64> {
+        self.distr.entropy_bits().map(|bits| bits + 32.0)
+    }
+}
+
+/// A distribution keeping only the samples of another distribution which
+/// satisfy a predicate.
+///
+/// This `struct` is created by the [`filter`] method on [`Distribution`].
+/// See its documentation for more.
+///
+/// [`Distribution`]: trait.Distribution.html
+/// [`filter`]: trait.Distribution.html#method.filter
+#[derive(Clone, Copy)]
+pub struct Filter<D, F> {
+    pub(crate) distr: D,
+    pub(crate) pred: F,
+}
+
+impl<D, F, T> Distribution<T> for Filter<D, F>
+    where D: Distribution<T>, F: Fn(&T) -> bool
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        for _ in 0..MAX_FILTER_ITERATIONS {
+            let x = self.distr.sample(rng);
+            if (self.pred)(&x) {
+                return x;
+            }
+        }
+        panic!("Filter: no sample accepted after {} attempts; the predicate may \
+                never hold", MAX_FILTER_ITERATIONS);
+    }
+}
+
+impl<D: fmt::Debug, F> fmt::Debug for Filter<D, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Filter")
+            .field("distr", &self.distr)
+            .finish()
+    }
+}
+
+/// A distribution sampling two distributions independently and returning
+/// the pair of their samples.
+///
+/// This `struct` is created by the [`zip`] method on [`Distribution`].
+/// See its documentation for more.
+///
+/// [`Distribution`]: trait.Distribution.html
+/// [`zip`]: trait.Distribution.html#method.zip
+#[derive(Clone, Copy, Debug)]
+pub struct Zip<D1, D2> {
+    pub(crate) first: D1,
+    pub(crate) second: D2,
+}
+
+impl<D1, D2, A, B> Distribution<(A, B)> for Zip<D1, D2>
+    where D1: Distribution<A>, D2: Distribution<B>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (A, B) {
+        let a = self.first.sample(rng);
+        (a, self.second.sample(rng))
+    }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        match (self.first.entropy_bits(), self.second.entropy_bits()) {
+            (Some(a), Some(b)) => Some(a + b),
+            _ => None,
+        }
+    }
+}
+
+/// A distribution binning the real-valued samples of another distribution,
+/// returning the index `floor((x - origin) / bin_width)` of the bin
+/// containing each sample `x`.
+///
+/// Bin `0` is `[origin, origin + bin_width)`. Indices beyond the range of
+/// `i64` are clamped to it, and NaN samples give `i64::MIN`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Discretize, Distribution, Uniform};
+///
+/// // Tenths of the unit interval.
+/// let decile = Discretize::new(Uniform::new(0.0, 1.0), 0.1, 0.0).unwrap();
+/// let i = decile.sample(&mut rand::thread_rng());
+/// assert!(i >= 0 && i < 10);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Discretize<D> {
+    distr: D,
+    bin_width: f64,
+    origin: f64,
+}
+
+impl<D: Distribution<f64>> Discretize<D> {
+    /// Construct a new `Discretize`, binning the samples of `distr` in bins
+    /// of width `bin_width`, starting at `origin`.
+    ///
+    /// Returns an error if `bin_width <= 0`, `bin_width` is infinite or NaN.
+    pub fn new(distr: D, bin_width: f64, origin: f64) -> Result<Discretize<D>, DistError> {
+        if !(bin_width > 0.0 && bin_width < ::core::f64::INFINITY) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        Ok(Discretize { distr, bin_width, origin })
+    }
+
+    /// Construct a new `Discretize`, like [`new`](#method.new).
+    ///
+    /// Panics if `bin_width <= 0`, `bin_width` is infinite or NaN.
+    pub fn new_unchecked(distr: D, bin_width: f64, origin: f64) -> Discretize<D> {
+        Discretize::new(distr, bin_width, origin)
+            .expect("Discretize::new_unchecked called with invalid bin_width")
+    }
+}
+
+impl<D: Distribution<f64>> Distribution<i64> for Discretize<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i64 {
+        use core::i64;
+        let y = (self.distr.sample(rng) - self.origin) / self.bin_width;
+        // Casting a float outside the range of `i64` is undefined before
+        // Rust 1.45, so clamp it first. `i64::MAX as f64` rounds up to
+        // `2^63`, which is out of range itself.
+        if y >= i64::MAX as f64 {
+            return i64::MAX;
+        }
+        let y = if y >= i64::MIN as f64 { y } else { i64::MIN as f64 };
+        // `floor` without `std`: the cast truncates towards zero.
+        let i = y as i64;
+        if (i as f64) > y { i.saturating_sub(1) } else { i }
+    }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        self.distr.entropy_bits()
+    }
+}
+
+#[cfg(all(test, feature="std"))]
+mod test {
+    use distributions::{Distribution, DistError, Normal, StandardNormal, Exp, Uniform, Binomial};
+    use super::Discretize;
+
+    #[test]
+    fn test_affine() {
+        let mut rng = ::test::rng(391);
+        let distr = Normal::new(0.0, 1.0).unwrap().affine(3.0, -2.0);
+        let samples: Vec<f64> = distr.sample_iter(&mut rng).take(100_000).collect();
+        ::test::assert_moments(&samples, -2.0, 9.0, 0.02);
+
+        // The sample type is inferred from the parameters.
+        let x: f32 = StandardNormal.affine(0.0f32, 5.0).sample(&mut rng);
+        assert_eq!(x, 5.0);
+    }
+
+    #[test]
+    fn test_random_sign() {
+        let mut rng = ::test::rng(392);
+        let distr = Exp::new(1.0).unwrap().with_random_sign();
+        let samples: Vec<f64> = distr.sample_iter(&mut rng).take(100_000).collect();
+        // Laplace distribution with unit scale: mean 0 and variance 2
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let var = samples.iter().map(|x| x * x).sum::<f64>() / 100_000.0;
+        assert!(mean.abs() < 0.02);
+        assert!((var - 2.0).abs() < 0.04);
+
+        let positive = samples.iter().filter(|&&x| x > 0.0).count();
+        assert!((positive as f64 / 100_000.0 - 0.5).abs() < 0.01);
+        // Symmetric quantiles
+        let mut sorted = samples.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for &q in [0.1, 0.25].iter() {
+            let lo = sorted[(q * 100_000.0) as usize];
+            let hi = sorted[((1.0 - q) * 100_000.0) as usize];
+            assert!((lo + hi).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_reflect() {
+        let mut rng = ::test::rng(191);
+        let distr = Exp::new(1.0).unwrap().reflect(0.0);
+        let samples: Vec<f64> = distr.sample_iter(&mut rng).take(100_000).collect();
+        // Laplace distribution with unit scale: mean 0 and variance 2
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let var = samples.iter().map(|x| x * x).sum::<f64>() / 100_000.0;
+        assert!(mean.abs() < 0.02);
+        assert!((var - 2.0).abs() < 0.04);
+        let positive = samples.iter().filter(|&&x| x > 0.0).count();
+        assert!((positive as f64 / 100_000.0 - 0.5).abs() < 0.01);
+
+        // About another center, the mirror image of `[4, 6)` is `(2, 4]`.
+        let distr = Uniform::new(4.0, 6.0).reflect(4.0);
+        let mut below = 0;
+        for _ in 0..10_000 {
+            let x = distr.sample(&mut rng);
+            assert!(x > 2.0 && x < 6.0);
+            if x <= 4.0 { below += 1; }
+        }
+        assert!((below as f64 / 10_000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut rng = ::test::rng(201);
+        let half_normal = Normal::new(0.0, 1.0).unwrap().filter(|x| *x > 0.0);
+        let samples: Vec<f64> = half_normal.sample_iter(&mut rng).take(100_000).collect();
+        assert!(samples.iter().all(|&x| x > 0.0));
+        // Mean `sqrt(2 / π)` and variance `1 - 2 / π`.
+        let pi = ::std::f64::consts::PI;
+        ::test::assert_moments(&samples, (2.0 / pi).sqrt(), 1.0 - 2.0 / pi, 0.02);
+
+        // Filters compose with the other combinators.
+        let distr = Uniform::new(0u32, 100).filter(|x| x % 2 == 0).filter(|&x| x < 10);
+        for _ in 0..100 {
+            let x = distr.sample(&mut rng);
+            assert!(x % 2 == 0 && x < 10);
+        }
+        assert_eq!(format!("{:?}", Exp::new(1.0).unwrap().filter(|_| true)),
+                   "Filter { distr: Exp { lambda: 1.0, lambda_inverse: 1.0 } }");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_filter_never() {
+        let mut rng = ::test::rng(202);
+        Uniform::new(0.0, 1.0).filter(|x| *x > 1.0).sample(&mut rng);
+    }
+
+    #[test]
+    fn test_zip() {
+        let mut rng = ::test::rng(210);
+        let distr = Binomial::new(10, 0.5).unwrap().zip(Normal::new(0.0, 1.0).unwrap());
+        let (counts, normals): (Vec<f64>, Vec<f64>) = distr.sample_iter(&mut rng)
+            .take(100_000)
+            .map(|(k, x): (u64, f64)| (k as f64, x))
+            .unzip();
+        assert!(counts.iter().all(|&k| k >= 0.0 && k <= 10.0));
+        ::test::assert_moments(&counts, 5.0, 2.5, 0.02);
+        let mean = normals.iter().sum::<f64>() / 100_000.0;
+        let var = normals.iter().map(|x| x * x).sum::<f64>() / 100_000.0;
+        assert!(mean.abs() < 0.02);
+        assert!((var - 1.0).abs() < 0.02);
+        // The components are independent.
+        assert!(::test::correlation(&counts, &normals).abs() < 0.02);
+
+        // Zips nest, and compose with the other combinators.
+        let distr = Uniform::new(0u32, 3).zip(Uniform::new(0.0, 1.0).affine(2.0, 1.0))
+            .zip(::distributions::Dirac::new('x'));
+        let ((i, x), c) = distr.sample(&mut rng);
+        assert!(i < 3 && x >= 1.0 && x < 3.0 && c == 'x');
+    }
+
+    #[test]
+    fn test_discretize() {
+        let mut rng = ::test::rng(175);
+        let distr = Discretize::new(Uniform::new(-1.0, 1.5), 0.5, -1.0).unwrap();
+        let mut counts = [0u32; 5];
+        for _ in 0..50_000 {
+            let i = distr.sample(&mut rng);
+            assert!(i >= 0 && i < 5);
+            counts[i as usize] += 1;
+        }
+        for &count in counts.iter() {
+            assert!((count as f64 - 10_000.0).abs() < 500.0, "{:?}", counts);
+        }
+
+        // Negative values are floored, not truncated.
+        let constant = Discretize::new(::distributions::Dirac::new(-0.25), 1.0, 0.0).unwrap();
+        assert_eq!(constant.sample(&mut rng), -1);
+        let constant = Discretize::new(::distributions::Dirac::new(-1.0), 0.5, 0.0).unwrap();
+        assert_eq!(constant.sample(&mut rng), -2);
+
+        // Indices out of range are clamped.
+        for &(x, i) in [(1e300, ::std::i64::MAX), (9223372036854775808.0, ::std::i64::MAX),
+                        (-1e300, ::std::i64::MIN), (::std::f64::NAN, ::std::i64::MIN)].iter() {
+            let constant = Discretize::new(::distributions::Dirac::new(x), 1.0, 0.0).unwrap();
+            assert_eq!(constant.sample(&mut rng), i);
+        }
+    }
+
+    #[test]
+    fn test_discretize_invalid() {
+        let u = Uniform::new(0.0, 1.0);
+        assert_eq!(Discretize::new(u, 0.0, 0.0).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Discretize::new(u, -1.0, 0.0).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Discretize::new(u, ::std::f64::NAN, 0.0).unwrap_err(),
+                   DistError::ScaleNotPositive);
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests shared by the parametrized distributions: their `Display` output
+//! and the panics of their `*_unchecked` constructors.
+//!
+//! Tests of the behaviour of a single distribution belong in its own module.
+
+use std::fmt;
+use std::panic;
+
+use distributions::{Bernoulli, Beta, BetaBinomial, Binomial, BinomialMixture,
+    BivariateNormal, BoundedPareto, Cauchy, ChiSquared, Dice, Dirichlet,
+    DirichletMultinomial, DiscreteWeibull, Erlang, Exp, FisherF, Frechet, Gamma,
+    GeneralizedExtremeValue, Geometric, Hypoexponential, InverseGamma, InverseGaussian,
+    Levy, LogNormal, LogUniform, Multinomial, NegativeBinomial, NegativeHypergeometric,
+    Normal, NormalInverseGamma, Pareto, Pert, PiecewiseLinearCdf, Poisson, PoissonProcess,
+    SkewNormal, Stable, StudentT, Triangular, TwoStateMarkov, UniformSimplex, Wald,
+    Weibull, Zeta};
+
+#[test]
+fn test_display() {
+    let mixture = BinomialMixture::new(vec![
+        (1.0, Binomial::new(10, 0.25).unwrap()),
+        (3.0, Binomial::new(10, 0.5).unwrap()),
+    ]).unwrap();
+    let cases: &[(&fmt::Display, &str)] = &[
+        (&Bernoulli::new(0.3).unwrap(), "Bernoulli(p=0.3)"),
+        (&Bernoulli::new(1.0).unwrap(), "Bernoulli(p=1)"),
+        (&Bernoulli::new(0.0).unwrap(), "Bernoulli(p=0)"),
+        (&Beta::new(2.0, 0.7).unwrap(), "Beta(alpha=2, beta=0.7)"),
+        (&BetaBinomial::new(10, 0.5, 2.0).unwrap(), "BetaBinomial(n=10, alpha=0.5, beta=2)"),
+        (&Binomial::new(20, 0.3).unwrap(), "Binomial(n=20, p=0.3)"),
+        (&Binomial::from_ratio(5, 1, 4).unwrap(), "Binomial(n=5, p=0.25)"),
+        (&mixture, "BinomialMixture(n=10, weights=[0.25, 0.75], p=[0.25, 0.5])"),
+        (&BivariateNormal::new([0.0, 1.0], [1.0, 2.5], -0.2).unwrap(),
+         "BivariateNormal(mean=[0, 1], std_dev=[1, 2.5], rho=-0.2)"),
+        (&BoundedPareto::new(1.5, 0.3, 8.0).unwrap(),
+         "BoundedPareto(scale=1.5, shape=0.3, upper=8)"),
+        (&Cauchy::new(2.0, 5.0).unwrap(), "Cauchy(median=2, scale=5)"),
+        (&ChiSquared::new(1.0).unwrap(), "ChiSquared(k=1)"),
+        (&ChiSquared::new(0.3).unwrap(), "ChiSquared(k=0.3)"),
+        (&Dice::new(3, 6).unwrap(), "Dice(count=3, sides=6)"),
+        (&Dirichlet::new(vec![1.0, 2.5, 3.0]).unwrap(), "Dirichlet(alpha=[1, 2.5, 3])"),
+        (&DirichletMultinomial::new(10, &[0.5, 2.0]).unwrap(),
+         "DirichletMultinomial(n=10, alpha=[0.5, 2])"),
+        (&DiscreteWeibull::new(0.5, 1.5).unwrap(), "DiscreteWeibull(q=0.5, beta=1.5)"),
+        (&Erlang::new(3, 0.5).unwrap(), "Erlang(k=3, rate=0.5)"),
+        (&Exp::new(0.3).unwrap(), "Exp(lambda=0.3)"),
+        (&FisherF::new(2.0, 32.0).unwrap(), "FisherF(m=2, n=32)"),
+        (&Frechet::new(-1.0, 1.5, 0.3).unwrap(), "Frechet(location=-1, scale=1.5, shape=0.3)"),
+        (&Gamma::new(0.3, 2.5).unwrap(), "Gamma(shape=0.3, scale=2.5)"),
+        (&GeneralizedExtremeValue::new(-1.0, 1.5, 0.3).unwrap(),
+         "GeneralizedExtremeValue(location=-1, scale=1.5, shape=0.3)"),
+        (&Geometric::new(0.25).unwrap(), "Geometric(p=0.25)"),
+        (&Hypoexponential::new(&[1.0, 2.5]).unwrap(), "Hypoexponential(rates=[1, 2.5])"),
+        (&InverseGamma::new(3.0, 0.5).unwrap(), "InverseGamma(shape=3, scale=0.5)"),
+        (&InverseGaussian::new(1.0, 2.5).unwrap(), "InverseGaussian(mean=1, shape=2.5)"),
+        (&Levy::new(-1.0, 0.5).unwrap(), "Levy(location=-1, scale=0.5)"),
+        (&LogNormal::new(0.1, 2.0).unwrap(), "LogNormal(mean=0.1, std_dev=2)"),
+        (&LogUniform::new(20.0, 2e4).unwrap(), "LogUniform(low=20, high=20000)"),
+        (&Multinomial::new(10, &[0.25, 0.75]).unwrap(), "Multinomial(n=10, p=[0.25, 0.75])"),
+        (&NegativeBinomial::new(3.0, 0.25).unwrap(), "NegativeBinomial(r=3, p=0.25)"),
+        (&NegativeHypergeometric::new(52, 13, 2).unwrap(),
+         "NegativeHypergeometric(population=52, successes=13, failures_before_stop=2)"),
+        (&Normal::new(2.0, 0.5).unwrap(), "Normal(mean=2, std_dev=0.5)"),
+        (&Normal::new(-1.5f32, 3.0).unwrap(), "Normal(mean=-1.5, std_dev=3)"),
+        (&NormalInverseGamma::new(0.5, 1.0, 2.0, 3.0).unwrap(),
+         "NormalInverseGamma(mu=0.5, lambda=1, alpha=2, beta=3)"),
+        (&Pareto::new(1.5, 0.3).unwrap(), "Pareto(scale=1.5, shape=0.3)"),
+        (&Pert::new(0.0, 1.0, 2.5, 4.0).unwrap(), "Pert(min=0, mode=1, max=2.5, shape=4)"),
+        (&Poisson::new(2.5).unwrap(), "Poisson(lambda=2.5)"),
+        (&Poisson::new_truncated(2.5, 4).unwrap(), "Poisson(lambda=2.5, max=4)"),
+        (&PoissonProcess::new(2.5).unwrap(), "PoissonProcess(rate=2.5)"),
+        (&SkewNormal::new(0.5, 2.0, -3.0).unwrap(), "SkewNormal(location=0.5, scale=2, shape=-3)"),
+        (&Stable::new(1.5, -0.5, 2.0, 1.0).unwrap(),
+         "Stable(alpha=1.5, beta=-0.5, scale=2, location=1)"),
+        (&StudentT::new(11.0).unwrap(), "StudentT(n=11)"),
+        (&Triangular::new(0.0, 2.5, 1.0).unwrap(), "Triangular(min=0, max=2.5, mode=1)"),
+        (&TwoStateMarkov::new(0.9, 0.5).unwrap(),
+         "TwoStateMarkov(p_stay_on=0.9, p_stay_off=0.5)"),
+        (&UniformSimplex::new(3).unwrap(), "UniformSimplex(dim=3)"),
+        (&Wald::with_location(1.0, 2.5, 3.0).unwrap(), "Wald(mean=1, shape=2.5, location=3)"),
+        (&Weibull::new(1.5, 0.3).unwrap(), "Weibull(scale=1.5, shape=0.3)"),
+        (&Zeta::new(1.5).unwrap(), "Zeta(s=1.5)"),
+    ];
+    for &(distr, expected) in cases.iter() {
+        assert_eq!(distr.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_unchecked_invalid() {
+    // Each constructor is called with parameters its checked version rejects.
+    let cases: &[(&str, fn())] = &[
+        ("Bernoulli::new", || { Bernoulli::new_unchecked(2.0); }),
+        ("Bernoulli::from_ratio", || { Bernoulli::from_ratio_unchecked(3, 2); }),
+        ("Beta", || { Beta::new_unchecked(0., 0.); }),
+        ("BetaBinomial", || { BetaBinomial::new_unchecked(5, ::std::f64::NAN, 1.0); }),
+        ("Binomial", || { Binomial::new_unchecked(20, -10.0); }),
+        ("BinomialMixture", || { BinomialMixture::new_unchecked(vec![]); }),
+        ("BivariateNormal", || {
+            BivariateNormal::new_unchecked([0.0; 2], [1.0; 2], ::std::f64::NAN);
+        }),
+        ("BoundedPareto", || { BoundedPareto::new_unchecked(1., 1., 0.5); }),
+        ("Cauchy", || { Cauchy::new_unchecked(0.0, -10.0); }),
+        ("ChiSquared", || { ChiSquared::new_unchecked(-1.0); }),
+        ("Dice", || { Dice::new_unchecked(1, 0); }),
+        ("Dirichlet", || { Dirichlet::new_with_param_unchecked(0.0f64, 2); }),
+        ("DiscreteWeibull", || { DiscreteWeibull::new_unchecked(0.5, -1.0); }),
+        ("Erlang", || { Erlang::new_unchecked(1, -1.0); }),
+        ("Exp", || { Exp::new_unchecked(-10.0); }),
+        ("FisherF", || { FisherF::new_unchecked(0.0, 1.0); }),
+        ("Frechet", || { Frechet::new_unchecked(0., -1., 1.); }),
+        ("Gamma", || { Gamma::new_unchecked(-1.0, 1.0); }),
+        ("GeneralizedExtremeValue", || { GeneralizedExtremeValue::new_unchecked(0., -1., 0.); }),
+        ("Geometric", || { Geometric::new_unchecked(0.0); }),
+        ("Hypoexponential", || { Hypoexponential::new_unchecked(&[-1.0]); }),
+        ("InverseGamma", || { InverseGamma::new_unchecked(-1.0, 1.0); }),
+        ("InverseGaussian", || { InverseGaussian::new_unchecked(-1.0, 1.0); }),
+        ("Levy", || { Levy::new_unchecked(0.0, -2.0); }),
+        ("LogNormal", || { LogNormal::new_unchecked(0.0, -1.0); }),
+        ("LogUniform", || { LogUniform::new_unchecked(3.0, 1.0); }),
+        ("Multinomial", || { Multinomial::new_unchecked(10, &[]); }),
+        ("NegativeBinomial", || { NegativeBinomial::new_unchecked(-1.0, 0.5); }),
+        ("NegativeHypergeometric", || { NegativeHypergeometric::new_unchecked(10, 10, 1); }),
+        ("Normal", || { Normal::new_unchecked(10.0, -1.0); }),
+        ("NormalInverseGamma", || { NormalInverseGamma::new_unchecked(0.0, 1.0, 1.0, 0.0); }),
+        ("Pareto", || { Pareto::new_unchecked(0., 0.); }),
+        ("PiecewiseLinearCdf", || { PiecewiseLinearCdf::new_unchecked(&[1.0, 0.0], &[0.0, 1.0]); }),
+        ("Pert", || { Pert::new_unchecked(2.0, 1.0, 0.0, 4.0); }),
+        ("Poisson", || { Poisson::new_unchecked(-10.0); }),
+        ("Poisson::new_truncated", || { Poisson::new_truncated_unchecked(-1.0, 4); }),
+        ("PoissonProcess", || { PoissonProcess::new_unchecked(0.0); }),
+        ("SkewNormal", || { SkewNormal::new_unchecked(0.0, -1.0, 0.0); }),
+        ("Stable", || { Stable::new_unchecked(1.0, -2.0, 1.0, 0.0); }),
+        ("StudentT", || { StudentT::new_unchecked(0.0); }),
+        ("Triangular", || { Triangular::new_unchecked(0.0, 1.0, 2.0); }),
+        ("TwoStateMarkov", || { TwoStateMarkov::new_unchecked(2.0, 0.5); }),
+        ("UniformSimplex", || { UniformSimplex::new_unchecked(0); }),
+        ("Wald", || { Wald::new_unchecked(-1.0, 1.0); }),
+        ("Weibull", || { Weibull::new_unchecked(0., 0.); }),
+        ("Zeta", || { Zeta::new_unchecked(-2.0); }),
+    ];
+    for &(name, construct) in cases.iter() {
+        assert!(panic::catch_unwind(construct).is_err(), "{} did not panic", name);
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rolls of fair dice.
+
+use Rng;
+use distributions::{Distribution, DistError, Uniform};
+use core::fmt;
+#[cfg(all(feature="alloc", not(feature="std")))] use alloc::vec::Vec;
+
+/// The sum of rolling `count` fair dice with `sides` sides each, such as
+/// `3d6` in tabletop game notation.
+///
+/// Each die is uniformly distributed over `1..=sides`, so samples lie in
+/// `count..=count * sides`. The maximum sum `count * sides` should fit in a
+/// `u32`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Dice, Distribution};
+///
+/// let three_d6 = Dice::new(3, 6).unwrap();
+/// let v = three_d6.sample(&mut rand::thread_rng());
+/// println!("rolled {} on 3d6", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Dice {
+    count: u32,
+    sides: u32,
+    die: Uniform<u32>,
+}
+
+impl Dice {
+    /// Construct a new `Dice` rolling `count` dice with `sides` sides each.
+    ///
+    /// Returns an error if `sides == 0`.
+    pub fn new(count: u32, sides: u32) -> Result<Dice, DistError> {
+        if sides == 0 {
+            return Err(DistError::SizeTooSmall);
+        }
+        Ok(Dice { count, sides, die: Uniform::new_inclusive(1, sides) })
+    }
+
+    /// Construct a new `Dice`, like [`new`](#method.new).
+    ///
+    /// Panics if `sides == 0`.
+    pub fn new_unchecked(count: u32, sides: u32) -> Dice {
+        Dice::new(count, sides).expect("Dice::new_unchecked called with sides == 0")
+    }
+
+    /// Roll the dice, returning the value of each die rather than the sum.
+    #[cfg(feature="alloc")]
+    pub fn roll_detailed<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u32> {
+        (0..self.count).map(|_| self.die.sample(rng)).collect()
+    }
+}
+
+impl Distribution<u32> for Dice {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
+        (0..self.count).map(|_| self.die.sample(rng)).sum()
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Dice(count={}, sides={})", self.count, self.sides)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::Dice;
+
+    #[test]
+    fn test_dice_range() {
+        let mut rng = ::test::rng(390);
+        for &(count, sides) in [(0, 6), (1, 1), (3, 6), (10, 20)].iter() {
+            let dice = Dice::new(count, sides).unwrap();
+            let mut seen_min = false;
+            let mut seen_max = false;
+            for _ in 0..10_000 {
+                let v = dice.sample(&mut rng);
+                assert!(v >= count && v <= count * sides);
+                seen_min |= v == count;
+                seen_max |= v == count * sides;
+            }
+            if count <= 3 {
+                assert!(seen_min && seen_max);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature="alloc")]
+    fn test_dice_roll_detailed() {
+        let mut rng = ::test::rng(391);
+        let dice = Dice::new(4, 6).unwrap();
+        let mut counts = [0u32; 7];
+        for _ in 0..10_000 {
+            let roll = dice.roll_detailed(&mut rng);
+            assert_eq!(roll.len(), 4);
+            for d in roll {
+                counts[d as usize] += 1;
+            }
+        }
+        assert_eq!(counts[0], 0);
+        // Each face is expected 40_000 / 6 ≈ 6667 times.
+        for &c in counts[1..].iter() {
+            assert!(c > 6300 && c < 7000);
+        }
+    }
+
+    #[test]
+    fn test_dice_invalid() {
+        assert_eq!(Dice::new(3, 0).unwrap_err(), DistError::SizeTooSmall);
+    }
+}
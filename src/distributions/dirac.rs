@@ -0,0 +1,80 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Dirac (constant) distribution.
+
+use Rng;
+use distributions::Distribution;
+
+/// The Dirac distribution, which always returns the same value.
+///
+/// Sampling clones `value` and consumes no randomness. This is useful to pass
+/// a fixed value to code that expects a distribution, for example in tests or
+/// as a degenerate component of a mixture.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Dirac, Distribution};
+///
+/// let d = Dirac::new(42);
+/// assert_eq!(d.sample(&mut rand::thread_rng()), 42);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Dirac<T> {
+    value: T,
+}
+
+impl<T> Dirac<T> {
+    /// Construct a new `Dirac` distribution always returning `value`.
+    pub fn new(value: T) -> Dirac<T> {
+        Dirac { value }
+    }
+}
+
+impl<T: Clone> Distribution<T> for Dirac<T> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> T {
+        self.value.clone()
+    }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {RngCore, Error};
+    use distributions::Distribution;
+    use super::Dirac;
+
+    struct PanicRng;
+
+    impl RngCore for PanicRng {
+        fn next_u32(&mut self) -> u32 { panic!("rng used") }
+        fn next_u64(&mut self) -> u64 { panic!("rng used") }
+        fn fill_bytes(&mut self, _: &mut [u8]) { panic!("rng used") }
+        fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), Error> {
+            panic!("rng used")
+        }
+    }
+
+    #[test]
+    fn test_dirac() {
+        let mut rng = PanicRng;
+        let d = Dirac::new(3.5f64);
+        for _ in 0..100 {
+            assert_eq!(d.sample(&mut rng), 3.5);
+        }
+        assert_eq!(d.entropy_bits(), Some(0.0));
+
+        let d = Dirac::new((1u8, 'x'));
+        assert_eq!(d.sample(&mut rng), (1, 'x'));
+    }
+}
@@ -10,8 +10,10 @@
 //! The dirichlet distribution.
 
 use Rng;
-use distributions::Distribution;
+use distributions::{Distribution, DistError};
 use distributions::gamma::Gamma;
+use distributions::multinomial::sample_multinomial_into;
+use std::fmt;
 
 /// The dirichelet distribution `Dirichlet(alpha)`.
 ///
@@ -25,7 +27,7 @@ use distributions::gamma::Gamma;
 /// use rand::prelude::*;
 /// use rand::distributions::Dirichlet;
 ///
-/// let dirichlet = Dirichlet::new(vec![1.0, 2.0, 3.0]);
+/// let dirichlet = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
 /// let samples = dirichlet.sample(&mut rand::thread_rng());
 /// println!("{:?} is from a Dirichlet([1.0, 2.0, 3.0]) distribution", samples);
 /// ```
@@ -39,33 +41,66 @@ pub struct Dirichlet {
 impl Dirichlet {
     /// Construct a new `Dirichlet` with the given alpha parameter `alpha`.
     ///
-    /// # Panics
+    /// # Errors
     /// - if `alpha.len() < 2`
+    /// - if any `alpha[i] <= 0.0`
     ///
     #[inline]
-    pub fn new<V: Into<Vec<f64>>>(alpha: V) -> Dirichlet {
+    pub fn new<V: Into<Vec<f64>>>(alpha: V) -> Result<Dirichlet, DistError> {
         let a = alpha.into();
-        assert!(a.len() > 1);
+        if a.len() < 2 {
+            return Err(DistError::SizeTooSmall);
+        }
         for i in 0..a.len() {
-            assert!(a[i] > 0.0);
+            if !(a[i] > 0.0) {
+                return Err(DistError::ShapeNotPositive);
+            }
         }
 
-        Dirichlet { alpha: a }
+        Ok(Dirichlet { alpha: a })
     }
 
-    /// Construct a new `Dirichlet` with the given shape parameter `alpha` and `size`.
+    /// Construct a new `Dirichlet`, like [`new`](#method.new).
     ///
     /// # Panics
+    /// - if `alpha.len() < 2`
+    /// - if any `alpha[i] <= 0.0`
+    ///
+    #[inline]
+    pub fn new_unchecked<V: Into<Vec<f64>>>(alpha: V) -> Dirichlet {
+        Dirichlet::new(alpha).expect("Dirichlet::new_unchecked called with invalid alpha")
+    }
+
+    /// Construct a new `Dirichlet` with the given shape parameter `alpha` and `size`.
+    ///
+    /// # Errors
     /// - if `alpha <= 0.0`
     /// - if `size < 2`
     ///
     #[inline]
-    pub fn new_with_param(alpha: f64, size: usize) -> Dirichlet {
-        assert!(alpha > 0.0);
-        assert!(size > 1);
-        Dirichlet {
-            alpha: vec![alpha; size],
+    pub fn new_with_param(alpha: f64, size: usize) -> Result<Dirichlet, DistError> {
+        if !(alpha > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if size < 2 {
+            return Err(DistError::SizeTooSmall);
         }
+        Ok(Dirichlet {
+            alpha: vec![alpha; size],
+        })
+    }
+
+    /// Construct a new `Dirichlet` with the given shape parameter `alpha` and
+    /// `size`, like [`new_with_param`](#method.new_with_param).
+    ///
+    /// # Panics
+    /// - if `alpha <= 0.0`
+    /// - if `size < 2`
+    ///
+    #[inline]
+    pub fn new_with_param_unchecked(alpha: f64, size: usize) -> Dirichlet {
+        Dirichlet::new_with_param(alpha, size)
+            .expect("Dirichlet::new_with_param_unchecked called with invalid parameters")
     }
 }
 
@@ -76,7 +111,7 @@ impl Distribution<Vec<f64>> for Dirichlet {
         let mut sum = 0.0f64;
 
         for i in 0..n {
-            let g = Gamma::new(self.alpha[i], 1.0);
+            let g = Gamma::new_unchecked(self.alpha[i], 1.0);
             samples[i] = g.sample(rng);
             sum += samples[i];
         }
@@ -88,14 +123,111 @@ impl Distribution<Vec<f64>> for Dirichlet {
     }
 }
 
+/// The Dirichlet-multinomial (multivariate Pólya) distribution
+/// `DirichletMultinomial(n, alpha)`.
+///
+/// Each sample first draws category probabilities `p ~ Dirichlet(alpha)`, then
+/// distributes `n` trials among the categories according to `p`. The result
+/// is a vector of counts summing to `n`; compared to a multinomial with fixed
+/// probabilities, the counts are overdispersed.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::DirichletMultinomial;
+///
+/// let dm = DirichletMultinomial::new(10, &[1.0, 2.0, 3.0]).unwrap();
+/// let mut counts = [0u64; 3];
+/// dm.sample_into(&mut rand::thread_rng(), &mut counts);
+/// assert_eq!(counts.iter().sum::<u64>(), 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DirichletMultinomial {
+    /// Number of trials.
+    n: u64,
+    dirichlet: Dirichlet,
+}
+
+impl DirichletMultinomial {
+    /// Construct a new `DirichletMultinomial` with `n` trials and
+    /// concentration parameters `alpha`.
+    ///
+    /// # Errors
+    /// - if `alpha.len() < 2`
+    /// - if any `alpha[i] <= 0.0`
+    ///
+    pub fn new(n: u64, alpha: &[f64]) -> Result<DirichletMultinomial, DistError> {
+        Ok(DirichletMultinomial { n, dirichlet: Dirichlet::new(alpha)? })
+    }
+
+    /// Construct a new `DirichletMultinomial`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    /// - if `alpha.len() < 2`
+    /// - if any `alpha[i] <= 0.0`
+    ///
+    pub fn new_unchecked(n: u64, alpha: &[f64]) -> DirichletMultinomial {
+        DirichletMultinomial::new(n, alpha)
+            .expect("DirichletMultinomial::new_unchecked called with invalid alpha")
+    }
+
+    /// Write the count of each category into `out`.
+    ///
+    /// # Panics
+    /// - if `out.len()` is not the number of categories
+    ///
+    pub fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u64]) {
+        assert_eq!(out.len(), self.dirichlet.alpha.len(),
+                   "output length does not match number of categories");
+        let p = self.dirichlet.sample(rng);
+        sample_multinomial_into(rng, self.n, &p, out);
+    }
+}
+
+impl Distribution<Vec<u64>> for DirichletMultinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        let mut out = vec![0; self.dirichlet.alpha.len()];
+        self.sample_into(rng, &mut out);
+        out
+    }
+}
+
+/// Write `values` as a comma-separated list in brackets.
+pub(crate) fn fmt_list(f: &mut fmt::Formatter, values: &[f64]) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, v) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", v)?;
+    }
+    write!(f, "]")
+}
+
+impl fmt::Display for Dirichlet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Dirichlet(alpha=")?;
+        fmt_list(f, &self.alpha)?;
+        write!(f, ")")
+    }
+}
+
+impl fmt::Display for DirichletMultinomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DirichletMultinomial(n={}, alpha=", self.n)?;
+        fmt_list(f, &self.dirichlet.alpha)?;
+        write!(f, ")")
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Dirichlet;
-    use distributions::Distribution;
+    use super::{Dirichlet, DirichletMultinomial};
+    use distributions::{Distribution, DistError};
 
     #[test]
     fn test_dirichlet() {
-        let d = Dirichlet::new(vec![1.0, 2.0, 3.0]);
+        let d = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
         let mut rng = ::test::rng(221);
         let samples = d.sample(&mut rng);
         let _: Vec<f64> = samples
@@ -107,11 +239,26 @@ mod test {
             .collect();
     }
 
+    #[test]
+    fn test_dirichlet_moments() {
+        let alpha = [1.0, 2.0, 3.0];
+        let alpha0 = 6.0;
+        let d = Dirichlet::new(&alpha[..]).unwrap();
+        let mut rng = ::test::rng(222);
+        let samples: Vec<Vec<f64>> = (0..100_000).map(|_| d.sample(&mut rng)).collect();
+        for (i, &a) in alpha.iter().enumerate() {
+            let xs: Vec<f64> = samples.iter().map(|s| s[i]).collect();
+            let mean = a / alpha0;
+            let var = a * (alpha0 - a) / (alpha0 * alpha0 * (alpha0 + 1.0));
+            ::test::assert_moments(&xs, mean, var, 0.03);
+        }
+    }
+
     #[test]
     fn test_dirichlet_with_param() {
         let alpha = 0.5f64;
         let size = 2;
-        let d = Dirichlet::new_with_param(alpha, size);
+        let d = Dirichlet::new_with_param(alpha, size).unwrap();
         let mut rng = ::test::rng(221);
         let samples = d.sample(&mut rng);
         let _: Vec<f64> = samples
@@ -124,14 +271,53 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_dirichlet_invalid_length() {
-        Dirichlet::new_with_param(0.5f64, 1);
+        assert_eq!(Dirichlet::new_with_param(0.5f64, 1).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(Dirichlet::new(vec![0.5f64]).unwrap_err(), DistError::SizeTooSmall);
     }
 
     #[test]
-    #[should_panic]
     fn test_dirichlet_invalid_alpha() {
-        Dirichlet::new_with_param(0.0f64, 2);
+        assert_eq!(Dirichlet::new_with_param(0.0f64, 2).unwrap_err(),
+                   DistError::ShapeNotPositive);
+        assert_eq!(Dirichlet::new(vec![1.0, -1.0]).unwrap_err(), DistError::ShapeNotPositive);
+    }
+
+    #[test]
+    fn test_dirichlet_multinomial() {
+        let alpha = [0.5, 1.5, 3.0];
+        let alpha0 = 5.0;
+        let n = 20;
+        let dm = DirichletMultinomial::new(n, &alpha).unwrap();
+        let mut rng = ::test::rng(223);
+        let mut sums = [0u64; 3];
+        let trials = 50_000;
+        for _ in 0..trials {
+            let counts = dm.sample(&mut rng);
+            assert_eq!(counts.iter().sum::<u64>(), n);
+            for i in 0..3 { sums[i] += counts[i]; }
+        }
+        for (i, &a) in alpha.iter().enumerate() {
+            let mean = sums[i] as f64 / trials as f64;
+            let expected = n as f64 * a / alpha0;
+            assert!((mean - expected).abs() < expected * 0.02);
+        }
+
+        let none = DirichletMultinomial::new(0, &alpha).unwrap();
+        assert_eq!(none.sample(&mut rng), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_dirichlet_multinomial_invalid() {
+        assert_eq!(DirichletMultinomial::new(5, &[1.0]).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(DirichletMultinomial::new(5, &[1.0, 0.0]).unwrap_err(),
+                   DistError::ShapeNotPositive);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dirichlet_multinomial_sample_into_wrong_len() {
+        let dm = DirichletMultinomial::new_unchecked(5, &[1.0, 1.0]);
+        dm.sample_into(&mut ::test::rng(224), &mut [0; 3]);
     }
 }
@@ -0,0 +1,174 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The discrete Weibull distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Open01};
+use std::fmt;
+
+/// The discrete Weibull distribution `DiscreteWeibull(q, β)`.
+///
+/// This is a distribution over `k = 0, 1, 2, ...` with cumulative
+/// distribution function `F(k) = 1 - q^((k + 1)^β)`, the discrete analogue
+/// of the [`Weibull`] distribution introduced by Nakagawa and Osaki. It
+/// models the number of cycles survived by a device; for `β = 1` it is the
+/// [`Geometric`] distribution with success probability `1 - q`.
+///
+/// Samples are generated by inversion as `ceil((ln(1 - U) / ln(q))^(1/β)) -
+/// 1`, clamped to `u64::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{DiscreteWeibull, Distribution};
+///
+/// let cycles = DiscreteWeibull::new(0.9, 1.5).unwrap();
+/// let v = cycles.sample(&mut rand::thread_rng());
+/// println!("{} cycles survived", v);
+/// ```
+///
+/// [`Weibull`]: struct.Weibull.html
+/// [`Geometric`]: struct.Geometric.html
+#[derive(Clone, Copy, Debug)]
+pub struct DiscreteWeibull {
+    q: f64,
+    beta: f64,
+    /// `ln(q)`.
+    ln_q: f64,
+    /// `1 / β`.
+    inv_beta: f64,
+}
+
+impl DiscreteWeibull {
+    /// Construct a new `DiscreteWeibull` with parameters `q` and `beta`.
+    ///
+    /// # Errors
+    /// - `ProbabilityOutOfRange` unless `0 < q < 1`
+    /// - `ShapeNotPositive` unless `beta > 0` and `beta` is finite
+    ///
+    pub fn new(q: f64, beta: f64) -> Result<DiscreteWeibull, DistError> {
+        if !(q > 0.0 && q < 1.0) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        if !(beta > 0.0 && beta.is_finite()) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        Ok(DiscreteWeibull { q, beta, ln_q: q.ln(), inv_beta: 1.0 / beta })
+    }
+
+    /// Construct a new `DiscreteWeibull`, like [`new`](#method.new).
+    ///
+    /// Panics unless `0 < q < 1`, and `beta > 0` is finite.
+    pub fn new_unchecked(q: f64, beta: f64) -> DiscreteWeibull {
+        DiscreteWeibull::new(q, beta)
+            .expect("DiscreteWeibull::new_unchecked called with invalid parameters")
+    }
+
+    /// The cumulative distribution function: the probability of a value of
+    /// at most `k`.
+    pub fn cdf(&self, k: u64) -> f64 {
+        1.0 - self.survival(k)
+    }
+
+    /// The probability mass function: the probability of the value `k`.
+    pub fn pmf(&self, k: u64) -> f64 {
+        let below = if k == 0 { 1.0 } else { self.survival(k - 1) };
+        below - self.survival(k)
+    }
+
+    /// The probability of a value greater than `k`, `q^((k + 1)^β)`.
+    fn survival(&self, k: u64) -> f64 {
+        (self.ln_q * (k as f64 + 1.0).powf(self.beta)).exp()
+    }
+}
+
+impl Distribution<u64> for DiscreteWeibull {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // `1 - U` is also uniform on `(0, 1)`.
+        let u: f64 = rng.sample(Open01);
+        let x = (u.ln() / self.ln_q).powf(self.inv_beta).ceil();
+        // Casting a float beyond `u64::MAX` is undefined before Rust 1.45, so
+        // clamp huge `x` first; `u64::MAX as f64` rounds up to `2^64`, which
+        // is out of range itself.
+        if x - 1.0 >= ::std::u64::MAX as f64 {
+            return ::std::u64::MAX;
+        }
+        (x - 1.0) as u64
+    }
+}
+
+impl fmt::Display for DiscreteWeibull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DiscreteWeibull(q={}, beta={})", self.q, self.beta)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::DiscreteWeibull;
+
+    #[test]
+    fn test_discrete_weibull_frequencies() {
+        let mut rng = ::test::rng(212);
+        for &(q, beta) in [(0.5, 1.0), (0.3, 2.0), (0.8, 0.7)].iter() {
+            let distr = DiscreteWeibull::new(q, beta).unwrap();
+            let n = 100_000;
+            let mut counts = [0u32; 8];
+            for _ in 0..n {
+                let k = distr.sample(&mut rng);
+                if k < 8 {
+                    counts[k as usize] += 1;
+                }
+            }
+            for k in 0..8 {
+                let expected = distr.pmf(k as u64);
+                let freq = counts[k] as f64 / n as f64;
+                let sd = (expected * (1.0 - expected) / n as f64).sqrt();
+                assert!((freq - expected).abs() <= 5.0 * sd + 1e-9,
+                        "{}, k = {}: {} != {}", distr, k, freq, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_discrete_weibull_pmf() {
+        let distr = DiscreteWeibull::new(0.6, 1.3).unwrap();
+        // The pmf sums to the cdf.
+        let total: f64 = (0..20).map(|k| distr.pmf(k)).sum();
+        assert!((total - distr.cdf(19)).abs() < 1e-12);
+        assert!((distr.cdf(0) - 0.4).abs() < 1e-12);
+        // For `β = 1` this is the geometric distribution.
+        let geometric = DiscreteWeibull::new(0.25, 1.0).unwrap();
+        for k in 0..10 {
+            let expected = 0.75 * 0.25f64.powi(k as i32);
+            assert!((geometric.pmf(k) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_discrete_weibull_heavy_tail() {
+        let mut rng = ::test::rng(213);
+        let distr = DiscreteWeibull::new(0.999, 0.05).unwrap();
+        // Most samples are far beyond `u64::MAX`, and are clamped to it.
+        let max = (0..1000).map(|_| distr.sample(&mut rng)).max().unwrap();
+        assert_eq!(max, ::std::u64::MAX);
+    }
+
+    #[test]
+    fn test_discrete_weibull_invalid() {
+        assert_eq!(DiscreteWeibull::new(0.0, 1.0).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(DiscreteWeibull::new(1.0, 1.0).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(DiscreteWeibull::new(::std::f64::NAN, 1.0).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(DiscreteWeibull::new(0.5, 0.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(DiscreteWeibull::new(0.5, ::std::f64::INFINITY).unwrap_err(),
+                   DistError::ShapeNotPositive);
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Erlang distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, OpenClosed01};
+use std::fmt;
+
+/// The Erlang distribution `Erlang(k, rate)`.
+///
+/// This is the distribution of the sum of `k` independent exponential
+/// variates with the same rate, such as the waiting time until the `k`-th
+/// event of a Poisson process. It is the [`Gamma`] distribution with integer
+/// shape `k` and scale `1 / rate`, with mean `k / rate` and variance
+/// `k / rate^2`.
+///
+/// Samples are computed as `-ln(U_1 U_2 ... U_k) / rate`, taking a single
+/// logarithm of the product of `k` uniform variates. This is faster than
+/// [`Gamma`] for small `k`, but the cost grows linearly with `k`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Erlang, Distribution};
+///
+/// let erlang = Erlang::new(3, 2.0).unwrap();
+/// let v = erlang.sample(&mut rand::thread_rng());
+/// println!("{} is from an Erlang(3, 2) distribution", v);
+/// ```
+///
+/// [`Gamma`]: struct.Gamma.html
+#[derive(Clone, Copy, Debug)]
+pub struct Erlang {
+    k: u64,
+    rate: f64,
+}
+
+/// Take the logarithm of the product before it underflows.
+const PRODUCT_MIN: f64 = 1e-280;
+
+impl Erlang {
+    /// Construct a new `Erlang` with shape `k` and the given rate.
+    ///
+    /// Returns an error if `k == 0` or `rate <= 0`.
+    pub fn new(k: u64, rate: f64) -> Result<Erlang, DistError> {
+        if k == 0 {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if !(rate > 0.0) {
+            return Err(DistError::LambdaNotPositive);
+        }
+        Ok(Erlang { k, rate })
+    }
+
+    /// Construct a new `Erlang`, like [`new`](#method.new).
+    ///
+    /// Panics if `k == 0` or `rate <= 0`.
+    pub fn new_unchecked(k: u64, rate: f64) -> Erlang {
+        Erlang::new(k, rate).expect("Erlang::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for Erlang {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        // The uniforms are in `(0, 1]`, so the product is never zero. For
+        // large `k` it is folded into `log_sum` before it underflows.
+        let mut product = 1.0;
+        let mut log_sum = 0.0;
+        for _ in 0..self.k {
+            let u: f64 = rng.sample(OpenClosed01);
+            product *= u;
+            if product < PRODUCT_MIN {
+                log_sum += product.ln();
+                product = 1.0;
+            }
+        }
+        -(log_sum + product.ln()) / self.rate
+    }
+}
+
+impl fmt::Display for Erlang {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Erlang(k={}, rate={})", self.k, self.rate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError, Gamma};
+    use super::Erlang;
+
+    #[test]
+    fn test_erlang_moments() {
+        let mut rng = ::test::rng(185);
+        // The last case takes the logarithm of partial products.
+        for &(k, rate, n) in [(1, 0.5, 50_000), (3, 2.0, 50_000), (20, 4.0, 50_000),
+                              (1000, 1.0, 20_000)].iter() {
+            let erlang = Erlang::new(k, rate).unwrap();
+            let samples: Vec<f64> = (0..n).map(|_| erlang.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x.is_finite()));
+            let k = k as f64;
+            ::test::assert_moments(&samples, k / rate, k / (rate * rate), 0.03);
+        }
+    }
+
+    #[test]
+    fn test_erlang_matches_gamma() {
+        let mut rng = ::test::rng(186);
+        let erlang = Erlang::new(4, 2.0).unwrap();
+        let gamma = Gamma::new(4.0, 0.5).unwrap();
+        let n = 100_000;
+        // Compare the fraction of samples below a few thresholds.
+        for &t in [1.0, 2.0, 3.5].iter() {
+            let below_erlang = (0..n).filter(|_| erlang.sample(&mut rng) < t).count();
+            let below_gamma = (0..n).filter(|_| gamma.sample(&mut rng) < t).count();
+            let diff = (below_erlang as f64 - below_gamma as f64) / n as f64;
+            assert!(diff.abs() < 0.01, "threshold {}: {} != {}", t, below_erlang, below_gamma);
+        }
+    }
+
+    #[test]
+    fn test_erlang_invalid() {
+        assert_eq!(Erlang::new(0, 1.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(Erlang::new(2, 0.0).unwrap_err(), DistError::LambdaNotPositive);
+        assert_eq!(Erlang::new(2, ::std::f64::NAN).unwrap_err(), DistError::LambdaNotPositive);
+    }
+}
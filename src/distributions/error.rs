@@ -0,0 +1,124 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Error type for distribution constructors.
+
+use core::fmt;
+
+/// Error type returned from the fallible constructors of distributions, such
+/// as [`Binomial::new`] or [`Normal::new`], when a parameter is invalid.
+///
+/// Each distribution also offers a panicking `*_unchecked` constructor for
+/// callers who have already validated their parameters.
+///
+/// [`Binomial::new`]: struct.Binomial.html#method.new
+/// [`Normal::new`]: struct.Normal.html#method.new
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistError {
+    /// A probability is not in the range `[0, 1]` (or the narrower range
+    /// required by the distribution).
+    ProbabilityOutOfRange,
+
+    /// A standard deviation is negative or NaN.
+    StdDevNegative,
+
+    /// A standard deviation is not strictly positive.
+    StdDevNotPositive,
+
+    /// A correlation coefficient is not in the open range `(-1, 1)`.
+    CorrelationOutOfRange,
+
+    /// A mean is not strictly positive.
+    MeanNotPositive,
+
+    /// A scale parameter is not strictly positive.
+    ScaleNotPositive,
+
+    /// A shape parameter is not strictly positive.
+    ShapeNotPositive,
+
+    /// A shape parameter is outside the range required by the distribution.
+    ShapeOutOfRange,
+
+    /// A rate parameter `lambda` is not strictly positive.
+    LambdaNotPositive,
+
+    /// A number of degrees of freedom is not strictly positive.
+    DegreesOfFreedomNotPositive,
+
+    /// Too few parameters or dimensions were given.
+    SizeTooSmall,
+
+    /// The bounds of a range are inverted or equal.
+    InvalidRange,
+
+    /// The mode lies outside the range of the distribution.
+    ModeOutOfRange,
+
+    /// A count is zero where it must be positive, or exceeds the number of
+    /// items it is taken from.
+    CountOutOfRange,
+
+    /// The bound of a rejection sampler is not strictly positive and finite.
+    BoundNotPositive,
+
+    /// The dimensions of the parameters do not agree with each other.
+    DimensionMismatch,
+
+    /// A covariance matrix is not symmetric positive-definite.
+    NotPositiveDefinite,
+
+    /// A sample lies outside the support of the distribution.
+    SampleOutOfRange,
+
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl DistError {
+    fn msg(&self) -> &str {
+        match *self {
+            DistError::ProbabilityOutOfRange => "probability out of range",
+            DistError::StdDevNegative => "standard deviation is negative",
+            DistError::StdDevNotPositive => "standard deviation is not positive",
+            DistError::CorrelationOutOfRange => "correlation is not in (-1, 1)",
+            DistError::MeanNotPositive => "mean is not positive",
+            DistError::ScaleNotPositive => "scale is not positive",
+            DistError::ShapeNotPositive => "shape is not positive",
+            DistError::ShapeOutOfRange => "shape is out of range",
+            DistError::LambdaNotPositive => "lambda is not positive",
+            DistError::DegreesOfFreedomNotPositive =>
+                "degrees of freedom are not positive",
+            DistError::SizeTooSmall => "size is too small",
+            DistError::InvalidRange => "range bounds are inverted or equal",
+            DistError::ModeOutOfRange => "mode is outside the range",
+            DistError::CountOutOfRange => "count is out of range",
+            DistError::BoundNotPositive => "bound is not positive and finite",
+            DistError::DimensionMismatch => "dimensions do not match",
+            DistError::NotPositiveDefinite => "matrix is not positive-definite",
+            DistError::SampleOutOfRange => "sample is outside the support",
+            DistError::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature="std")]
+impl ::std::error::Error for DistError {
+    fn description(&self) -> &str {
+        self.msg()
+    }
+    fn cause(&self) -> Option<&::std::error::Error> {
+        None
+    }
+}
+
+impl fmt::Display for DistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.msg())
+    }
+}
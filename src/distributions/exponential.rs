@@ -10,8 +10,10 @@
 //! The exponential distribution.
 
 use {Rng};
-use distributions::{ziggurat_tables, Distribution};
+use distributions::{ziggurat_tables, Distribution, ContinuousDistribution, AntitheticDistribution,
+    InverseCdf, Moments, DistError, Open01, OpenClosed01};
 use distributions::utils::ziggurat;
+use std::fmt;
 
 /// Samples floating-point numbers according to the exponential distribution,
 /// with rate parameter `λ = 1`. This is equivalent to `Exp::new(1.0)` or
@@ -49,7 +51,9 @@ impl Distribution<f64> for Exp1 {
         }
         #[inline]
         fn zero_case<R: Rng + ?Sized>(rng: &mut R, _u: f64) -> f64 {
-            ziggurat_tables::ZIG_EXP_R - rng.gen::<f64>().ln()
+            // Sample from `(0, 1]` so that the logarithm is always finite.
+            let u: f64 = rng.sample(OpenClosed01);
+            ziggurat_tables::ZIG_EXP_R - u.ln()
         }
 
         ziggurat(rng, false,
@@ -57,6 +61,11 @@ impl Distribution<f64> for Exp1 {
                  &ziggurat_tables::ZIG_EXP_F,
                  pdf, zero_case)
     }
+
+    /// The ziggurat method almost always accepts the first 64-bit draw.
+    fn entropy_bits(&self) -> Option<f64> {
+        Some(64.0)
+    }
 }
 
 /// The exponential distribution `Exp(lambda)`.
@@ -69,23 +78,34 @@ impl Distribution<f64> for Exp1 {
 /// ```
 /// use rand::distributions::{Exp, Distribution};
 ///
-/// let exp = Exp::new(2.0);
+/// let exp = Exp::new(2.0).unwrap();
 /// let v = exp.sample(&mut rand::thread_rng());
 /// println!("{} is from a Exp(2) distribution", v);
 /// ```
 #[derive(Clone, Copy, Debug)]
 pub struct Exp {
+    lambda: f64,
     /// `lambda` stored as `1/lambda`, since this is what we scale by.
     lambda_inverse: f64
 }
 
 impl Exp {
     /// Construct a new `Exp` with the given shape parameter
-    /// `lambda`. Panics if `lambda <= 0`.
+    /// `lambda`. Returns an error if `lambda <= 0`.
+    #[inline]
+    pub fn new(lambda: f64) -> Result<Exp, DistError> {
+        if !(lambda > 0.0) {
+            return Err(DistError::LambdaNotPositive);
+        }
+        Ok(Exp { lambda, lambda_inverse: 1.0 / lambda })
+    }
+
+    /// Construct a new `Exp`, like [`new`](#method.new).
+    ///
+    /// Panics if `lambda <= 0`.
     #[inline]
-    pub fn new(lambda: f64) -> Exp {
-        assert!(lambda > 0.0, "Exp::new called with `lambda` <= 0");
-        Exp { lambda_inverse: 1.0 / lambda }
+    pub fn new_unchecked(lambda: f64) -> Exp {
+        Exp::new(lambda).expect("Exp::new_unchecked called with `lambda` <= 0")
     }
 }
 
@@ -96,27 +116,133 @@ impl Distribution<f64> for Exp {
     }
 }
 
+impl ContinuousDistribution for Exp {
+    /// This is `-∞` for `x < 0`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return ::std::f64::NEG_INFINITY;
+        }
+        self.lambda.ln() - self.lambda * x
+    }
+
+    /// The density is computed from the unit exponential variate before
+    /// scaling.
+    fn sample_with_ln_pdf<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let n: f64 = rng.sample(Exp1);
+        (n * self.lambda_inverse, self.lambda.ln() - n)
+    }
+}
+
+impl AntitheticDistribution for Exp {
+    /// The pair is sampled by inversion instead of with the Ziggurat method.
+    fn sample_antithetic<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let u: f64 = rng.sample(Open01);
+        (-u.ln() * self.lambda_inverse, -(-u).ln_1p() * self.lambda_inverse)
+    }
+}
+
+impl InverseCdf for Exp {
+    /// Sampling uses the faster Ziggurat method instead of inversion.
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        -(-p).ln_1p() * self.lambda_inverse
+    }
+}
+
+impl Moments for Exp {
+    /// The mean `1 / lambda`.
+    fn mean(&self) -> f64 {
+        self.lambda_inverse
+    }
+
+    /// The variance `1 / lambda^2`.
+    fn variance(&self) -> f64 {
+        self.lambda_inverse * self.lambda_inverse
+    }
+}
+
+impl fmt::Display for Exp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Exp(lambda={})", self.lambda)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use distributions::Distribution;
+    use distributions::{Distribution, DistError};
     use super::Exp;
 
     #[test]
     fn test_exp() {
-        let exp = Exp::new(10.0);
+        let exp = Exp::new(10.0).unwrap();
         let mut rng = ::test::rng(221);
         for _ in 0..1000 {
             assert!(exp.sample(&mut rng) >= 0.0);
         }
     }
     #[test]
-    #[should_panic]
-    fn test_exp_invalid_lambda_zero() {
-        Exp::new(0.0);
+    fn test_exp_ln_pdf() {
+        use distributions::ContinuousDistribution;
+        let exp = Exp::new(2.0).unwrap();
+        assert!((exp.ln_pdf(0.0) - 2f64.ln()).abs() < 1e-15);
+        assert!((exp.ln_pdf(1.5) - (2f64.ln() - 3.0)).abs() < 1e-15);
+        assert_eq!(exp.ln_pdf(-1.0), ::std::f64::NEG_INFINITY);
+
+        let mut rng = ::test::rng(223);
+        for _ in 0..1000 {
+            let (x, ln_p) = exp.sample_with_ln_pdf(&mut rng);
+            assert!(x >= 0.0);
+            assert!((ln_p - exp.ln_pdf(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_exp_antithetic() {
+        use distributions::AntitheticDistribution;
+        let exp = Exp::new(2.0).unwrap();
+        let mut rng = ::test::rng(183);
+        let (xs, ys): (Vec<f64>, Vec<f64>) =
+            (0..100_000).map(|_| exp.sample_antithetic(&mut rng)).unzip();
+        ::test::assert_moments(&xs, 0.5, 0.25, 0.02);
+        ::test::assert_moments(&ys, 0.5, 0.25, 0.02);
+        // The correlation of an antithetic exponential pair is `1 - π²/6`.
+        let corr = ::test::correlation(&xs, &ys);
+        assert!((corr - (1.0 - ::std::f64::consts::PI.powi(2) / 6.0)).abs() < 0.01);
     }
+
     #[test]
-    #[should_panic]
-    fn test_exp_invalid_lambda_neg() {
-        Exp::new(-10.0);
+    fn test_exp_inverse_cdf() {
+        use distributions::InverseCdf;
+        let exp = Exp::new(2.0).unwrap();
+        assert_eq!(exp.inverse_cdf(0.0), 0.0);
+        assert!((exp.inverse_cdf(0.5) - 2f64.ln() / 2.0).abs() < 1e-15);
+        for &p in [1e-20, 0.1, 0.9, 0.999].iter() {
+            let x = exp.inverse_cdf(p);
+            // `F(x) = 1 - exp(-2 x)`.
+            assert!((-(-2.0 * x).exp_m1() - p).abs() <= 1e-15 * p);
+        }
+    }
+
+    #[test]
+    fn test_exp_moments() {
+        use distributions::Moments;
+        let exp = Exp::new(4.0).unwrap();
+        assert_eq!(exp.mean(), 0.25);
+        assert_eq!(exp.variance(), 0.0625);
+        let mut rng = ::test::rng(231);
+        let samples: Vec<f64> = exp.sample_iter(&mut rng).take(100_000).collect();
+        ::test::assert_moments(&samples, exp.mean(), exp.variance(), 0.02);
+    }
+
+    #[test]
+    fn test_exp_finite() {
+        let exp = Exp::new(1e-3).unwrap();
+        let mut rng = ::test::rng(222);
+        for _ in 0..4_000_000 {
+            assert!(exp.sample(&mut rng).is_finite());
+        }
+    }
+    #[test]
+    fn test_exp_invalid_lambda_zero() {
+        assert_eq!(Exp::new(0.0).unwrap_err(), DistError::LambdaNotPositive);
     }
 }
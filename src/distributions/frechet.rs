@@ -0,0 +1,115 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Fréchet distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Open01};
+use std::fmt;
+
+/// Samples floating-point numbers according to the Fréchet distribution
+///
+/// This is the extreme value distribution of type II, with distribution
+/// function `F(x) = exp(-((x - location) / scale)^(-shape))` for
+/// `x > location`. It is heavy-tailed: moments of order `shape` and above
+/// are infinite.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand::distributions::Frechet;
+///
+/// let val: f64 = SmallRng::from_entropy().sample(Frechet::new(0., 1., 2.).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Frechet {
+    location: f64,
+    scale: f64,
+    shape: f64,
+    inv_shape: f64,
+}
+
+impl Frechet {
+    /// Construct a new `Frechet` distribution with given `location`, `scale`
+    /// and `shape`.
+    ///
+    /// # Errors
+    ///
+    /// `scale` and `shape` have to be non-zero and positive.
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Frechet, DistError> {
+        if !(scale > 0.) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        if !(shape > 0.) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        Ok(Frechet { location, scale, shape, inv_shape: 1./shape })
+    }
+
+    /// Construct a new `Frechet` distribution, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// `scale` and `shape` have to be non-zero and positive.
+    pub fn new_unchecked(location: f64, scale: f64, shape: f64) -> Frechet {
+        Frechet::new(location, scale, shape)
+            .expect("Frechet::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for Frechet {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        // `U = 1` would give an infinite sample, and `U = 0` one at the
+        // location itself.
+        let x: f64 = rng.sample(Open01);
+        self.location + self.scale * (-x.ln()).powf(-self.inv_shape)
+    }
+}
+
+impl fmt::Display for Frechet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Frechet(location={}, scale={}, shape={})",
+               self.location, self.scale, self.shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use distributions::{Distribution, DistError};
+    use super::Frechet;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Frechet::new(0., 0., 1.).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Frechet::new(0., 1., 0.).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(Frechet::new(0., 1., -1.).unwrap_err(), DistError::ShapeNotPositive);
+    }
+
+    #[test]
+    fn sample() {
+        let d = Frechet::new(5.0, 2.0, 1.0).unwrap();
+        let mut rng = ::test::rng(158);
+        let n = 10_000;
+        let mut max = 0.0f64;
+        let mut below_median = 0;
+        for _ in 0..n {
+            let r = d.sample(&mut rng);
+            assert!(r > 5.0);
+            max = max.max(r);
+            // The median is `location + scale / ln(2)^(1/shape)`.
+            if r < 5.0 + 2.0 / 2f64.ln() {
+                below_median += 1;
+            }
+        }
+        assert!((below_median as f64 / n as f64 - 0.5).abs() < 0.02);
+        // `P(X > 5 + 2000) = 1 - exp(-1e-3)`, so such outliers are expected
+        // about ten times in this many samples.
+        assert!(max > 2005.0);
+    }
+}
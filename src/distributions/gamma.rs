@@ -14,7 +14,8 @@ use self::ChiSquaredRepr::*;
 
 use Rng;
 use distributions::normal::StandardNormal;
-use distributions::{Distribution, Exp, Open01};
+use distributions::{Distribution, DistError, Exp, Moments, Open01};
+use std::fmt;
 
 /// The Gamma distribution `Gamma(shape, scale)` distribution.
 ///
@@ -32,12 +33,18 @@ use distributions::{Distribution, Exp, Open01};
 /// == 1`, and using the boosting technique described in that paper for
 /// `shape < 1`.
 ///
+/// For `shape == 1` this is exactly the exponential distribution
+/// `Exp(1 / scale)`. The method of Marsaglia & Tsang is exact for arbitrarily
+/// large `shape`, where its acceptance rate approaches one, so no normal
+/// approximation is used. This makes `Gamma` suitable as a building block for
+/// mixtures such as [`Beta`], [`Dirichlet`] and [`NegativeBinomial`].
+///
 /// # Example
 ///
 /// ```
 /// use rand::distributions::{Distribution, Gamma};
 ///
-/// let gamma = Gamma::new(2.0, 5.0);
+/// let gamma = Gamma::new(2.0, 5.0).unwrap();
 /// let v = gamma.sample(&mut rand::thread_rng());
 /// println!("{} is from a Gamma(2, 5) distribution", v);
 /// ```
@@ -46,8 +53,14 @@ use distributions::{Distribution, Exp, Open01};
 ///       Generating Gamma Variables" *ACM Trans. Math. Softw.* 26, 3
 ///       (September 2000), 363-372.
 ///       DOI:[10.1145/358407.358414](https://doi.acm.org/10.1145/358407.358414)
+///
+/// [`Beta`]: struct.Beta.html
+/// [`Dirichlet`]: struct.Dirichlet.html
+/// [`NegativeBinomial`]: struct.NegativeBinomial.html
 #[derive(Clone, Copy, Debug)]
 pub struct Gamma {
+    shape: f64,
+    scale: f64,
     repr: GammaRepr,
 }
 
@@ -93,20 +106,71 @@ impl Gamma {
     /// Construct an object representing the `Gamma(shape, scale)`
     /// distribution.
     ///
-    /// Panics if `shape <= 0` or `scale <= 0`.
+    /// Returns an error if `shape <= 0` or `scale <= 0`.
     #[inline]
-    pub fn new(shape: f64, scale: f64) -> Gamma {
-        assert!(shape > 0.0, "Gamma::new called with shape <= 0");
-        assert!(scale > 0.0, "Gamma::new called with scale <= 0");
+    pub fn new(shape: f64, scale: f64) -> Result<Gamma, DistError> {
+        if !(shape > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if !(scale > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
 
         let repr = if shape == 1.0 {
-            One(Exp::new(1.0 / scale))
+            One(Exp::new_unchecked(1.0 / scale))
         } else if shape < 1.0 {
             Small(GammaSmallShape::new_raw(shape, scale))
         } else {
             Large(GammaLargeShape::new_raw(shape, scale))
         };
-        Gamma { repr }
+        Ok(Gamma { shape, scale, repr })
+    }
+
+    /// Construct an object representing the `Gamma(shape, scale)`
+    /// distribution, like [`new`](#method.new).
+    ///
+    /// Panics if `shape <= 0` or `scale <= 0`.
+    #[inline]
+    pub fn new_unchecked(shape: f64, scale: f64) -> Gamma {
+        Gamma::new(shape, scale).expect("Gamma::new_unchecked called with invalid parameters")
+    }
+
+    /// Construct an object representing the `Gamma(shape, scale)`
+    /// distribution, with mean `shape * scale`. This is the same as
+    /// [`new`](#method.new), named for clarity.
+    ///
+    /// Returns an error if `shape <= 0` or `scale <= 0`.
+    #[inline]
+    pub fn from_shape_scale(shape: f64, scale: f64) -> Result<Gamma, DistError> {
+        Gamma::new(shape, scale)
+    }
+
+    /// Construct an object representing the Gamma distribution with the
+    /// given shape and rate, i.e. with scale `1 / rate` and mean
+    /// `shape / rate`.
+    ///
+    /// Returns an error if `shape <= 0`, `rate <= 0` or `rate` is infinite.
+    #[inline]
+    pub fn from_shape_rate(shape: f64, rate: f64) -> Result<Gamma, DistError> {
+        if !(rate > 0.0) {
+            return Err(DistError::LambdaNotPositive);
+        }
+        Gamma::new(shape, 1.0 / rate)
+    }
+
+    /// The shape parameter of the distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// The scale parameter of the distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// The rate parameter of the distribution, `1 / scale`.
+    pub fn rate(&self) -> f64 {
+        1.0 / self.scale
     }
 }
 
@@ -149,7 +213,7 @@ impl Distribution<f64> for GammaSmallShape {
 impl Distribution<f64> for GammaLargeShape {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         loop {
-            let x = rng.sample(StandardNormal);
+            let x: f64 = rng.sample(StandardNormal);
             let v_cbrt = 1.0 + self.c * x;
             if v_cbrt <= 0.0 { // a^3 <= 0 iff a <= 0
                 continue
@@ -180,7 +244,7 @@ impl Distribution<f64> for GammaLargeShape {
 /// ```
 /// use rand::distributions::{ChiSquared, Distribution};
 ///
-/// let chi = ChiSquared::new(11.0);
+/// let chi = ChiSquared::new(11.0).unwrap();
 /// let v = chi.sample(&mut rand::thread_rng());
 /// println!("{} is from a χ²(11) distribution", v)
 /// ```
@@ -200,15 +264,31 @@ enum ChiSquaredRepr {
 
 impl ChiSquared {
     /// Create a new chi-squared distribution with degrees-of-freedom
-    /// `k`. Panics if `k < 0`.
-    pub fn new(k: f64) -> ChiSquared {
+    /// `k`. Returns an error if `k <= 0`.
+    pub fn new(k: f64) -> Result<ChiSquared, DistError> {
         let repr = if k == 1.0 {
             DoFExactlyOne
         } else {
-            assert!(k > 0.0, "ChiSquared::new called with `k` < 0");
-            DoFAnythingElse(Gamma::new(0.5 * k, 2.0))
+            if !(k > 0.0) {
+                return Err(DistError::DegreesOfFreedomNotPositive);
+            }
+            DoFAnythingElse(Gamma::new_unchecked(0.5 * k, 2.0))
         };
-        ChiSquared { repr }
+        Ok(ChiSquared { repr })
+    }
+
+    /// Create a new chi-squared distribution, like [`new`](#method.new).
+    /// Panics if `k <= 0`.
+    pub fn new_unchecked(k: f64) -> ChiSquared {
+        ChiSquared::new(k).expect("ChiSquared::new_unchecked called with `k` <= 0")
+    }
+
+    /// The degrees of freedom `k`.
+    fn k(&self) -> f64 {
+        match self.repr {
+            DoFExactlyOne => 1.0,
+            DoFAnythingElse(ref g) => 2.0 * g.shape,
+        }
     }
 }
 impl Distribution<f64> for ChiSquared {
@@ -216,7 +296,7 @@ impl Distribution<f64> for ChiSquared {
         match self.repr {
             DoFExactlyOne => {
                 // k == 1 => N(0,1)^2
-                let norm = rng.sample(StandardNormal);
+                let norm: f64 = rng.sample(StandardNormal);
                 norm * norm
             }
             DoFAnythingElse(ref g) => g.sample(rng)
@@ -235,7 +315,7 @@ impl Distribution<f64> for ChiSquared {
 /// ```
 /// use rand::distributions::{FisherF, Distribution};
 ///
-/// let f = FisherF::new(2.0, 32.0);
+/// let f = FisherF::new(2.0, 32.0).unwrap();
 /// let v = f.sample(&mut rand::thread_rng());
 /// println!("{} is from an F(2, 32) distribution", v)
 /// ```
@@ -250,16 +330,23 @@ pub struct FisherF {
 
 impl FisherF {
     /// Create a new `FisherF` distribution, with the given
-    /// parameter. Panics if either `m` or `n` are not positive.
-    pub fn new(m: f64, n: f64) -> FisherF {
-        assert!(m > 0.0, "FisherF::new called with `m < 0`");
-        assert!(n > 0.0, "FisherF::new called with `n < 0`");
-
-        FisherF {
-            numer: ChiSquared::new(m),
-            denom: ChiSquared::new(n),
-            dof_ratio: n / m
+    /// parameter. Returns an error if either `m` or `n` are not positive.
+    pub fn new(m: f64, n: f64) -> Result<FisherF, DistError> {
+        if !(m > 0.0 && n > 0.0) {
+            return Err(DistError::DegreesOfFreedomNotPositive);
         }
+
+        Ok(FisherF {
+            numer: ChiSquared::new_unchecked(m),
+            denom: ChiSquared::new_unchecked(n),
+            dof_ratio: n / m
+        })
+    }
+
+    /// Create a new `FisherF` distribution, like [`new`](#method.new).
+    /// Panics if either `m` or `n` are not positive.
+    pub fn new_unchecked(m: f64, n: f64) -> FisherF {
+        FisherF::new(m, n).expect("FisherF::new_unchecked called with `m` or `n` <= 0")
     }
 }
 impl Distribution<f64> for FisherF {
@@ -276,7 +363,7 @@ impl Distribution<f64> for FisherF {
 /// ```
 /// use rand::distributions::{StudentT, Distribution};
 ///
-/// let t = StudentT::new(11.0);
+/// let t = StudentT::new(11.0).unwrap();
 /// let v = t.sample(&mut rand::thread_rng());
 /// println!("{} is from a t(11) distribution", v)
 /// ```
@@ -288,18 +375,26 @@ pub struct StudentT {
 
 impl StudentT {
     /// Create a new Student t distribution with `n` degrees of
-    /// freedom. Panics if `n <= 0`.
-    pub fn new(n: f64) -> StudentT {
-        assert!(n > 0.0, "StudentT::new called with `n <= 0`");
-        StudentT {
-            chi: ChiSquared::new(n),
-            dof: n
+    /// freedom. Returns an error if `n <= 0`.
+    pub fn new(n: f64) -> Result<StudentT, DistError> {
+        if !(n > 0.0) {
+            return Err(DistError::DegreesOfFreedomNotPositive);
         }
+        Ok(StudentT {
+            chi: ChiSquared::new_unchecked(n),
+            dof: n
+        })
+    }
+
+    /// Create a new Student t distribution, like [`new`](#method.new).
+    /// Panics if `n <= 0`.
+    pub fn new_unchecked(n: f64) -> StudentT {
+        StudentT::new(n).expect("StudentT::new_unchecked called with `n` <= 0")
     }
 }
 impl Distribution<f64> for StudentT {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        let norm = rng.sample(StandardNormal);
+        let norm: f64 = rng.sample(StandardNormal);
         norm * (self.dof / self.chi.sample(rng)).sqrt()
     }
 }
@@ -311,7 +406,7 @@ impl Distribution<f64> for StudentT {
 /// ```
 /// use rand::distributions::{Distribution, Beta};
 ///
-/// let beta = Beta::new(2.0, 5.0);
+/// let beta = Beta::new(2.0, 5.0).unwrap();
 /// let v = beta.sample(&mut rand::thread_rng());
 /// println!("{} is from a Beta(2, 5) distribution", v);
 /// ```
@@ -325,13 +420,23 @@ impl Beta {
     /// Construct an object representing the `Beta(alpha, beta)`
     /// distribution.
     ///
-    /// Panics if `shape <= 0` or `scale <= 0`.
-    pub fn new(alpha: f64, beta: f64) -> Beta {
-        assert!((alpha > 0.) & (beta > 0.));
-        Beta {
-            gamma_a: Gamma::new(alpha, 1.),
-            gamma_b: Gamma::new(beta, 1.),
+    /// Returns an error if `alpha <= 0` or `beta <= 0`.
+    pub fn new(alpha: f64, beta: f64) -> Result<Beta, DistError> {
+        if !((alpha > 0.) & (beta > 0.)) {
+            return Err(DistError::ShapeNotPositive);
         }
+        Ok(Beta {
+            gamma_a: Gamma::new_unchecked(alpha, 1.),
+            gamma_b: Gamma::new_unchecked(beta, 1.),
+        })
+    }
+
+    /// Construct an object representing the `Beta(alpha, beta)`
+    /// distribution, like [`new`](#method.new).
+    ///
+    /// Panics if `alpha <= 0` or `beta <= 0`.
+    pub fn new_unchecked(alpha: f64, beta: f64) -> Beta {
+        Beta::new(alpha, beta).expect("Beta::new_unchecked called with `alpha` or `beta` <= 0")
     }
 }
 
@@ -343,14 +448,172 @@ impl Distribution<f64> for Beta {
     }
 }
 
+/// The inverse-gamma distribution `InverseGamma(shape, scale)`.
+///
+/// This is the distribution of `1 / X` for `X` distributed as
+/// `Gamma(shape, 1 / scale)`, and is the conjugate prior of the variance of a
+/// normal distribution. The mean is `scale / (shape - 1)` for `shape > 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{InverseGamma, Distribution};
+///
+/// let inv_gamma = InverseGamma::new(3.0, 2.0).unwrap();
+/// let v = inv_gamma.sample(&mut rand::thread_rng());
+/// println!("{} is from an InverseGamma(3, 2) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct InverseGamma {
+    /// `Gamma(shape, 1 / scale)`.
+    gamma: Gamma,
+    scale: f64,
+}
+
+impl InverseGamma {
+    /// Construct an object representing the `InverseGamma(shape, scale)`
+    /// distribution.
+    ///
+    /// Returns an error if `shape <= 0` or `scale <= 0`.
+    pub fn new(shape: f64, scale: f64) -> Result<InverseGamma, DistError> {
+        if !(shape > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if !(scale > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        Ok(InverseGamma { gamma: Gamma::new(shape, 1.0 / scale)?, scale })
+    }
+
+    /// Construct an object representing the `InverseGamma(shape, scale)`
+    /// distribution, like [`new`](#method.new).
+    ///
+    /// Panics if `shape <= 0` or `scale <= 0`.
+    pub fn new_unchecked(shape: f64, scale: f64) -> InverseGamma {
+        InverseGamma::new(shape, scale)
+            .expect("InverseGamma::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for InverseGamma {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        1.0 / self.gamma.sample(rng)
+    }
+}
+
+impl Moments for Gamma {
+    /// The mean `shape * scale`.
+    fn mean(&self) -> f64 {
+        self.shape * self.scale
+    }
+
+    /// The variance `shape * scale^2`.
+    fn variance(&self) -> f64 {
+        self.shape * self.scale * self.scale
+    }
+}
+
+impl fmt::Display for Gamma {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Gamma(shape={}, scale={})", self.shape, self.scale)
+    }
+}
+
+impl fmt::Display for ChiSquared {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ChiSquared(k={})", self.k())
+    }
+}
+
+impl fmt::Display for FisherF {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FisherF(m={}, n={})", self.numer.k(), self.denom.k())
+    }
+}
+
+impl fmt::Display for StudentT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StudentT(n={})", self.dof)
+    }
+}
+
+impl fmt::Display for Beta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Beta(alpha={}, beta={})", self.gamma_a.shape, self.gamma_b.shape)
+    }
+}
+
+impl fmt::Display for InverseGamma {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "InverseGamma(shape={}, scale={})", self.gamma.shape, self.scale)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use distributions::Distribution;
-    use super::{Beta, ChiSquared, StudentT, FisherF};
+    use distributions::{Distribution, DistError};
+    use super::{Gamma, InverseGamma, Beta, ChiSquared, StudentT, FisherF};
+
+    #[test]
+    fn test_gamma_moments() {
+        let mut rng = ::test::rng(206);
+        // covers the small, one and large shape cases
+        for &shape in [0.3, 1.0, 2.5, 100.0, 1e6].iter() {
+            let scale = 2.0;
+            let gamma = Gamma::new(shape, scale).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| gamma.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            ::test::assert_moments(&samples, shape * scale, shape * scale * scale, 0.03);
+        }
+    }
+
+    #[test]
+    fn test_gamma_closed_form_moments() {
+        use distributions::{Exp, Moments};
+        let gamma = Gamma::new(2.5, 2.0).unwrap();
+        assert_eq!((gamma.mean(), gamma.variance()), (5.0, 10.0));
+        // Agrees with the exponential distribution for shape 1.
+        let exp = Exp::new(0.5).unwrap();
+        let one = Gamma::new(1.0, 2.0).unwrap();
+        assert_eq!((one.mean(), one.variance()), (exp.mean(), exp.variance()));
+    }
+
+    #[test]
+    fn test_gamma_invalid() {
+        assert_eq!(Gamma::new(0.0, 1.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(Gamma::new(1.0, -1.0).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Gamma::from_shape_rate(0.0, 1.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(Gamma::from_shape_rate(1.0, 0.0).unwrap_err(), DistError::LambdaNotPositive);
+        assert_eq!(Gamma::from_shape_rate(1.0, ::std::f64::INFINITY).unwrap_err(),
+                   DistError::ScaleNotPositive);
+    }
+
+    #[test]
+    fn test_gamma_shape_rate() {
+        for &(shape, scale) in [(0.5, 4.0), (1.0, 0.25), (3.0, 2.0)].iter() {
+            let by_scale = Gamma::from_shape_scale(shape, scale).unwrap();
+            let by_rate = Gamma::from_shape_rate(shape, 1.0 / scale).unwrap();
+            assert_eq!(by_scale.shape(), shape);
+            assert_eq!(by_scale.scale(), scale);
+            assert_eq!(by_rate.rate(), 1.0 / scale);
+            assert!((by_rate.scale() - scale).abs() < 1e-15 * scale);
+
+            // The same seed gives the same samples, with mean `shape * scale`.
+            let mut rng1 = ::test::rng(182);
+            let mut rng2 = ::test::rng(182);
+            let samples: Vec<f64> = (0..50_000).map(|_| {
+                let x = by_scale.sample(&mut rng1);
+                let y = by_rate.sample(&mut rng2);
+                assert!((x - y).abs() <= 1e-12 * x.abs());
+                x
+            }).collect();
+            ::test::assert_moments(&samples, shape * scale, shape * scale * scale, 0.03);
+        }
+    }
 
     #[test]
     fn test_chi_squared_one() {
-        let chi = ChiSquared::new(1.0);
+        let chi = ChiSquared::new(1.0).unwrap();
         let mut rng = ::test::rng(201);
         for _ in 0..1000 {
             chi.sample(&mut rng);
@@ -358,7 +621,7 @@ mod test {
     }
     #[test]
     fn test_chi_squared_small() {
-        let chi = ChiSquared::new(0.5);
+        let chi = ChiSquared::new(0.5).unwrap();
         let mut rng = ::test::rng(202);
         for _ in 0..1000 {
             chi.sample(&mut rng);
@@ -366,39 +629,50 @@ mod test {
     }
     #[test]
     fn test_chi_squared_large() {
-        let chi = ChiSquared::new(30.0);
+        let chi = ChiSquared::new(30.0).unwrap();
         let mut rng = ::test::rng(203);
         for _ in 0..1000 {
             chi.sample(&mut rng);
         }
     }
     #[test]
-    #[should_panic]
     fn test_chi_squared_invalid_dof() {
-        ChiSquared::new(-1.0);
+        assert_eq!(ChiSquared::new(-1.0).unwrap_err(),
+                   DistError::DegreesOfFreedomNotPositive);
     }
-
     #[test]
     fn test_f() {
-        let f = FisherF::new(2.0, 32.0);
+        let f = FisherF::new(2.0, 32.0).unwrap();
         let mut rng = ::test::rng(204);
         for _ in 0..1000 {
             f.sample(&mut rng);
         }
     }
+    #[test]
+    fn test_f_invalid_dof() {
+        assert_eq!(FisherF::new(0.0, 1.0).unwrap_err(),
+                   DistError::DegreesOfFreedomNotPositive);
+        assert_eq!(FisherF::new(1.0, -1.0).unwrap_err(),
+                   DistError::DegreesOfFreedomNotPositive);
+    }
 
     #[test]
     fn test_t() {
-        let t = StudentT::new(11.0);
+        let t = StudentT::new(11.0).unwrap();
         let mut rng = ::test::rng(205);
         for _ in 0..1000 {
             t.sample(&mut rng);
         }
     }
+    #[test]
+    fn test_t_invalid_dof() {
+        assert_eq!(StudentT::new(0.0).unwrap_err(),
+                   DistError::DegreesOfFreedomNotPositive);
+    }
 
     #[test]
     fn test_beta() {
-        let beta = Beta::new(1.0, 2.0);
+        let beta = Beta::new(1.0, 2.0).unwrap();
         let mut rng = ::test::rng(201);
         for _ in 0..1000 {
             beta.sample(&mut rng);
@@ -406,8 +680,45 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    fn test_beta_moments() {
+        let mut rng = ::test::rng(207);
+        for &(a, b) in [(2.0, 3.0), (0.5, 0.5), (20.0, 1.0)].iter() {
+            let beta = Beta::new(a, b).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| beta.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x <= 1.0));
+            let mean = a / (a + b);
+            let var = a * b / ((a + b) * (a + b) * (a + b + 1.0));
+            ::test::assert_moments(&samples, mean, var, 0.03);
+        }
+    }
+
+    #[test]
     fn test_beta_invalid_dof() {
-        Beta::new(0., 0.);
+        assert_eq!(Beta::new(0., 0.).unwrap_err(), DistError::ShapeNotPositive);
+    }
+    #[test]
+    fn test_inverse_gamma_moments() {
+        let mut rng = ::test::rng(187);
+        for &(shape, scale) in [(1.5, 1.0), (6.0, 2.0), (20.0, 0.5)].iter() {
+            let distr = InverseGamma::new(shape, scale).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| distr.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x > 0.0));
+            if shape > 4.0 {
+                // The variance has a finite estimate.
+                let mean = scale / (shape - 1.0);
+                ::test::assert_moments(&samples, mean, mean * mean / (shape - 2.0), 0.03);
+            }
+        }
+        // With `shape <= 2` the variance is infinite; check the median
+        // instead, which for `InverseGamma(1.5, 1)` is `1 / 1.1830`.
+        let distr = InverseGamma::new(1.5, 1.0).unwrap();
+        let below = (0..100_000).filter(|_| distr.sample(&mut rng) < 1.0 / 1.18304).count();
+        assert!((below as f64 / 100_000.0 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_inverse_gamma_invalid() {
+        assert_eq!(InverseGamma::new(0.0, 1.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(InverseGamma::new(1.0, 0.0).unwrap_err(), DistError::ScaleNotPositive);
     }
 }
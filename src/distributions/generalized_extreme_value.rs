@@ -0,0 +1,166 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The generalized extreme value distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Open01};
+use std::fmt;
+
+/// Below this magnitude, the shape parameter is treated as zero.
+const SHAPE_EPSILON: f64 = 1e-12;
+
+/// Samples floating-point numbers according to the generalized extreme value
+/// distribution.
+///
+/// This has distribution function
+/// `F(x) = exp(-(1 + shape (x - location) / scale)^(-1 / shape))`, and unifies
+/// the three types of extreme value distributions depending on the sign of
+/// `shape`:
+///
+/// - `shape > 0`: the [`Frechet`] type, with support
+///   `x > location - scale / shape`;
+/// - `shape == 0`: the Gumbel type, `F(x) = exp(-exp(-(x - location) / scale))`,
+///   with unbounded support;
+/// - `shape < 0`: the reversed Weibull type, with support
+///   `x < location - scale / shape`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand::distributions::GeneralizedExtremeValue;
+///
+/// let gev = GeneralizedExtremeValue::new(0., 1., 0.2).unwrap();
+/// let val: f64 = SmallRng::from_entropy().sample(gev);
+/// println!("{}", val);
+/// ```
+///
+/// [`Frechet`]: struct.Frechet.html
+#[derive(Clone, Copy, Debug)]
+pub struct GeneralizedExtremeValue {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedExtremeValue {
+    /// Construct a new `GeneralizedExtremeValue` distribution with given
+    /// `location`, `scale` and `shape`.
+    ///
+    /// # Errors
+    ///
+    /// `scale` has to be non-zero and positive.
+    pub fn new(location: f64, scale: f64, shape: f64)
+        -> Result<GeneralizedExtremeValue, DistError>
+    {
+        if !(scale > 0.) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        Ok(GeneralizedExtremeValue { location, scale, shape })
+    }
+
+    /// Construct a new `GeneralizedExtremeValue` distribution, like
+    /// [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// `scale` has to be non-zero and positive.
+    pub fn new_unchecked(location: f64, scale: f64, shape: f64) -> GeneralizedExtremeValue {
+        GeneralizedExtremeValue::new(location, scale, shape)
+            .expect("GeneralizedExtremeValue::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for GeneralizedExtremeValue {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.sample(Open01);
+        // `ln(-ln(U))` is a standard (reversed) Gumbel variate.
+        let g = (-u.ln()).ln();
+        if self.shape.abs() < SHAPE_EPSILON {
+            self.location - self.scale * g
+        } else {
+            // `((-ln U)^(-shape) - 1) / shape`, which is accurate for small
+            // `shape` as well.
+            self.location + self.scale * (-self.shape * g).exp_m1() / self.shape
+        }
+    }
+}
+
+impl fmt::Display for GeneralizedExtremeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GeneralizedExtremeValue(location={}, scale={}, shape={})",
+               self.location, self.scale, self.shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use distributions::{Distribution, DistError};
+    use super::GeneralizedExtremeValue;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(GeneralizedExtremeValue::new(0., 0., 1.).unwrap_err(),
+                   DistError::ScaleNotPositive);
+    }
+
+    #[test]
+    fn sample_positive_shape() {
+        // Bounded below by `1 - 2 / 0.5 = -3`.
+        let d = GeneralizedExtremeValue::new(1., 2., 0.5).unwrap();
+        let mut rng = ::test::rng(159);
+        let mut min = ::std::f64::INFINITY;
+        for _ in 0..10_000 {
+            let x = d.sample(&mut rng);
+            assert!(x > -3.);
+            min = min.min(x);
+        }
+        assert!(min < -1.);
+    }
+
+    #[test]
+    fn sample_negative_shape() {
+        // Bounded above by `1 + 2 / 0.5 = 5`.
+        let d = GeneralizedExtremeValue::new(1., 2., -0.5).unwrap();
+        let mut rng = ::test::rng(160);
+        let mut max = ::std::f64::NEG_INFINITY;
+        for _ in 0..10_000 {
+            let x = d.sample(&mut rng);
+            assert!(x < 5.);
+            max = max.max(x);
+        }
+        assert!(max > 4.5);
+    }
+
+    #[test]
+    fn sample_zero_shape() {
+        // Gumbel: unbounded, with median `1 - 2 ln(ln(2))`.
+        let d = GeneralizedExtremeValue::new(1., 2., 0.).unwrap();
+        let mut rng = ::test::rng(161);
+        let n = 10_000;
+        let median = 1. - 2. * 2f64.ln().ln();
+        let (mut min, mut max, mut below) = (0f64, 0f64, 0);
+        for _ in 0..n {
+            let x = d.sample(&mut rng);
+            assert!(x.is_finite());
+            min = min.min(x);
+            max = max.max(x);
+            if x < median { below += 1; }
+        }
+        assert!(min < -2. && max > 15.);
+        assert!((below as f64 / n as f64 - 0.5).abs() < 0.02);
+
+        // A tiny shape parameter gives the same samples.
+        let tiny = GeneralizedExtremeValue::new(1., 2., 1e-14).unwrap();
+        let mut a = ::test::rng(162);
+        let mut b = ::test::rng(162);
+        for _ in 0..100 {
+            assert_eq!(tiny.sample(&mut a), d.sample(&mut b));
+        }
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The geometric distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Bernoulli, OpenClosed01};
+use std::fmt;
+
+/// The geometric distribution `Geometric(p)`.
+///
+/// This is the distribution of the number of failures before the first
+/// success in a series of independent Bernoulli trials, each with probability
+/// of success `p`. Its support is `k = 0, 1, 2, ...` and it has density
+/// function `f(k) = (1 - p)^k p`.
+///
+/// Note that some authors instead count the number of trials up to and
+/// including the first success; that variant is this distribution shifted by
+/// one.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Geometric, Distribution};
+///
+/// let geo = Geometric::new(0.25).unwrap();
+/// let v = geo.sample(&mut rand::thread_rng());
+/// println!("{} is from a Geometric(0.25) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Geometric {
+    /// Probability of success.
+    p: f64,
+    /// `ln(1 - p)`, precalculated for the inverse transform.
+    ln_1_p: f64,
+}
+
+// Above this probability of success, simulating the Bernoulli trials directly
+// is cheaper than the logarithm needed by the inverse transform, since on
+// average fewer than one failure is drawn before the first success.
+const TRIAL_THRESHOLD: f64 = 0.5;
+
+impl Geometric {
+    /// Construct a new `Geometric` with the given probability of success `p`.
+    ///
+    /// Returns an error if `p <= 0`, `p > 1` or `p` is NaN.
+    pub fn new(p: f64) -> Result<Geometric, DistError> {
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        Ok(Geometric { p, ln_1_p: (-p).ln_1p() })
+    }
+
+    /// Construct a new `Geometric`, like [`new`](#method.new).
+    ///
+    /// Panics if `p <= 0`, `p > 1` or `p` is NaN.
+    pub fn new_unchecked(p: f64) -> Geometric {
+        Geometric::new(p).expect("Geometric::new_unchecked called with invalid p")
+    }
+
+    /// Probability mass function: the probability of exactly `k` failures
+    /// before the first success.
+    pub fn pmf(&self, k: u64) -> f64 {
+        if self.p == 1.0 {
+            return if k == 0 { 1.0 } else { 0.0 };
+        }
+        self.p * (k as f64 * self.ln_1_p).exp()
+    }
+
+    /// Cumulative distribution function: the probability of at most `k`
+    /// failures before the first success.
+    pub fn cdf(&self, k: u64) -> f64 {
+        if self.p == 1.0 {
+            return 1.0;
+        }
+        -((k as f64 + 1.0) * self.ln_1_p).exp_m1()
+    }
+
+    /// Return whether `value` lies within `sigmas` standard deviations of
+    /// the mean `(1 - p) / p`, where the variance is `(1 - p) / p^2`. Bounds
+    /// are included.
+    pub fn is_within_sigma(&self, value: u64, sigmas: f64) -> bool {
+        let q = 1.0 - self.p;
+        let mean = q / self.p;
+        let std_dev = q.sqrt() / self.p;
+        (value as f64 - mean).abs() <= sigmas * std_dev
+    }
+}
+
+impl Distribution<u64> for Geometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        if self.p >= TRIAL_THRESHOLD {
+            let d = Bernoulli::new_unchecked(self.p);
+            let mut failures = 0;
+            while !rng.sample(d) {
+                failures += 1;
+            }
+            return failures;
+        }
+
+        // Inverse transform: `P(X >= k) = (1 - p)^k`, hence
+        // `floor(ln(U) / ln(1 - p))` is geometrically distributed for `U`
+        // uniform. Using `(0, 1]` guarantees the logarithm is finite.
+        let u: f64 = rng.sample(OpenClosed01);
+        (u.ln() / self.ln_1_p).floor() as u64
+    }
+}
+
+impl fmt::Display for Geometric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Geometric(p={})", self.p)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use Rng;
+    use distributions::{Distribution, DistError};
+    use super::Geometric;
+
+    #[test]
+    fn test_geometric_pmf() {
+        let geo = Geometric::new(0.3).unwrap();
+        assert!((geo.pmf(0) - 0.3).abs() < 1e-15);
+        assert!((geo.pmf(1) - 0.7 * 0.3).abs() < 1e-15);
+        assert!((geo.pmf(5) - 0.7f64.powi(5) * 0.3).abs() < 1e-15);
+
+        let mut sum = 0.0;
+        for k in 0..200 {
+            sum += geo.pmf(k);
+            assert!((geo.cdf(k) - sum).abs() < 1e-12);
+        }
+        assert!((sum - 1.0).abs() < 1e-12);
+
+        let certain = Geometric::new(1.0).unwrap();
+        assert_eq!(certain.pmf(0), 1.0);
+        assert_eq!(certain.pmf(3), 0.0);
+        assert_eq!(certain.cdf(0), 1.0);
+    }
+
+    #[test]
+    fn test_geometric_is_within_sigma() {
+        // Mean 3 and standard deviation sqrt(12) = 3.46.
+        let geo = Geometric::new(0.25).unwrap();
+        assert!(geo.is_within_sigma(3, 0.0));
+        assert!(geo.is_within_sigma(6, 1.0));
+        assert!(!geo.is_within_sigma(7, 1.0));
+        assert!(geo.is_within_sigma(0, 1.0));
+        assert!(geo.is_within_sigma(9, 2.0));
+        assert!(!geo.is_within_sigma(10, 2.0));
+    }
+
+    #[test]
+    fn test_geometric_certain() {
+        let mut rng = ::test::rng(360);
+        let geo = Geometric::new(1.0).unwrap();
+        for _ in 0..100 {
+            assert_eq!(geo.sample(&mut rng), 0);
+        }
+    }
+
+    #[test]
+    fn test_geometric_trials() {
+        let mut rng = ::test::rng(361);
+        for &p in [0.5, 0.9].iter() {
+            let geo = Geometric::new(p).unwrap();
+            let n = 10_000;
+            let sum: u64 = (0..n).map(|_| geo.sample(&mut rng)).sum();
+            let mean = sum as f64 / n as f64;
+            let expected = (1.0 - p) / p;
+            assert!((mean - expected).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_geometric_inverse_matches_trials() {
+        // Compare the inverse-transform fast path against directly counting
+        // failed Bernoulli trials.
+        let mut rng = ::test::rng(362);
+        let p = 0.05;
+        let geo = Geometric::new(p).unwrap();
+        let n = 20_000;
+
+        let mut fast = [0u32; 10];
+        let mut reference = [0u32; 10];
+        let (mut fast_sum, mut reference_sum) = (0, 0);
+        for _ in 0..n {
+            let k = geo.sample(&mut rng);
+            fast_sum += k;
+            if k < 10 { fast[k as usize] += 1; }
+
+            let mut k = 0;
+            while !rng.gen_bool(p) { k += 1; }
+            reference_sum += k;
+            if k < 10 { reference[k as usize] += 1; }
+        }
+
+        let expected = (1.0 - p) / p;
+        let fast_mean = fast_sum as f64 / n as f64;
+        let reference_mean = reference_sum as f64 / n as f64;
+        assert!((fast_mean - expected).abs() < expected / 20.0);
+        assert!((fast_mean - reference_mean).abs() < expected / 10.0);
+
+        for k in 0..10 {
+            let expected = geo.pmf(k as u64) * n as f64;
+            assert!((fast[k] as f64 - expected).abs() < expected / 5.0);
+            assert!((reference[k] as f64 - expected).abs() < expected / 5.0);
+        }
+    }
+
+    #[test]
+    fn test_geometric_invalid_p() {
+        assert_eq!(Geometric::new(0.0).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(Geometric::new(1.5).unwrap_err(), DistError::ProbabilityOutOfRange);
+    }
+}
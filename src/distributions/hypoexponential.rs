@@ -0,0 +1,114 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The hypoexponential distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Exp1};
+use distributions::dirichlet::fmt_list;
+use std::fmt;
+
+/// The hypoexponential distribution `Hypoexponential(rates)`.
+///
+/// This is the distribution of the sum of independent exponential variates
+/// with the given rates, such as the total time spent passing through a
+/// series of phases. It has mean `Σ 1/rate[i]` and variance `Σ 1/rate[i]^2`.
+/// The rates are usually distinct; if they are all equal this is the Erlang
+/// distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Hypoexponential, Distribution};
+///
+/// let phases = Hypoexponential::new(&[1.0, 2.0, 5.0]).unwrap();
+/// let v = phases.sample(&mut rand::thread_rng());
+/// println!("{} is from a Hypoexponential([1, 2, 5]) distribution", v);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hypoexponential {
+    rates: Vec<f64>,
+    /// The mean of each phase, `1 / rate`.
+    rate_inverses: Vec<f64>,
+}
+
+impl Hypoexponential {
+    /// Construct a new `Hypoexponential` with the rate of each phase.
+    ///
+    /// # Errors
+    /// - if `rates` is empty
+    /// - if any rate is not positive
+    ///
+    pub fn new(rates: &[f64]) -> Result<Hypoexponential, DistError> {
+        if rates.is_empty() {
+            return Err(DistError::SizeTooSmall);
+        }
+        if !rates.iter().all(|&rate| rate > 0.0) {
+            return Err(DistError::LambdaNotPositive);
+        }
+        Ok(Hypoexponential {
+            rates: rates.to_vec(),
+            rate_inverses: rates.iter().map(|&rate| 1.0 / rate).collect(),
+        })
+    }
+
+    /// Construct a new `Hypoexponential`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    /// - if `rates` is empty
+    /// - if any rate is not positive
+    ///
+    pub fn new_unchecked(rates: &[f64]) -> Hypoexponential {
+        Hypoexponential::new(rates)
+            .expect("Hypoexponential::new_unchecked called with invalid rates")
+    }
+}
+
+impl Distribution<f64> for Hypoexponential {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.rate_inverses.iter().map(|&inv| {
+            let n: f64 = rng.sample(Exp1);
+            n * inv
+        }).sum()
+    }
+}
+
+impl fmt::Display for Hypoexponential {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Hypoexponential(rates=")?;
+        fmt_list(f, &self.rates)?;
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::Hypoexponential;
+
+    #[test]
+    fn test_hypoexponential_moments() {
+        let mut rng = ::test::rng(184);
+        let rates = [0.5, 2.0, 10.0];
+        let distr = Hypoexponential::new(&rates).unwrap();
+        let samples: Vec<f64> = (0..100_000).map(|_| distr.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let mean = rates.iter().map(|&r| 1.0 / r).sum();
+        let var = rates.iter().map(|&r| 1.0 / (r * r)).sum();
+        ::test::assert_moments(&samples, mean, var, 0.03);
+    }
+
+    #[test]
+    fn test_hypoexponential_invalid() {
+        assert_eq!(Hypoexponential::new(&[]).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(Hypoexponential::new(&[1.0, 0.0]).unwrap_err(),
+                   DistError::LambdaNotPositive);
+        assert_eq!(Hypoexponential::new(&[::std::f64::NAN]).unwrap_err(),
+                   DistError::LambdaNotPositive);
+    }
+}
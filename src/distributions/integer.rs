@@ -10,6 +10,8 @@
 
 use {Rng};
 use distributions::{Distribution, Standard};
+#[cfg(rust_1_28)]
+use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
 #[cfg(feature="simd_support")]
 use packed_simd::*;
 #[cfg(all(target_arch = "x86", feature="nightly"))]
@@ -88,6 +90,32 @@ impl_int_from_uint! { i64, u64 }
 #[cfg(rust_1_26)] impl_int_from_uint! { i128, u128 }
 impl_int_from_uint! { isize, usize }
 
+// Zero is rejected and the value resampled, so the result is uniform over all
+// non-zero values. Even for `NonZeroU8` a resample is needed only 1 in 256
+// times.
+#[cfg(rust_1_28)]
+macro_rules! impl_nonzero {
+    ($ty:ty, $uty:ty) => {
+        impl Distribution<$ty> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                loop {
+                    if let Some(x) = <$ty>::new(rng.gen::<$uty>()) {
+                        return x;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(rust_1_28)] impl_nonzero! { NonZeroU8, u8 }
+#[cfg(rust_1_28)] impl_nonzero! { NonZeroU16, u16 }
+#[cfg(rust_1_28)] impl_nonzero! { NonZeroU32, u32 }
+#[cfg(rust_1_28)] impl_nonzero! { NonZeroU64, u64 }
+#[cfg(rust_1_28)] impl_nonzero! { NonZeroU128, u128 }
+#[cfg(rust_1_28)] impl_nonzero! { NonZeroUsize, usize }
+
 #[cfg(feature="simd_support")]
 macro_rules! simd_impl {
     ($(($intrinsic:ident, $vec:ty),)+) => {$(
@@ -158,4 +186,32 @@ mod tests {
         #[cfg(rust_1_26)]
         rng.sample::<u128, _>(Standard);
     }
+
+    #[test]
+    #[cfg(rust_1_28)]
+    fn test_nonzero() {
+        use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+        use rngs::mock::StepRng;
+
+        let mut rng = ::test::rng(807);
+        let mut counts = [0u32; 256];
+        for _ in 0..1_000_000 {
+            let x: NonZeroU8 = rng.gen();
+            counts[x.get() as usize] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        // Each value is expected 1_000_000 / 255 ≈ 3922 times.
+        assert!(counts[1..].iter().all(|&c| c > 3500 && c < 4350));
+
+        for _ in 0..1000 {
+            assert!(rng.gen::<NonZeroU16>().get() != 0);
+            assert!(rng.gen::<NonZeroU32>().get() != 0);
+            assert!(rng.gen::<NonZeroU64>().get() != 0);
+            assert!(rng.gen::<NonZeroUsize>().get() != 0);
+        }
+
+        // An all-zero draw is rejected.
+        let mut rng = StepRng::new(0, 1);
+        assert_eq!(rng.gen::<NonZeroU64>().get(), 1);
+    }
 }
@@ -0,0 +1,213 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The inverse Gaussian distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Standard, StandardNormal};
+use std::fmt;
+
+/// The inverse Gaussian distribution `IG(mean, shape)`.
+///
+/// This is the distribution of the first passage time of a Brownian motion
+/// with positive drift. It has density function
+/// `f(x) = sqrt(shape / (2 pi x^3)) exp(-shape (x - mean)^2 / (2 mean^2 x))`
+/// for `x > 0`, mean `mean` and variance `mean^3 / shape`.
+///
+/// The same distribution, with an additional location parameter, is also
+/// available as [`Wald`].
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{InverseGaussian, Distribution};
+///
+/// let ig = InverseGaussian::new(1.0, 2.0).unwrap();
+/// let v = ig.sample(&mut rand::thread_rng());
+/// println!("{} is from an IG(1, 2) distribution", v);
+/// ```
+///
+/// [`Wald`]: struct.Wald.html
+#[derive(Clone, Copy, Debug)]
+pub struct InverseGaussian {
+    mean: f64,
+    shape: f64,
+}
+
+impl InverseGaussian {
+    /// Construct a new `InverseGaussian` with the given `mean` and `shape`.
+    ///
+    /// Returns an error if `mean <= 0` or `shape <= 0`.
+    pub fn new(mean: f64, shape: f64) -> Result<InverseGaussian, DistError> {
+        if !(mean > 0.0) {
+            return Err(DistError::MeanNotPositive);
+        }
+        if !(shape > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        Ok(InverseGaussian { mean, shape })
+    }
+
+    /// Construct a new `InverseGaussian`, like [`new`](#method.new).
+    ///
+    /// Panics if `mean <= 0` or `shape <= 0`.
+    pub fn new_unchecked(mean: f64, shape: f64) -> InverseGaussian {
+        InverseGaussian::new(mean, shape)
+            .expect("InverseGaussian::new_unchecked called with invalid parameters")
+    }
+
+    /// The mean of the distribution.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The shape parameter of the distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+impl Distribution<f64> for InverseGaussian {
+    /// Uses the transformation method of Michael, Schucany and Haas (1976):
+    /// one of the two roots of a quadratic in a chi-squared variate is
+    /// picked with the appropriate probability.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let mu = self.mean;
+        let n: f64 = rng.sample(StandardNormal);
+        let y = mu * n * n;
+        let x = mu + mu / (2.0 * self.shape)
+            * (y - (4.0 * self.shape * y + y * y).sqrt());
+        let u: f64 = rng.sample(Standard);
+        if u <= mu / (mu + x) { x } else { mu * mu / x }
+    }
+}
+
+impl fmt::Display for InverseGaussian {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "InverseGaussian(mean={}, shape={})", self.mean, self.shape)
+    }
+}
+
+/// The Wald distribution: the [`InverseGaussian`] distribution under another
+/// name, optionally shifted by a location parameter.
+///
+/// Samples are `location + X` where `X ~ IG(mean, shape)`; without a shift
+/// this produces exactly the same values as [`InverseGaussian`].
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Wald, Distribution};
+///
+/// let wald = Wald::with_location(1.0, 2.0, 5.0).unwrap();
+/// let v = wald.sample(&mut rand::thread_rng());
+/// assert!(v >= 5.0);
+/// ```
+///
+/// [`InverseGaussian`]: struct.InverseGaussian.html
+#[derive(Clone, Copy, Debug)]
+pub struct Wald {
+    inner: InverseGaussian,
+    location: f64,
+}
+
+impl Wald {
+    /// Construct a new `Wald` with the given `mean` and `shape`, and no
+    /// location shift.
+    ///
+    /// Returns an error if `mean <= 0` or `shape <= 0`.
+    pub fn new(mean: f64, shape: f64) -> Result<Wald, DistError> {
+        Wald::with_location(mean, shape, 0.0)
+    }
+
+    /// Construct a new `Wald` with the given `mean` and `shape`, shifted by
+    /// `location`.
+    ///
+    /// Returns an error if `mean <= 0` or `shape <= 0`.
+    pub fn with_location(mean: f64, shape: f64, location: f64) -> Result<Wald, DistError> {
+        Ok(Wald { inner: InverseGaussian::new(mean, shape)?, location })
+    }
+
+    /// Construct a new `Wald`, like [`new`](#method.new).
+    ///
+    /// Panics if `mean <= 0` or `shape <= 0`.
+    pub fn new_unchecked(mean: f64, shape: f64) -> Wald {
+        Wald::new(mean, shape).expect("Wald::new_unchecked called with invalid parameters")
+    }
+
+    /// The mean parameter, which excludes the location shift.
+    pub fn mean(&self) -> f64 {
+        self.inner.mean
+    }
+
+    /// The shape parameter of the distribution.
+    pub fn shape(&self) -> f64 {
+        self.inner.shape
+    }
+
+    /// The location shift of the distribution.
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Distribution<f64> for Wald {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.location + self.inner.sample(rng)
+    }
+}
+
+impl fmt::Display for Wald {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Wald(mean={}, shape={}, location={})",
+               self.inner.mean, self.inner.shape, self.location)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::{InverseGaussian, Wald};
+
+    #[test]
+    fn test_inverse_gaussian() {
+        let mut rng = ::test::rng(154);
+        for &(mean, shape) in [(1.0, 2.0), (3.0, 0.5), (0.2, 10.0)].iter() {
+            let ig = InverseGaussian::new(mean, shape).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| ig.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x > 0.0));
+            ::test::assert_moments(&samples, mean, mean * mean * mean / shape, 0.1);
+        }
+    }
+
+    #[test]
+    fn test_inverse_gaussian_invalid() {
+        assert_eq!(InverseGaussian::new(0.0, 1.0).unwrap_err(), DistError::MeanNotPositive);
+        assert_eq!(InverseGaussian::new(1.0, -1.0).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(Wald::new(1.0, 0.0).unwrap_err(), DistError::ShapeNotPositive);
+    }
+
+    #[test]
+    fn test_wald_matches_inverse_gaussian() {
+        let ig = InverseGaussian::new(1.5, 3.0).unwrap();
+        let wald = Wald::new(1.5, 3.0).unwrap();
+        assert_eq!((wald.mean(), wald.shape(), wald.location()), (1.5, 3.0, 0.0));
+        let mut a = ::test::rng(155);
+        let mut b = ::test::rng(155);
+        for _ in 0..1000 {
+            assert_eq!(ig.sample(&mut a), wald.sample(&mut b));
+        }
+
+        let shifted = Wald::with_location(1.5, 3.0, -2.0).unwrap();
+        let mut a = ::test::rng(156);
+        let mut b = ::test::rng(156);
+        for _ in 0..1000 {
+            assert_eq!(ig.sample(&mut a) - 2.0, shifted.sample(&mut b));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A joint distribution of independent components.
+
+use {Rng, RngCore};
+use distributions::Distribution;
+use core::fmt;
+#[cfg(not(feature="std"))] use alloc::boxed::Box;
+#[cfg(not(feature="std"))] use alloc::vec::Vec;
+
+/// The joint distribution of independent real-valued components, sampling
+/// each of them into a `Vec<f64>`.
+///
+/// Components may be distributions of different types; they are sampled in
+/// the order in which they were added with [`push`](#method.push). This is a
+/// lightweight alternative to a custom `struct` implementing [`Distribution`]
+/// for each record type.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, Joint, Normal, Exp, Uniform};
+///
+/// let record = Joint::new()
+///     .push(Normal::new(170.0, 10.0).unwrap())
+///     .push(Exp::new(0.1).unwrap())
+///     .push(Uniform::new(0.0, 1.0));
+/// let v = record.sample(&mut rand::thread_rng());
+/// assert_eq!(v.len(), 3);
+/// ```
+///
+/// [`Distribution`]: trait.Distribution.html
+pub struct Joint {
+    components: Vec<Box<SampleF64>>,
+}
+
+/// An object-safe version of `Distribution<f64>`, so that distributions of
+/// different types can be stored together.
+trait SampleF64 {
+    fn sample_f64(&self, rng: &mut RngCore) -> f64;
+}
+
+impl<D: Distribution<f64>> SampleF64 for D {
+    fn sample_f64(&self, rng: &mut RngCore) -> f64 {
+        self.sample(rng)
+    }
+}
+
+impl Joint {
+    /// Construct a new `Joint` without any components.
+    pub fn new() -> Joint {
+        Joint { components: Vec::new() }
+    }
+
+    /// Add the distribution `distr` as the next component.
+    pub fn push<D: Distribution<f64> + 'static>(mut self, distr: D) -> Joint {
+        self.components.push(Box::new(distr));
+        self
+    }
+
+    /// The number of components, which is the length of each sample.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Return whether there are no components.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl Default for Joint {
+    fn default() -> Joint {
+        Joint::new()
+    }
+}
+
+impl Distribution<Vec<f64>> for Joint {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let mut rng = rng;
+        self.components.iter().map(|c| c.sample_f64(&mut rng)).collect()
+    }
+}
+
+impl fmt::Debug for Joint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Joint")
+            .field("components", &self.components.len())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature="std"))]
+mod test {
+    use distributions::{Distribution, Exp, Normal, Uniform};
+    use super::Joint;
+
+    #[test]
+    fn test_joint_marginals() {
+        let mut rng = ::test::rng(167);
+        let joint = Joint::new()
+            .push(Normal::new(5.0, 2.0).unwrap())
+            .push(Exp::new(0.5).unwrap())
+            .push(Uniform::new(1.0, 3.0));
+        assert_eq!(joint.len(), 3);
+
+        let samples: Vec<Vec<f64>> = (0..100_000).map(|_| joint.sample(&mut rng)).collect();
+        let column = |i: usize| -> Vec<f64> { samples.iter().map(|s| s[i]).collect() };
+        ::test::assert_moments(&column(0), 5.0, 4.0, 0.02);
+        ::test::assert_moments(&column(1), 2.0, 4.0, 0.02);
+        ::test::assert_moments(&column(2), 2.0, 1.0 / 3.0, 0.02);
+        assert!(column(2).iter().all(|&x| x >= 1.0 && x < 3.0));
+    }
+
+    #[test]
+    fn test_joint_empty() {
+        let mut rng = ::test::rng(168);
+        let joint = Joint::default();
+        assert!(joint.is_empty());
+        assert_eq!(joint.sample(&mut rng), Vec::<f64>::new());
+        assert_eq!(format!("{:?}", joint.push(Exp::new(1.0).unwrap())),
+                   "Joint { components: 1 }");
+    }
+}
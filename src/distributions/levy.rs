@@ -0,0 +1,104 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Lévy distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, StandardNormal};
+use std::fmt;
+
+/// The Lévy distribution `Levy(location, scale)`.
+///
+/// This is the stable distribution with stability `α = 1/2` and skewness
+/// `β = 1`, with density function
+/// `f(x) = sqrt(c / (2 π)) e^(-c / (2 (x - μ))) / (x - μ)^(3/2)` for
+/// `x > μ`, where `μ` is the location and `c` the scale. Its tail is so heavy
+/// that even the mean is infinite; the median is about `μ + 2.198 c`.
+///
+/// Samples are generated as `location + scale / Z^2` for a standard normal
+/// `Z`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Levy, Distribution};
+///
+/// let levy = Levy::new(0.0, 1.0).unwrap();
+/// let v = levy.sample(&mut rand::thread_rng());
+/// println!("{} is from a Levy(0, 1) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Levy {
+    location: f64,
+    scale: f64,
+}
+
+impl Levy {
+    /// Construct a new `Levy` with the given `location` and `scale`.
+    ///
+    /// Returns an error if `scale <= 0`.
+    pub fn new(location: f64, scale: f64) -> Result<Levy, DistError> {
+        if !(scale > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        Ok(Levy { location, scale })
+    }
+
+    /// Construct a new `Levy`, like [`new`](#method.new).
+    ///
+    /// Panics if `scale <= 0`.
+    pub fn new_unchecked(location: f64, scale: f64) -> Levy {
+        Levy::new(location, scale).expect("Levy::new_unchecked called with scale <= 0")
+    }
+}
+
+impl Distribution<f64> for Levy {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z: f64 = rng.sample(StandardNormal);
+        self.location + self.scale / (z * z)
+    }
+}
+
+impl fmt::Display for Levy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Levy(location={}, scale={})", self.location, self.scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::Levy;
+
+    #[test]
+    fn test_levy_median() {
+        let mut rng = ::test::rng(220);
+        let levy = Levy::new(3.0, 2.0).unwrap();
+        let mut samples: Vec<f64> = (0..100_001).map(|_| levy.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|&x| x > 3.0));
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        // The median of `1 / Z^2` is `1 / 0.6745^2`, the squared inverse of
+        // the upper quartile of the standard normal distribution.
+        let median = samples[50_000];
+        let expected = 3.0 + 2.0 / (0.674_489_75f64 * 0.674_489_75);
+        assert!((median - expected).abs() < 0.02 * expected, "{} != {}", median, expected);
+
+        // The tail decays like `x^(-1/2)`: `P(X - μ > 10^4 c)` is about
+        // `sqrt(2 / (π 10^4)) = 0.008`.
+        let huge = samples.iter().filter(|&&x| x > 3.0 + 2e4).count();
+        assert!(huge > 600 && huge < 1000, "{}", huge);
+        assert!(samples[100_000] > 3.0 + 2e8);
+    }
+
+    #[test]
+    fn test_levy_invalid() {
+        assert_eq!(Levy::new(0.0, 0.0).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Levy::new(0.0, -1.0).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Levy::new(0.0, ::std::f64::NAN).unwrap_err(), DistError::ScaleNotPositive);
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The log-uniform distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Uniform};
+use std::fmt;
+
+/// The log-uniform (reciprocal) distribution `LogUniform(low, high)`.
+///
+/// Samples are uniformly distributed in log space: `exp(U)` for `U` uniform
+/// on `[ln(low), ln(high))`. Each order of magnitude within the range is
+/// thus equally likely, which is useful for quantities spanning several
+/// orders of magnitude, such as decibel levels or frequencies. It has
+/// density function `f(x) = 1 / (x ln(high / low))` for `low <= x < high`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{LogUniform, Distribution};
+///
+/// let freq = LogUniform::new(20.0, 20_000.0).unwrap();
+/// let v = freq.sample(&mut rand::thread_rng());
+/// println!("{} Hz is from an audible frequency range", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LogUniform {
+    low: f64,
+    high: f64,
+    log_uniform: Uniform<f64>,
+}
+
+impl LogUniform {
+    /// Construct a new `LogUniform` on the range `[low, high)`.
+    ///
+    /// Returns an error unless `0 < low < high`, and `high` is finite.
+    pub fn new(low: f64, high: f64) -> Result<LogUniform, DistError> {
+        if !(low > 0.0 && low < high && high.is_finite()) {
+            return Err(DistError::InvalidRange);
+        }
+        Ok(LogUniform { low, high, log_uniform: Uniform::new(low.ln(), high.ln()) })
+    }
+
+    /// Construct a new `LogUniform`, like [`new`](#method.new).
+    ///
+    /// Panics unless `0 < low < high`, and `high` is finite.
+    pub fn new_unchecked(low: f64, high: f64) -> LogUniform {
+        LogUniform::new(low, high).expect("LogUniform::new_unchecked called with invalid range")
+    }
+}
+
+impl Distribution<f64> for LogUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = self.log_uniform.sample(rng).exp();
+        // Rounding may carry the result just outside of the range.
+        if x < self.low {
+            self.low
+        } else if x >= self.high {
+            self.low.max(self.high - self.high * ::std::f64::EPSILON)
+        } else {
+            x
+        }
+    }
+}
+
+impl fmt::Display for LogUniform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LogUniform(low={}, high={})", self.low, self.high)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::LogUniform;
+
+    #[test]
+    fn test_log_uniform_log_moments() {
+        let mut rng = ::test::rng(169);
+        let distr = LogUniform::new(2.0, 2e4).unwrap();
+        let samples: Vec<f64> = (0..100_000).map(|_| {
+            let x = distr.sample(&mut rng);
+            assert!(x >= 2.0 && x < 2e4);
+            x.ln()
+        }).collect();
+        let (a, b) = (2f64.ln(), 2e4f64.ln());
+        ::test::assert_moments(&samples, (a + b) / 2.0, (b - a) * (b - a) / 12.0, 0.02);
+    }
+
+    #[test]
+    fn test_log_uniform_decades() {
+        let mut rng = ::test::rng(170);
+        let distr = LogUniform::new(1.0, 1e6).unwrap();
+        let n = 60_000;
+        let mut counts = [0u32; 6];
+        for _ in 0..n {
+            counts[distr.sample(&mut rng).log10().floor() as usize] += 1;
+        }
+        // Each decade receives a sixth of the samples.
+        for &count in counts.iter() {
+            assert!((count as f64 - 10_000.0).abs() < 500.0, "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_log_uniform_invalid() {
+        assert_eq!(LogUniform::new(0.0, 1.0).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(LogUniform::new(-1.0, 1.0).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(LogUniform::new(2.0, 2.0).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(LogUniform::new(1.0, ::std::f64::INFINITY).unwrap_err(),
+                   DistError::InvalidRange);
+    }
+}
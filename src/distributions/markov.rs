@@ -0,0 +1,148 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A two-state Markov chain producing correlated booleans.
+
+use Rng;
+use distributions::{Distribution, DistError, Bernoulli};
+use core::fmt;
+
+/// A two-state Markov chain, producing a bursty stream of booleans.
+///
+/// The chain is either on (`true`) or off (`false`). Each step it stays on
+/// with probability `p_stay_on` if it is on, and stays off with probability
+/// `p_stay_off` if it is off; otherwise it switches. In the long run it is
+/// on for a fraction `(1 - p_stay_off) / (2 - p_stay_on - p_stay_off)` of
+/// the steps, and runs of `true` have a mean length of `1 / (1 - p_stay_on)`.
+///
+/// Since every step depends on the previous one, this is a stateful sampler
+/// advanced with [`next`](#method.next), rather than a [`Distribution`].
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::TwoStateMarkov;
+///
+/// let mut chain = TwoStateMarkov::new(0.9, 0.8).unwrap();
+/// let mut rng = rand::thread_rng();
+/// let bursts: Vec<bool> = (0..20).map(|_| chain.next(&mut rng)).collect();
+/// println!("{:?}", bursts);
+/// ```
+///
+/// [`Distribution`]: trait.Distribution.html
+#[derive(Clone, Copy, Debug)]
+pub struct TwoStateMarkov {
+    p_stay_on: f64,
+    p_stay_off: f64,
+    stay_on: Bernoulli,
+    stay_off: Bernoulli,
+    state: bool,
+}
+
+impl TwoStateMarkov {
+    /// Construct a new `TwoStateMarkov` chain, starting in the off state.
+    ///
+    /// Returns an error if either probability is not in `[0, 1]`.
+    pub fn new(p_stay_on: f64, p_stay_off: f64) -> Result<TwoStateMarkov, DistError> {
+        Ok(TwoStateMarkov {
+            p_stay_on,
+            p_stay_off,
+            stay_on: Bernoulli::new(p_stay_on)?,
+            stay_off: Bernoulli::new(p_stay_off)?,
+            state: false,
+        })
+    }
+
+    /// Construct a new `TwoStateMarkov`, like [`new`](#method.new).
+    ///
+    /// Panics if either probability is not in `[0, 1]`.
+    pub fn new_unchecked(p_stay_on: f64, p_stay_off: f64) -> TwoStateMarkov {
+        TwoStateMarkov::new(p_stay_on, p_stay_off)
+            .expect("TwoStateMarkov::new_unchecked called with invalid probabilities")
+    }
+
+    /// The current state of the chain, i.e. the value last returned by
+    /// [`next`](#method.next), or `false` initially.
+    pub fn state(&self) -> bool {
+        self.state
+    }
+
+    /// Advance the chain by one step, and return the new state.
+    pub fn next<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        let stay = if self.state {
+            self.stay_on.sample(rng)
+        } else {
+            self.stay_off.sample(rng)
+        };
+        self.state ^= !stay;
+        self.state
+    }
+}
+
+impl fmt::Display for TwoStateMarkov {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TwoStateMarkov(p_stay_on={}, p_stay_off={})", self.p_stay_on, self.p_stay_off)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use Rng;
+    use distributions::DistError;
+    use super::TwoStateMarkov;
+
+    #[test]
+    fn test_markov_stationary() {
+        let mut rng = ::test::rng(395);
+        let (p_on, p_off) = (0.9, 0.7);
+        let mut chain = TwoStateMarkov::new(p_on, p_off).unwrap();
+        let n = 100_000;
+        let (mut on, mut runs) = (0, 0);
+        let mut last = chain.state();
+        for _ in 0..n {
+            let x = chain.next(&mut rng);
+            assert_eq!(x, chain.state());
+            if x { on += 1; }
+            if x && !last { runs += 1; }
+            last = x;
+        }
+        let expected = (1.0 - p_off) / (2.0 - p_on - p_off);
+        assert!((on as f64 / n as f64 - expected).abs() < 0.01);
+
+        // Independent draws with the same on-fraction would have runs of mean
+        // length `1 / (1 - 0.75) = 4`, rather than `1 / (1 - 0.9) = 10`.
+        let mean_run = on as f64 / runs as f64;
+        assert!((mean_run - 10.0).abs() < 0.5);
+        let (mut independent_on, mut independent_runs) = (0, 0);
+        let mut last = false;
+        for _ in 0..n {
+            let x = rng.gen_bool(expected);
+            if x { independent_on += 1; }
+            if x && !last { independent_runs += 1; }
+            last = x;
+        }
+        assert!(mean_run > 2.0 * independent_on as f64 / independent_runs as f64);
+    }
+
+    #[test]
+    fn test_markov_deterministic() {
+        let mut rng = ::test::rng(396);
+        let mut stuck = TwoStateMarkov::new(0.3, 1.0).unwrap();
+        assert!((0..100).all(|_| !stuck.next(&mut rng)));
+        let mut alternating = TwoStateMarkov::new(0.0, 0.0).unwrap();
+        for i in 0..100 {
+            assert_eq!(alternating.next(&mut rng), i % 2 == 0);
+        }
+    }
+
+    #[test]
+    fn test_markov_invalid() {
+        assert_eq!(TwoStateMarkov::new(1.5, 0.5).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(TwoStateMarkov::new(0.5, -0.1).unwrap_err(), DistError::ProbabilityOutOfRange);
+    }
+}
@@ -70,10 +70,39 @@
 //!
 //! [`Alphanumeric`] is a simple distribution to sample random letters and
 //! numbers of the `char` type; in contrast [`Standard`] may sample any valid
-//! `char`.
+//! `char`. [`Utf8String`] samples `String`s of a given number of `char`s,
+//! optionally restricted to some ranges.
 //!
 //! [`WeightedIndex`] can be used to do weighted sampling from a set of items,
-//! such as from an array.
+//! such as from an array, and [`Categorical`] from the keys of a map of
+//! weights. [`CategoricalSampler`] samples without repeats, excluding the
+//! indices already chosen.
+//!
+//! [`Benford`] samples leading decimal digits according to Benford's law, for
+//! example to generate realistic-looking financial data.
+//!
+//! [`Dice`] samples the sum of rolling several fair dice.
+//!
+//! [`Dirac`] always returns the same value, to use a constant in place of a
+//! distribution.
+//!
+//! [`TwoStateMarkov`] is a stateful sampler producing a bursty stream of
+//! correlated booleans.
+//!
+//! [`Joint`] samples several independent real-valued distributions into a
+//! `Vec`, for example to generate structured records. [`Discretize`] bins the
+//! samples of a real-valued distribution into integer indices. [`RandomWalk`]
+//! accumulates the steps of a real-valued distribution into a path.
+//!
+//! Distributions implementing [`ContinuousDistribution`] also provide their
+//! probability density function, and those implementing
+//! [`AntitheticDistribution`] can sample negatively correlated pairs, and
+//! those implementing [`InverseCdf`] expose their quantile function.
+//! Distributions implementing [`Moments`] report their exact mean and
+//! variance; those of any real-valued distribution can be estimated by
+//! sampling with [`estimate_moments`], and its quantiles with
+//! [`RunningQuantile`]; [`estimate_probability`] estimates the probability
+//! of any event.
 //!
 //! # Non-uniform probability distributions
 //!
@@ -83,29 +112,60 @@
 //!   (e.g. errors, offsets):
 //!   - [`Normal`] distribution, and [`StandardNormal`] as a primitive
 //!   - [`Cauchy`] distribution
+//!   - [`Levy`] distribution
+//!   - [`Stable`] distribution, generalizing the normal, Cauchy and Lévy
+//!     distributions
+//!   - [`SkewNormal`] distribution
 //! - Related to Bernoulli trials (yes/no events, with a given probability):
-//!   - [`Binomial`] distribution
+//!   - [`Binomial`] distribution, and [`BetaBinomial`] for an uncertain
+//!     probability
+//!   - [`BinomialMixture`] of binomial distributions
+//!   - [`Geometric`] distribution
+//!   - [`NegativeBinomial`] distribution
+//!   - [`NegativeHypergeometric`] distribution
 //!   - [`Bernoulli`] distribution, similar to [`Rng::gen_bool`].
 //! - Related to positive real-valued quantities that grow exponentially
 //!   (e.g. prices, incomes, populations):
 //!   - [`LogNormal`] distribution
+//!   - [`LogUniform`] distribution
 //! - Related to the occurrence of independent events at a given rate:
-//!   - [`Pareto`] distribution
-//!   - [`Poisson`] distribution
+//!   - [`Pareto`] distribution, truncated as [`BoundedPareto`], and [`Zeta`]
+//!     as its discrete analogue
+//!   - [`Poisson`] distribution, and [`PoissonProcess`] for the times of
+//!     the events
 //!   - [`Exp`]onential distribution, and [`Exp1`] as a primitive
-//!   - [`Weibull`] distribution
+//!   - [`Hypoexponential`] distribution, the sum of exponentials, and
+//!     [`Erlang`] for equal rates
+//!   - [`InverseGaussian`] distribution, also available as [`Wald`]
+//!   - [`Weibull`] distribution, and [`DiscreteWeibull`] as its discrete
+//!     analogue
+//!   - [`Frechet`] distribution
+//!   - [`GeneralizedExtremeValue`] distribution
 //! - Gamma and derived distributions:
-//!   - [`Gamma`] distribution
+//!   - [`Gamma`] distribution, and [`InverseGamma`] for its reciprocal
 //!   - [`ChiSquared`] distribution
 //!   - [`StudentT`] distribution
 //!   - [`FisherF`] distribution
+//!   - [`NormalInverseGamma`] distribution, the conjugate prior of the mean
+//!     and variance of a normal distribution
 //! - Triangular distribution:
 //!   - [`Beta`] distribution
+//!   - [`Pert`] distribution
 //!   - [`Triangular`] distribution
 //! - Multivariate probability distributions
+//!   - [`BivariateNormal`] distribution
+//!   - [`MultivariateNormal`] distribution
 //!   - [`Dirichlet`] distribution
+//!   - [`DirichletMultinomial`] distribution
+//!   - [`Multinomial`] distribution
+//!   - [`UniformSimplex`] distribution
 //!   - [`UnitSphereSurface`] distribution
 //!   - [`UnitCircle`] distribution
+//!   - [`UnitDisc`] distribution
+//!
+//! Other continuous distributions can be sampled by [`Rejection`] sampling,
+//! given their density, or with [`PiecewiseLinearCdf`] from control points of
+//! their CDF.
 //!
 //! # Examples
 //!
@@ -115,7 +175,7 @@
 //! use rand::{thread_rng, Rng};
 //! use rand::distributions::Exp;
 //!
-//! let exp = Exp::new(2.0);
+//! let exp = Exp::new(2.0).unwrap();
 //! let v = thread_rng().sample(exp);
 //! println!("{} is from an Exp(2) distribution", v);
 //! ```
@@ -154,78 +214,198 @@
 //! [Floating point implementation]: struct.Standard.html#floating-point-implementation
 // distributions
 //! [`Alphanumeric`]: struct.Alphanumeric.html
+//! [`Benford`]: struct.Benford.html
 //! [`Bernoulli`]: struct.Bernoulli.html
+//! [`BivariateNormal`]: struct.BivariateNormal.html
+//! [`BoundedPareto`]: struct.BoundedPareto.html
+//! [`AntitheticDistribution`]: trait.AntitheticDistribution.html
 //! [`Beta`]: struct.Beta.html
+//! [`BetaBinomial`]: struct.BetaBinomial.html
 //! [`Binomial`]: struct.Binomial.html
+//! [`BinomialMixture`]: struct.BinomialMixture.html
+//! [`Categorical`]: struct.Categorical.html
+//! [`CategoricalSampler`]: struct.CategoricalSampler.html
 //! [`Cauchy`]: struct.Cauchy.html
 //! [`ChiSquared`]: struct.ChiSquared.html
+//! [`ContinuousDistribution`]: trait.ContinuousDistribution.html
+//! [`Dice`]: struct.Dice.html
+//! [`Dirac`]: struct.Dirac.html
 //! [`Dirichlet`]: struct.Dirichlet.html
+//! [`DiscreteWeibull`]: struct.DiscreteWeibull.html
+//! [`Discretize`]: struct.Discretize.html
+//! [`estimate_moments`]: fn.estimate_moments.html
+//! [`estimate_probability`]: fn.estimate_probability.html
+//! [`DirichletMultinomial`]: struct.DirichletMultinomial.html
+//! [`Erlang`]: struct.Erlang.html
 //! [`Exp`]: struct.Exp.html
 //! [`Exp1`]: struct.Exp1.html
 //! [`FisherF`]: struct.FisherF.html
+//! [`Frechet`]: struct.Frechet.html
 //! [`Gamma`]: struct.Gamma.html
+//! [`GeneralizedExtremeValue`]: struct.GeneralizedExtremeValue.html
+//! [`Geometric`]: struct.Geometric.html
+//! [`Hypoexponential`]: struct.Hypoexponential.html
+//! [`InverseCdf`]: trait.InverseCdf.html
+//! [`InverseGamma`]: struct.InverseGamma.html
+//! [`InverseGaussian`]: struct.InverseGaussian.html
+//! [`Joint`]: struct.Joint.html
+//! [`Levy`]: struct.Levy.html
+//! [`Moments`]: trait.Moments.html
 //! [`LogNormal`]: struct.LogNormal.html
+//! [`LogUniform`]: struct.LogUniform.html
+//! [`Multinomial`]: struct.Multinomial.html
+//! [`MultivariateNormal`]: struct.MultivariateNormal.html
+//! [`NegativeBinomial`]: struct.NegativeBinomial.html
+//! [`NegativeHypergeometric`]: struct.NegativeHypergeometric.html
 //! [`Normal`]: struct.Normal.html
+//! [`NormalInverseGamma`]: struct.NormalInverseGamma.html
 //! [`Open01`]: struct.Open01.html
 //! [`OpenClosed01`]: struct.OpenClosed01.html
 //! [`Pareto`]: struct.Pareto.html
+//! [`Pert`]: struct.Pert.html
+//! [`PiecewiseLinearCdf`]: struct.PiecewiseLinearCdf.html
 //! [`Poisson`]: struct.Poisson.html
+//! [`PoissonProcess`]: struct.PoissonProcess.html
+//! [`RandomWalk`]: struct.RandomWalk.html
+//! [`Rejection`]: struct.Rejection.html
+//! [`RunningQuantile`]: struct.RunningQuantile.html
+//! [`SkewNormal`]: struct.SkewNormal.html
+//! [`Stable`]: struct.Stable.html
 //! [`Standard`]: struct.Standard.html
 //! [`StandardNormal`]: struct.StandardNormal.html
 //! [`StudentT`]: struct.StudentT.html
 //! [`Triangular`]: struct.Triangular.html
+//! [`TwoStateMarkov`]: struct.TwoStateMarkov.html
 //! [`Uniform`]: struct.Uniform.html
 //! [`Uniform::new`]: struct.Uniform.html#method.new
 //! [`Uniform::new_inclusive`]: struct.Uniform.html#method.new_inclusive
+//! [`UniformSimplex`]: struct.UniformSimplex.html
 //! [`UnitSphereSurface`]: struct.UnitSphereSurface.html
 //! [`UnitCircle`]: struct.UnitCircle.html
+//! [`UnitDisc`]: struct.UnitDisc.html
+//! [`Utf8String`]: struct.Utf8String.html
+//! [`Wald`]: struct.Wald.html
 //! [`Weibull`]: struct.Weibull.html
 //! [`WeightedIndex`]: struct.WeightedIndex.html
+//! [`Zeta`]: struct.Zeta.html
 
 #[cfg(any(rust_1_26, features="nightly"))]
 use core::iter;
 use Rng;
 
 pub use self::other::Alphanumeric;
+pub use self::combinators::{Affine, RandomSign, Reflect, Filter, Zip, Discretize};
+pub use self::error::DistError;
 #[doc(inline)] pub use self::uniform::Uniform;
 pub use self::float::{OpenClosed01, Open01};
-pub use self::bernoulli::Bernoulli;
-#[cfg(feature="alloc")] pub use self::weighted::{WeightedIndex, WeightedError};
+pub use self::bernoulli::{Bernoulli, WeightedBool};
+pub use self::rejection::Rejection;
+pub use self::benford::Benford;
+pub use self::dice::Dice;
+pub use self::dirac::Dirac;
+pub use self::markov::TwoStateMarkov;
+pub use self::moments::{estimate_moments, estimate_probability, RunningQuantile};
+pub use self::negative_hypergeometric::NegativeHypergeometric;
+#[cfg(feature="alloc")] pub use self::weighted::{WeightedIndex, WeightedError, Categorical,
+    CategoricalSampler};
+#[cfg(feature="alloc")] pub use self::piecewise::PiecewiseLinearCdf;
+#[cfg(feature="alloc")] pub use self::joint::Joint;
+#[cfg(feature="alloc")] pub use self::random_walk::RandomWalk;
+#[cfg(feature="alloc")] pub use self::utf8_string::Utf8String;
 #[cfg(feature="std")] pub use self::unit_sphere::UnitSphereSurface;
 #[cfg(feature="std")] pub use self::unit_circle::UnitCircle;
+#[cfg(feature="std")] pub use self::unit_disc::UnitDisc;
 #[cfg(feature="std")] pub use self::gamma::{Gamma, ChiSquared, FisherF,
-    StudentT, Beta};
-#[cfg(feature="std")] pub use self::normal::{Normal, LogNormal, StandardNormal};
+    StudentT, Beta, InverseGamma};
+#[cfg(feature="std")] pub use self::normal::{Normal, LogNormal, StandardNormal,
+    BivariateNormal, MultivariateNormal};
+#[cfg(feature="std")] pub use self::erlang::Erlang;
 #[cfg(feature="std")] pub use self::exponential::{Exp, Exp1};
-#[cfg(feature="std")] pub use self::pareto::Pareto;
+#[cfg(feature="std")] pub use self::frechet::Frechet;
+#[cfg(feature="std")] pub use self::generalized_extreme_value::GeneralizedExtremeValue;
+#[cfg(feature="std")] pub use self::hypoexponential::Hypoexponential;
+#[cfg(feature="std")] pub use self::inverse_gaussian::{InverseGaussian, Wald};
+#[cfg(feature="std")] pub use self::levy::Levy;
+#[cfg(feature="std")] pub use self::log_uniform::LogUniform;
+#[cfg(feature="std")] pub use self::normal_inverse_gamma::NormalInverseGamma;
+#[cfg(feature="std")] pub use self::pareto::{Pareto, BoundedPareto};
+#[cfg(feature="std")] pub use self::pert::Pert;
 #[cfg(feature="std")] pub use self::poisson::Poisson;
-#[cfg(feature="std")] pub use self::binomial::Binomial;
+#[cfg(feature="std")] pub use self::poisson_process::PoissonProcess;
+#[cfg(feature="std")] pub use self::binomial::{Binomial, SampleAlgorithm, SampleStats,
+    binomial_kl_divergence};
+#[cfg(feature="std")] pub use self::beta_binomial::BetaBinomial;
+#[cfg(feature="std")] pub use self::binomial_mixture::BinomialMixture;
+#[cfg(feature="std")] pub use self::geometric::Geometric;
+#[cfg(feature="std")] pub use self::negative_binomial::NegativeBinomial;
 #[cfg(feature="std")] pub use self::cauchy::Cauchy;
-#[cfg(feature="std")] pub use self::dirichlet::Dirichlet;
+#[cfg(feature="std")] pub use self::dirichlet::{Dirichlet, DirichletMultinomial};
+#[cfg(feature="std")] pub use self::discrete_weibull::DiscreteWeibull;
+#[cfg(feature="std")] pub use self::multinomial::Multinomial;
+#[cfg(feature="std")] pub use self::simplex::UniformSimplex;
+#[cfg(feature="std")] pub use self::skew_normal::SkewNormal;
+#[cfg(feature="std")] pub use self::stable::Stable;
 #[cfg(feature="std")] pub use self::triangular::Triangular;
 #[cfg(feature="std")] pub use self::weibull::Weibull;
+#[cfg(feature="std")] pub use self::zeta::Zeta;
 
 pub mod uniform;
 mod bernoulli;
+mod benford;
+mod dice;
+mod dirac;
+mod markov;
+mod moments;
+mod negative_hypergeometric;
 #[cfg(feature="alloc")] mod weighted;
+#[cfg(feature="alloc")] mod piecewise;
+#[cfg(feature="alloc")] mod joint;
+#[cfg(feature="alloc")] mod random_walk;
+#[cfg(feature="alloc")] mod utf8_string;
 #[cfg(feature="std")] mod unit_sphere;
 #[cfg(feature="std")] mod unit_circle;
+#[cfg(feature="std")] mod unit_disc;
 #[cfg(feature="std")] mod gamma;
 #[cfg(feature="std")] mod normal;
+#[cfg(feature="std")] mod erlang;
 #[cfg(feature="std")] mod exponential;
+#[cfg(feature="std")] mod frechet;
+#[cfg(feature="std")] mod generalized_extreme_value;
+#[cfg(feature="std")] mod hypoexponential;
+#[cfg(feature="std")] mod inverse_gaussian;
+#[cfg(feature="std")] mod levy;
+#[cfg(feature="std")] mod log_uniform;
+#[cfg(feature="std")] mod normal_inverse_gamma;
 #[cfg(feature="std")] mod pareto;
+#[cfg(feature="std")] mod pert;
 #[cfg(feature="std")] mod poisson;
+#[cfg(feature="std")] mod poisson_process;
 #[cfg(feature="std")] mod binomial;
+#[cfg(feature="std")] mod beta_binomial;
+#[cfg(feature="std")] mod binomial_mixture;
+#[cfg(feature="std")] mod geometric;
+#[cfg(feature="std")] mod negative_binomial;
 #[cfg(feature="std")] mod cauchy;
 #[cfg(feature="std")] mod dirichlet;
+#[cfg(feature="std")] mod discrete_weibull;
+#[cfg(feature="std")] mod multinomial;
+#[cfg(feature="std")] mod simplex;
+#[cfg(feature="std")] mod skew_normal;
+#[cfg(feature="std")] mod stable;
 #[cfg(feature="std")] mod triangular;
 #[cfg(feature="std")] mod weibull;
+#[cfg(feature="std")] mod zeta;
 
+mod combinators;
+mod error;
 mod float;
 mod integer;
 mod other;
-mod utils;
+mod rejection;
+pub(crate) mod utils;
 #[cfg(feature="std")] mod ziggurat_tables;
+#[cfg(all(test, feature="std"))] mod common_tests;
+#[cfg(all(test, feature="std"))] mod value_stability;
 
 /// Types (distributions) that can be used to create a random instance of `T`.
 ///
@@ -238,8 +418,14 @@ mod utils;
 /// advantage of not needing to consider thread safety, and for most
 /// distributions efficient state-less sampling algorithms are available.
 ///
+/// Since sampling only takes `&self`, a distribution which is `Sync` (as all
+/// distributions in this crate are, given `Sync` parameters) can be shared
+/// between threads, with each thread sampling using its own `Rng`. With the
+/// `rayon` feature, [`sample_par`] does this to fill a buffer in parallel.
+///
 /// [`Rng`]: ../trait.Rng.html
 /// [`sample_iter`]: trait.Distribution.html#method.sample_iter
+/// [`sample_par`]: trait.Distribution.html#method.sample_par
 pub trait Distribution<T> {
     /// Generate a random value of `T`, using `rng` as the source of randomness.
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T;
@@ -277,12 +463,304 @@ pub trait Distribution<T> {
             phantom: ::core::marker::PhantomData,
         }
     }
+
+    /// Create a distribution returning `scale * x + shift` for each sample
+    /// `x` of this distribution.
+    ///
+    /// This is typically used to turn a standard distribution into a
+    /// located and scaled one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::thread_rng;
+    /// use rand::distributions::{Distribution, Exp};
+    ///
+    /// // Exponential distribution with rate 2, shifted to start at 10
+    /// let distr = Exp::new(1.0).unwrap().affine(0.5, 10.0);
+    /// let v = distr.sample(&mut thread_rng());
+    /// assert!(v >= 10.0);
+    /// ```
+    fn affine(self, scale: T, shift: T) -> Affine<Self, T>
+        where Self: Sized
+    {
+        Affine { distr: self, scale, shift }
+    }
+
+    /// Create a distribution returning `x` or `-x` with equal probability for
+    /// each sample `x` of this distribution.
+    ///
+    /// The sign is chosen with [`Rng::gen_sign`], independently of `x`. This
+    /// is typically used to turn a distribution of magnitudes into one which
+    /// is symmetric about zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::thread_rng;
+    /// use rand::distributions::{Distribution, Exp};
+    ///
+    /// // Laplace distribution with unit scale
+    /// let distr = Exp::new(1.0).unwrap().with_random_sign();
+    /// let v = distr.sample(&mut thread_rng());
+    /// println!("{} is from a Laplace distribution", v);
+    /// ```
+    ///
+    /// [`Rng::gen_sign`]: ../trait.Rng.html#method.gen_sign
+    fn with_random_sign(self) -> RandomSign<Self>
+        where Self: Sized
+    {
+        RandomSign { distr: self }
+    }
+
+    /// Create a distribution returning `x` or its mirror image about `center`,
+    /// `center - (x - center)`, with equal probability for each sample `x` of
+    /// this distribution.
+    ///
+    /// This turns a one-sided distribution into one which is symmetric about
+    /// `center`; [`with_random_sign`] is the special case of a center of
+    /// zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::thread_rng;
+    /// use rand::distributions::{Distribution, Exp};
+    ///
+    /// // Laplace distribution with unit scale, centered on 5
+    /// let distr = Exp::new(1.0).unwrap().affine(1.0, 5.0).reflect(5.0);
+    /// let v = distr.sample(&mut thread_rng());
+    /// println!("{} is from a Laplace distribution", v);
+    /// ```
+    ///
+    /// [`with_random_sign`]: trait.Distribution.html#method.with_random_sign
+    fn reflect(self, center: T) -> Reflect<Self, T>
+        where Self: Sized
+    {
+        Reflect { distr: self, center }
+    }
+
+    /// Create a distribution returning only the samples `x` of this
+    /// distribution for which `pred(&x)` is true.
+    ///
+    /// Samples are drawn until one is accepted, so this is equivalent to
+    /// conditioning on `pred` and takes `1 / p` attempts on average if `pred`
+    /// holds with probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// Sampling panics if no sample is accepted after a large number of
+    /// attempts, for example if `pred` never holds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::thread_rng;
+    /// use rand::distributions::{Distribution, Normal};
+    ///
+    /// // Standard normal distribution truncated to positive values
+    /// let distr = Normal::new(0.0, 1.0).unwrap().filter(|x| *x > 0.0);
+    /// let v = distr.sample(&mut thread_rng());
+    /// assert!(v > 0.0);
+    /// ```
+    fn filter<F>(self, pred: F) -> Filter<Self, F>
+        where Self: Sized, F: Fn(&T) -> bool
+    {
+        Filter { distr: self, pred }
+    }
+
+    /// Create a distribution returning pairs `(x, y)` of a sample `x` of this
+    /// distribution and a sample `y` of `other`, sampled independently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::thread_rng;
+    /// use rand::distributions::{Distribution, Binomial, Normal};
+    ///
+    /// let distr = Binomial::new(10, 0.5).unwrap().zip(Normal::new(0.0, 1.0).unwrap());
+    /// let (k, x): (u64, f64) = distr.sample(&mut thread_rng());
+    /// println!("{} successes and a noise of {}", k, x);
+    /// ```
+    fn zip<D, U>(self, other: D) -> Zip<Self, D>
+        where Self: Sized, D: Distribution<U>
+    {
+        Zip { first: self, second: other }
+    }
+
+    /// Estimate the expected number of random bits drawn from the `Rng` per
+    /// sample, if known.
+    ///
+    /// This is advisory only, for example to budget entropy in constrained
+    /// environments or to compare algorithms. It counts the bits requested
+    /// from the `Rng`, not the entropy of the samples. Distributions which
+    /// draw a widely varying amount return `None`, which is also the default.
+    fn entropy_bits(&self) -> Option<f64> {
+        None
+    }
+
+    /// Fill `out` with samples in parallel, using one of `rngs` per thread.
+    ///
+    /// `out` is split into `rngs.len()` contiguous chunks of equal length
+    /// (except for the last), and the `i`-th chunk is filled sequentially
+    /// using `rngs[i]`. The result is thus deterministic given the state of
+    /// the generators, and equal to filling each chunk in turn on a single
+    /// thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rngs` is empty while `out` is not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::FromEntropy;
+    /// use rand::rngs::SmallRng;
+    /// use rand::distributions::{Distribution, Uniform};
+    ///
+    /// let mut rngs: Vec<SmallRng> = (0..4).map(|_| SmallRng::from_entropy()).collect();
+    /// let mut out = [0u32; 1000];
+    /// Uniform::new(0, 10).sample_par(&mut rngs, &mut out);
+    /// assert!(out.iter().all(|&x| x < 10));
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn sample_par<R>(&self, rngs: &mut [R], out: &mut [T])
+        where Self: Sync, R: Rng + Send, T: Send
+    {
+        use rayon::prelude::*;
+
+        if out.is_empty() {
+            return;
+        }
+        assert!(!rngs.is_empty(), "Distribution::sample_par called without rngs");
+        let chunk_size = (out.len() + rngs.len() - 1) / rngs.len();
+        out.par_chunks_mut(chunk_size)
+            .zip(rngs.par_iter_mut())
+            .for_each(|(chunk, rng)| {
+                for x in chunk.iter_mut() {
+                    *x = self.sample(rng);
+                }
+            });
+    }
 }
 
 impl<'a, T, D: Distribution<T>> Distribution<T> for &'a D {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
         (*self).sample(rng)
     }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        (*self).entropy_bits()
+    }
+}
+
+/// Continuous distributions over `f64` with a known probability density
+/// function.
+///
+/// This is useful for importance sampling and particle filters, which need
+/// the density of each sample. Implementations can override
+/// [`sample_with_ln_pdf`] when the sample and its density share work.
+///
+/// # Example
+///
+/// ```
+/// use rand::thread_rng;
+/// use rand::distributions::{ContinuousDistribution, Normal};
+///
+/// let normal = Normal::new(0.0, 1.0).unwrap();
+/// let (x, ln_p) = normal.sample_with_ln_pdf(&mut thread_rng());
+/// assert_eq!(ln_p, normal.ln_pdf(x));
+/// ```
+///
+/// [`sample_with_ln_pdf`]: trait.ContinuousDistribution.html#method.sample_with_ln_pdf
+pub trait ContinuousDistribution: Distribution<f64> {
+    /// Natural logarithm of the probability density function at `x`.
+    fn ln_pdf(&self, x: f64) -> f64;
+
+    /// Generate a random value, and return it together with the natural
+    /// logarithm of its probability density.
+    fn sample_with_ln_pdf<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let x = self.sample(rng);
+        (x, self.ln_pdf(x))
+    }
+}
+
+/// Distributions over `f64` which can generate antithetic pairs of samples.
+///
+/// An antithetic pair is `(F⁻¹(u), F⁻¹(1 - u))` for a single uniform `u`,
+/// where `F⁻¹` is the inverse of the cumulative distribution function. Each
+/// member of the pair has the distribution of `self`, but the two are
+/// negatively correlated, so averaging a function over both can reduce the
+/// variance of Monte Carlo estimates. This is implemented for distributions
+/// with a closed-form inverse CDF.
+///
+/// # Example
+///
+/// ```
+/// use rand::thread_rng;
+/// use rand::distributions::{AntitheticDistribution, Exp};
+///
+/// let exp = Exp::new(2.0).unwrap();
+/// let (x, y) = exp.sample_antithetic(&mut thread_rng());
+/// let estimate = (x + y) / 2.0;
+/// println!("{} estimates the mean of 0.5", estimate);
+/// ```
+pub trait AntitheticDistribution: Distribution<f64> {
+    /// Generate an antithetic pair of random values.
+    fn sample_antithetic<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64);
+}
+
+/// Distributions over `f64` with a closed-form quantile function.
+///
+/// The quantile function is the inverse of the cumulative distribution
+/// function: `inverse_cdf(p)` is the value `x` such that a sample is at most
+/// `x` with probability `p`. Applied to a uniform variate on `(0, 1)` it
+/// gives a sample of the distribution, which is how most implementors
+/// sample.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{InverseCdf, Exp};
+///
+/// let exp = Exp::new(2.0).unwrap();
+/// let median = exp.inverse_cdf(0.5);
+/// assert!((median - 2f64.ln() / 2.0).abs() < 1e-15);
+/// ```
+pub trait InverseCdf: Distribution<f64> {
+    /// The quantile function at `p`.
+    ///
+    /// The result is unspecified for `p` outside of `[0, 1]`.
+    fn inverse_cdf(&self, p: f64) -> f64;
+}
+
+/// Distributions with a closed-form mean and variance.
+///
+/// This lets generic code, such as tests and calibration utilities, query
+/// the exact moments of any implementor, for example to compare them with
+/// sample statistics. Distributions whose mean or variance is infinite or
+/// undefined, like [`Cauchy`], do not implement it.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Moments, Exp, Normal};
+///
+/// fn coefficient_of_variation<D: Moments>(distr: &D) -> f64 {
+///     distr.variance().sqrt() / distr.mean()
+/// }
+///
+/// assert_eq!(coefficient_of_variation(&Exp::new(3.0).unwrap()), 1.0);
+/// assert_eq!(coefficient_of_variation(&Normal::new(4.0, 2.0).unwrap()), 0.5);
+/// ```
+///
+/// [`Cauchy`]: struct.Cauchy.html
+pub trait Moments {
+    /// The mean of the distribution.
+    fn mean(&self) -> f64;
+
+    /// The variance of the distribution.
+    fn variance(&self) -> f64;
 }
 
 
@@ -614,8 +1092,33 @@ mod tests {
     fn test_distributions_iter() {
         use distributions::Normal;
         let mut rng = ::test::rng(210);
-        let distr = Normal::new(10.0, 10.0);
+        let distr = Normal::new(10.0, 10.0).unwrap();
         let results: Vec<_> = distr.sample_iter(&mut rng).take(100).collect();
         println!("{:?}", results);
     }
+
+    #[cfg(feature="rayon")]
+    #[test]
+    fn test_sample_par() {
+        use distributions::Uniform;
+        let distr = Uniform::new(0u32, 1000);
+        let seeds = [421, 422, 423, 424, 425];
+        let n = 1003;
+
+        let mut rngs: Vec<_> = seeds.iter().map(|&s| ::test::rng(s)).collect();
+        let mut parallel = vec![0u32; n];
+        distr.sample_par(&mut rngs, &mut parallel);
+
+        let chunk_size = (n + seeds.len() - 1) / seeds.len();
+        let mut serial = Vec::with_capacity(n);
+        for (i, &s) in seeds.iter().enumerate() {
+            let mut rng = ::test::rng(s);
+            let len = chunk_size.min(n - i * chunk_size);
+            serial.extend(distr.sample_iter(&mut rng).take(len));
+        }
+        assert_eq!(parallel, serial);
+
+        let mut empty: [u32; 0] = [];
+        distr.sample_par(&mut rngs[..0], &mut empty);
+    }
 }
@@ -0,0 +1,317 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Estimating the moments, quantiles and probabilities of a distribution by
+//! sampling.
+
+use Rng;
+use distributions::{Distribution, DistError};
+
+/// Estimate the mean and variance of `dist` from `n` samples.
+///
+/// Returns the sample mean and the unbiased sample variance, computed in a
+/// single pass with Welford's online algorithm. This is numerically stable
+/// and does not store the samples.
+///
+/// # Panics
+///
+/// Panics if `n < 2`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{estimate_moments, Normal};
+///
+/// let normal = Normal::new(2.0, 3.0).unwrap();
+/// let (mean, var) = estimate_moments(&normal, &mut rand::thread_rng(), 10_000);
+/// println!("mean {} and variance {}, expected 2 and 9", mean, var);
+/// ```
+pub fn estimate_moments<D, R>(dist: &D, rng: &mut R, n: usize) -> (f64, f64)
+    where D: Distribution<f64> + ?Sized, R: Rng + ?Sized
+{
+    assert!(n >= 2, "estimate_moments called with fewer than 2 samples");
+    let mut mean = 0.0;
+    // Sum of squared differences from the current mean.
+    let mut m2 = 0.0;
+    for i in 0..n {
+        let x = dist.sample(rng);
+        let delta = x - mean;
+        mean += delta / (i + 1) as f64;
+        m2 += delta * (x - mean);
+    }
+    (mean, m2 / (n - 1) as f64)
+}
+
+/// Estimate the probability of `event` from `n` samples of `dist`: the
+/// fraction of the samples for which `event` is true.
+///
+/// This is useful to check an exact `pmf` or `cdf` against empirical
+/// frequencies; the standard error of the estimate is `sqrt(p (1 - p) / n)`.
+///
+/// # Panics
+///
+/// Panics if `n == 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{estimate_probability, Binomial};
+///
+/// let binomial = Binomial::new(10, 0.5).unwrap();
+/// let p = estimate_probability(&binomial, &mut rand::thread_rng(), 10_000, |&k| k == 5);
+/// println!("P(X = 5) is about {}, expected {}", p, binomial.pmf(5));
+/// ```
+pub fn estimate_probability<D, T, R, F>(dist: &D, rng: &mut R, n: usize, event: F) -> f64
+    where D: Distribution<T> + ?Sized, R: Rng + ?Sized, F: Fn(&T) -> bool
+{
+    assert!(n > 0, "estimate_probability called with no samples");
+    let hits = (0..n).filter(|_| event(&dist.sample(rng))).count();
+    hits as f64 / n as f64
+}
+
+/// A streaming estimate of the `p`-quantile of a sequence of samples, using
+/// the P² algorithm of Jain and Chlamtac.
+///
+/// Samples are added one at a time with [`push`](#method.push); the estimate
+/// is updated in constant time and memory by tracking five markers, at the
+/// minimum, the maximum, the estimated quantile and halfway between them.
+/// Until five samples have been seen the exact quantile is returned. To track
+/// several quantiles, such as the median and the 95th percentile, use one
+/// `RunningQuantile` for each.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, Exp, RunningQuantile};
+///
+/// let exp = Exp::new(1.0).unwrap();
+/// let mut p95 = RunningQuantile::new(0.95).unwrap();
+/// for x in exp.sample_iter(&mut rand::thread_rng()).take(10_000) {
+///     p95.push(x);
+/// }
+/// println!("95th percentile about {}, expected 3.0", p95.quantile().unwrap());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RunningQuantile {
+    p: f64,
+    count: u64,
+    /// Marker heights; the first samples, sorted, while `count < 5`.
+    heights: [f64; 5],
+    /// Marker positions, counted from zero.
+    positions: [f64; 5],
+    /// Desired marker positions.
+    desired: [f64; 5],
+}
+
+impl RunningQuantile {
+    /// Construct a new `RunningQuantile` estimating the `p`-quantile, e.g.
+    /// `0.5` for the median.
+    ///
+    /// Returns an error unless `0 < p < 1`.
+    pub fn new(p: f64) -> Result<RunningQuantile, DistError> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        Ok(RunningQuantile {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+        })
+    }
+
+    /// The number of samples added so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Add the sample `x`. NaN samples are ignored.
+    pub fn push(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        if self.count < 5 {
+            // Insert into the sorted initial samples.
+            let mut i = self.count as usize;
+            while i > 0 && self.heights[i - 1] > x {
+                self.heights[i] = self.heights[i - 1];
+                i -= 1;
+            }
+            self.heights[i] = x;
+            self.count += 1;
+            return;
+        }
+        self.count += 1;
+
+        let q = &mut self.heights;
+        let n = &mut self.positions;
+        // Find the cell containing `x`, extending the extremes if needed.
+        let k = if x < q[0] {
+            q[0] = x;
+            0
+        } else if x >= q[4] {
+            q[4] = x;
+            3
+        } else {
+            (1..4).find(|&i| x < q[i]).unwrap_or(4) - 1
+        };
+        for i in (k + 1)..5 {
+            n[i] += 1.0;
+        }
+        let p = self.p;
+        let increments = [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0];
+        for i in 0..5 {
+            self.desired[i] += increments[i];
+        }
+
+        // Move the middle markers towards their desired positions.
+        for i in 1..4 {
+            let d = self.desired[i] - n[i];
+            if (d >= 1.0 && n[i + 1] - n[i] > 1.0) || (d <= -1.0 && n[i - 1] - n[i] < -1.0) {
+                let d = if d > 0.0 { 1.0 } else { -1.0 };
+                // Piecewise-parabolic prediction of the new height.
+                let parabolic = q[i] + d / (n[i + 1] - n[i - 1])
+                    * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                       + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]));
+                q[i] = if q[i - 1] < parabolic && parabolic < q[i + 1] {
+                    parabolic
+                } else {
+                    // Fall back to linear prediction to keep the heights
+                    // ordered.
+                    let j = if d > 0.0 { i + 1 } else { i - 1 };
+                    q[i] + d * (q[j] - q[i]) / (n[j] - n[i])
+                };
+                n[i] += d;
+            }
+        }
+    }
+
+    /// The current estimate of the quantile, or `None` if no samples have
+    /// been added.
+    pub fn quantile(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            count if count < 5 => {
+                // Round to the nearest rank.
+                let rank = ((count - 1) as f64 * self.p + 0.5) as usize;
+                Some(self.heights[rank])
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::DistError;
+    use super::{estimate_moments, estimate_probability, RunningQuantile};
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_estimate_moments_normal() {
+        use distributions::Normal;
+        let mut rng = ::test::rng(164);
+        let normal = Normal::new(1e6, 2.0).unwrap();
+        let (mean, var) = estimate_moments(&normal, &mut rng, 100_000);
+        // The standard error of the mean is 2 / sqrt(100_000) = 0.006.
+        assert!((mean - 1e6).abs() < 0.03);
+        assert!((var - 4.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_estimate_moments_constant() {
+        use distributions::Dirac;
+        let mut rng = ::test::rng(165);
+        let (mean, var) = estimate_moments(&Dirac::new(3.5), &mut rng, 10);
+        assert_eq!(mean, 3.5);
+        assert_eq!(var, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_estimate_moments_too_few() {
+        use distributions::Dirac;
+        let mut rng = ::test::rng(166);
+        estimate_moments(&Dirac::new(0.0), &mut rng, 1);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_estimate_probability_binomial() {
+        use distributions::Binomial;
+        let mut rng = ::test::rng(219);
+        for &(n, p) in [(10, 0.5), (40, 0.3), (1000, 0.1)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let mean = binomial.mean() as u64;
+            let estimate = estimate_probability(&binomial, &mut rng, 100_000, |&k| k <= mean);
+            let exact = binomial.cdf(mean);
+            let sd = (exact * (1.0 - exact) / 100_000.0).sqrt();
+            assert!((estimate - exact).abs() < 5.0 * sd, "{}: {} != {}", binomial, estimate, exact);
+        }
+    }
+
+    #[test]
+    fn test_estimate_probability_certain() {
+        use distributions::Dirac;
+        let mut rng = ::test::rng(220);
+        assert_eq!(estimate_probability(&Dirac::new(2), &mut rng, 10, |&x| x == 2), 1.0);
+        assert_eq!(estimate_probability(&Dirac::new(2), &mut rng, 10, |&x| x > 2), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_estimate_probability_no_samples() {
+        use distributions::Dirac;
+        let mut rng = ::test::rng(221);
+        estimate_probability(&Dirac::new(0.0), &mut rng, 0, |_| true);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_running_quantile_normal() {
+        use distributions::{Distribution, Normal};
+        let mut rng = ::test::rng(174);
+        let normal = Normal::new(3.0, 2.0).unwrap();
+        let mut median = RunningQuantile::new(0.5).unwrap();
+        let mut p95 = RunningQuantile::new(0.95).unwrap();
+        for _ in 0..100_000 {
+            let x = normal.sample(&mut rng);
+            median.push(x);
+            p95.push(x);
+        }
+        assert_eq!(median.count(), 100_000);
+        assert!((median.quantile().unwrap() - 3.0).abs() < 0.05);
+        // The 95th percentile of the standard normal is 1.645.
+        assert!((p95.quantile().unwrap() - (3.0 + 1.645 * 2.0)).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_running_quantile_few_samples() {
+        let mut q = RunningQuantile::new(0.5).unwrap();
+        assert_eq!(q.quantile(), None);
+        q.push(3.0);
+        assert_eq!(q.quantile(), Some(3.0));
+        q.push(1.0);
+        q.push(::core::f64::NAN);
+        q.push(2.0);
+        assert_eq!(q.count(), 3);
+        assert_eq!(q.quantile(), Some(2.0));
+        // Monotone data keeps the median in the middle.
+        for x in 4..1001 {
+            q.push(x as f64);
+        }
+        assert!((q.quantile().unwrap() - 500.5).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_running_quantile_invalid() {
+        assert_eq!(RunningQuantile::new(0.0).unwrap_err(), DistError::ProbabilityOutOfRange);
+        assert_eq!(RunningQuantile::new(1.0).unwrap_err(), DistError::ProbabilityOutOfRange);
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multinomial distribution.
+
+use Rng;
+use distributions::{Distribution, DistError};
+use distributions::binomial::Binomial;
+use distributions::dirichlet::fmt_list;
+use distributions::utils::log_gamma;
+use std::f64::NEG_INFINITY;
+use std::fmt;
+
+/// The multinomial distribution `Multinomial(n, p)`.
+///
+/// This is the distribution of the counts of each category when `n`
+/// independent trials each fall into category `i` with probability `p[i]`.
+/// The result is a vector of counts summing to `n`, with probability
+/// `n! / (k[0]! k[1]! ...) p[0]^k[0] p[1]^k[1] ...`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Multinomial, Distribution};
+///
+/// let multinomial = Multinomial::new(10, &[0.2, 0.3, 0.5]).unwrap();
+/// let counts = multinomial.sample(&mut rand::thread_rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Multinomial {
+    /// Number of trials.
+    n: u64,
+    /// Probability of each category, summing to 1.
+    p: Vec<f64>,
+}
+
+impl Multinomial {
+    /// Construct a new `Multinomial` with `n` trials and category
+    /// probabilities `p`. The probabilities are normalized to sum to 1.
+    ///
+    /// # Errors
+    /// - if `p.len() < 2`
+    /// - if any `p[i]` is negative, infinite or NaN, or all are zero
+    ///
+    pub fn new(n: u64, p: &[f64]) -> Result<Multinomial, DistError> {
+        if p.len() < 2 {
+            return Err(DistError::SizeTooSmall);
+        }
+        if !p.iter().all(|&x| x >= 0.0 && x.is_finite()) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        let total: f64 = p.iter().sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        Ok(Multinomial { n, p: p.iter().map(|&x| x / total).collect() })
+    }
+
+    /// Construct a new `Multinomial`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    /// - if `p.len() < 2`
+    /// - if any `p[i]` is negative, infinite or NaN, or all are zero
+    ///
+    pub fn new_unchecked(n: u64, p: &[f64]) -> Multinomial {
+        Multinomial::new(n, p)
+            .expect("Multinomial::new_unchecked called with invalid probabilities")
+    }
+
+    /// Natural logarithm of the probability mass function: the log of the
+    /// probability of observing exactly `counts`.
+    ///
+    /// Returns negative infinity if `counts` don't sum to `n`.
+    ///
+    /// # Panics
+    /// - if `counts.len()` is not the number of categories
+    ///
+    pub fn ln_pmf(&self, counts: &[u64]) -> f64 {
+        assert_eq!(counts.len(), self.p.len(),
+                   "counts length does not match number of categories");
+        let total = counts.iter().fold(Some(0u64), |acc, &k| acc.and_then(|s| s.checked_add(k)));
+        if total != Some(self.n) {
+            return NEG_INFINITY;
+        }
+        let mut result = log_gamma(self.n as f64 + 1.0);
+        for (&k, &p) in counts.iter().zip(self.p.iter()) {
+            if k == 0 {
+                continue;
+            }
+            if p == 0.0 {
+                return NEG_INFINITY;
+            }
+            let k = k as f64;
+            result += k * p.ln() - log_gamma(k + 1.0);
+        }
+        result
+    }
+
+    /// Write the count of each category into `out`.
+    ///
+    /// # Panics
+    /// - if `out.len()` is not the number of categories
+    ///
+    pub fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u64]) {
+        assert_eq!(out.len(), self.p.len(),
+                   "output length does not match number of categories");
+        sample_multinomial_into(rng, self.n, &self.p, out);
+    }
+
+    /// Write the proportion of the trials which fall into each category,
+    /// the counts divided by `n`, into `out`. If `n == 0` all proportions
+    /// are zero.
+    ///
+    /// # Panics
+    /// - if `out.len()` is not the number of categories
+    ///
+    pub fn sample_proportions<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [f64]) {
+        assert_eq!(out.len(), self.p.len(),
+                   "output length does not match number of categories");
+        let n = self.n.max(1) as f64;
+        sample_multinomial_with(rng, self.n, &self.p, |i, count| out[i] = count as f64 / n);
+    }
+}
+
+/// Distribute `n` trials among the categories with probabilities `p`,
+/// writing the counts into `out`, which has the same length as `p`.
+pub(crate) fn sample_multinomial_into<R: Rng + ?Sized>(
+    rng: &mut R, n: u64, p: &[f64], out: &mut [u64])
+{
+    sample_multinomial_with(rng, n, p, |i, count| out[i] = count);
+}
+
+/// Distribute `n` trials among the categories with probabilities `p`,
+/// passing the index and count of each category to `emit`.
+fn sample_multinomial_with<R: Rng + ?Sized, F: FnMut(usize, u64)>(
+    rng: &mut R, n: u64, p: &[f64], mut emit: F)
+{
+    // Multinomial sampling by conditional binomials: each category takes
+    // its share of the trials which remain after the previous categories.
+    let mut remaining_n = n;
+    let mut remaining_p = 1.0;
+    let last = p.len() - 1;
+    for i in 0..last {
+        let count = if remaining_n == 0 || !(remaining_p > 0.0) {
+            0
+        } else {
+            let q = (p[i] / remaining_p).min(1.0);
+            Binomial::new_unchecked(remaining_n, q).sample(rng)
+        };
+        emit(i, count);
+        remaining_n -= count;
+        remaining_p -= p[i];
+    }
+    emit(last, remaining_n);
+}
+
+impl Distribution<Vec<u64>> for Multinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        let mut out = vec![0; self.p.len()];
+        self.sample_into(rng, &mut out);
+        out
+    }
+}
+
+impl fmt::Display for Multinomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Multinomial(n={}, p=", self.n)?;
+        fmt_list(f, &self.p)?;
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Multinomial;
+    use distributions::{Distribution, DistError};
+    use std::f64::NEG_INFINITY;
+
+    fn factorial(k: u64) -> f64 {
+        (1..k + 1).fold(1.0, |acc, i| acc * i as f64)
+    }
+
+    #[test]
+    fn test_multinomial_ln_pmf() {
+        let p = [0.2, 0.3, 0.5];
+        let n = 6;
+        let multinomial = Multinomial::new(n, &p).unwrap();
+        let mut total = 0.0;
+        for a in 0..n + 1 {
+            for b in 0..n + 1 - a {
+                let counts = [a, b, n - a - b];
+                let mut expected = factorial(n);
+                for i in 0..3 {
+                    expected *= p[i].powi(counts[i] as i32) / factorial(counts[i]);
+                }
+                let pmf = multinomial.ln_pmf(&counts).exp();
+                assert!((pmf - expected).abs() < 1e-12, "{:?}: {} != {}", counts, pmf, expected);
+                total += pmf;
+            }
+        }
+        assert!((total - 1.0).abs() < 1e-12);
+
+        assert_eq!(multinomial.ln_pmf(&[1, 2, 2]), NEG_INFINITY);
+        assert_eq!(multinomial.ln_pmf(&[1, ::core::u64::MAX, 2]), NEG_INFINITY);
+        let impossible = Multinomial::new(2, &[0.0, 1.0]).unwrap();
+        assert_eq!(impossible.ln_pmf(&[1, 1]), NEG_INFINITY);
+        assert_eq!(impossible.ln_pmf(&[0, 2]), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multinomial_ln_pmf_wrong_length() {
+        Multinomial::new(2, &[0.5, 0.5]).unwrap().ln_pmf(&[1, 1, 0]);
+    }
+
+    #[test]
+    fn test_multinomial_sample() {
+        // Unnormalized probabilities 0.1, 0.3 and 0.6.
+        let multinomial = Multinomial::new(20, &[1.0, 3.0, 6.0]).unwrap();
+        let mut rng = ::test::rng(171);
+        let mut sums = [0u64; 3];
+        let trials = 50_000;
+        for _ in 0..trials {
+            let counts = multinomial.sample(&mut rng);
+            assert_eq!(counts.iter().sum::<u64>(), 20);
+            for i in 0..3 { sums[i] += counts[i]; }
+        }
+        for (i, &p) in [0.1, 0.3, 0.6].iter().enumerate() {
+            let mean = sums[i] as f64 / trials as f64;
+            assert!((mean - 20.0 * p).abs() < 20.0 * p * 0.02);
+        }
+    }
+
+    #[test]
+    fn test_multinomial_sample_proportions() {
+        let mut rng = ::test::rng(222);
+        let p = [0.1, 0.3, 0.6];
+        let mut out = [0.0; 3];
+        for &(n, tol) in [(100u64, 0.2), (10_000, 0.02), (1_000_000, 0.002)].iter() {
+            let multinomial = Multinomial::new(n, &p).unwrap();
+            multinomial.sample_proportions(&mut rng, &mut out);
+            assert!((out.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            for i in 0..3 {
+                assert!((out[i] - p[i]).abs() < tol, "n = {}: {:?}", n, out);
+            }
+        }
+        Multinomial::new(0, &p).unwrap().sample_proportions(&mut rng, &mut out);
+        assert_eq!(out, [0.0; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multinomial_sample_proportions_wrong_length() {
+        let mut rng = ::test::rng(223);
+        Multinomial::new(2, &[0.5, 0.5]).unwrap().sample_proportions(&mut rng, &mut [0.0; 3]);
+    }
+
+    #[test]
+    fn test_multinomial_invalid() {
+        assert_eq!(Multinomial::new(5, &[1.0]).unwrap_err(), DistError::SizeTooSmall);
+        assert_eq!(Multinomial::new(5, &[0.5, -0.5]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(Multinomial::new(5, &[0.0, 0.0]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The negative binomial distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Gamma, Moments, Poisson};
+use distributions::utils::log_gamma;
+use std::fmt;
+
+/// The negative binomial distribution `NegativeBinomial(r, p)`.
+///
+/// This is the distribution of the number of failures before the `r`-th
+/// success in a series of independent Bernoulli trials, each with probability
+/// of success `p`. It has density function
+/// `f(k) = Γ(k + r) / (k! Γ(r)) p^r (1 - p)^k` for `k >= 0`, which also
+/// makes sense for non-integral `r > 0`.
+///
+/// Samples are generated as a gamma–Poisson mixture: `λ` is drawn from
+/// `Gamma(r, (1 - p) / p)`, and the result from `Poisson(λ)`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{NegativeBinomial, Distribution};
+///
+/// let nb = NegativeBinomial::new(3.0, 0.25).unwrap();
+/// let v = nb.sample(&mut rand::thread_rng());
+/// println!("{} is from a NegativeBinomial(3, 0.25) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeBinomial {
+    /// Number of successes.
+    r: f64,
+    /// Probability of success.
+    p: f64,
+    /// The mixing distribution, or `None` if `p == 1`.
+    gamma: Option<Gamma>,
+}
+
+impl NegativeBinomial {
+    /// Construct a new `NegativeBinomial` with the given number of successes
+    /// `r` and probability of success `p`.
+    ///
+    /// Returns an error if `r <= 0`, or if `p <= 0`, `p > 1` or `p` is NaN.
+    pub fn new(r: f64, p: f64) -> Result<NegativeBinomial, DistError> {
+        if !(r > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        let gamma = if p == 1.0 {
+            None
+        } else {
+            Some(Gamma::new_unchecked(r, (1.0 - p) / p))
+        };
+        Ok(NegativeBinomial { r, p, gamma })
+    }
+
+    /// Construct a new `NegativeBinomial`, like [`new`](#method.new).
+    ///
+    /// Panics if `r <= 0`, or if `p <= 0`, `p > 1` or `p` is NaN.
+    pub fn new_unchecked(r: f64, p: f64) -> NegativeBinomial {
+        NegativeBinomial::new(r, p)
+            .expect("NegativeBinomial::new_unchecked called with invalid parameters")
+    }
+
+    /// Natural logarithm of the probability mass function: the logarithm of
+    /// the probability of exactly `k` failures.
+    ///
+    /// This is computed in log-space, so it is accurate even when the
+    /// probability itself underflows or `r` is large.
+    pub fn ln_pmf(&self, k: u64) -> f64 {
+        // Avoid `0 * ln(0)` for certain success.
+        if self.p == 1.0 {
+            return if k == 0 { 0.0 } else { ::std::f64::NEG_INFINITY };
+        }
+        let k = k as f64;
+        log_gamma(k + self.r) - log_gamma(k + 1.0) - log_gamma(self.r)
+            + self.r * self.p.ln() + k * (-self.p).ln_1p()
+    }
+
+    /// Probability mass function: the probability of exactly `k` failures.
+    pub fn pmf(&self, k: u64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    /// The mean of the distribution, `r (1 - p) / p`.
+    pub fn mean(&self) -> f64 {
+        self.r * (1.0 - self.p) / self.p
+    }
+
+    /// The variance of the distribution, `r (1 - p) / p^2`.
+    pub fn variance(&self) -> f64 {
+        self.mean() / self.p
+    }
+}
+
+impl Moments for NegativeBinomial {
+    fn mean(&self) -> f64 {
+        NegativeBinomial::mean(self)
+    }
+
+    fn variance(&self) -> f64 {
+        NegativeBinomial::variance(self)
+    }
+}
+
+impl Distribution<u64> for NegativeBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let gamma = match self.gamma {
+            Some(ref gamma) => gamma,
+            None => return 0,
+        };
+        let lambda = gamma.sample(rng);
+        // For small `r` the gamma sample may underflow to zero.
+        if lambda > 0.0 {
+            Poisson::new_unchecked(lambda).sample(rng)
+        } else {
+            0
+        }
+    }
+}
+
+impl fmt::Display for NegativeBinomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NegativeBinomial(r={}, p={})", self.r, self.p)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError, Moments};
+    use super::NegativeBinomial;
+
+    #[test]
+    fn test_negative_binomial_moments() {
+        let mut rng = ::test::rng(411);
+        for &(r, p) in [(1.0, 0.5), (3.0, 0.25), (0.5, 0.1), (50.0, 0.9)].iter() {
+            let nb = NegativeBinomial::new(r, p).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| nb.sample(&mut rng) as f64).collect();
+            let mean = r * (1.0 - p) / p;
+            let var = mean / p;
+            ::test::assert_moments(&samples, mean, var, 0.03);
+            assert_eq!(nb.mean(), mean);
+            assert_eq!(nb.variance(), var);
+            assert_eq!((Moments::mean(&nb), Moments::variance(&nb)), (mean, var));
+        }
+    }
+
+    #[test]
+    fn test_negative_binomial_pmf() {
+        for &(r, p) in [(1.0, 0.5), (3.0, 0.25), (0.5, 0.1), (50.0, 0.9), (1e6, 0.999)].iter() {
+            let nb = NegativeBinomial::new(r, p).unwrap();
+            // Sum far enough into the tail, and check the moments. `log_gamma`
+            // limits the accuracy for large `r`.
+            let end = (nb.mean() + 40.0 * nb.variance().sqrt()) as u64 + 20;
+            let (mut total, mut mean, mut var) = (0.0, 0.0, 0.0);
+            for k in 0..end {
+                let pmf = nb.pmf(k);
+                let k = k as f64;
+                total += pmf;
+                mean += k * pmf;
+                var += (k - nb.mean()).powi(2) * pmf;
+            }
+            assert!((total - 1.0).abs() < 1e-6, "{}", total);
+            assert!((mean - nb.mean()).abs() < 1e-6 * nb.mean().max(1.0));
+            assert!((var - nb.variance()).abs() < 1e-6 * nb.variance().max(1.0));
+        }
+        // A geometric distribution for `r = 1`.
+        let geometric = NegativeBinomial::new(1.0, 0.25).unwrap();
+        assert!((geometric.pmf(2) - 0.25 * 0.75 * 0.75).abs() < 1e-15);
+
+        let certain = NegativeBinomial::new(5.0, 1.0).unwrap();
+        assert_eq!(certain.pmf(0), 1.0);
+        assert_eq!(certain.pmf(1), 0.0);
+        assert_eq!((certain.mean(), certain.variance()), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_negative_binomial_certain() {
+        let mut rng = ::test::rng(412);
+        let nb = NegativeBinomial::new(5.0, 1.0).unwrap();
+        for _ in 0..100 {
+            assert_eq!(nb.sample(&mut rng), 0);
+        }
+    }
+
+    #[test]
+    fn test_negative_binomial_invalid() {
+        assert_eq!(NegativeBinomial::new(0.0, 0.5).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(NegativeBinomial::new(1.0, 0.0).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(NegativeBinomial::new(1.0, 1.5).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+    }
+}
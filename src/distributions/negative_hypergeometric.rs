@@ -0,0 +1,151 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The negative hypergeometric distribution.
+
+use Rng;
+use distributions::{Distribution, DistError};
+use core::fmt;
+
+/// The negative hypergeometric distribution
+/// `NegativeHypergeometric(population, successes, failures_before_stop)`.
+///
+/// An urn holds `population` items, of which `successes` count as successes
+/// and the rest as failures. Items are drawn without replacement until
+/// `failures_before_stop` failures have been drawn; this is the distribution
+/// of the number of successes drawn by then. Its support is
+/// `0..=successes`, and it has mean
+/// `failures_before_stop * successes / (population - successes + 1)`.
+///
+/// Samples are generated by simulating the draws, which takes time linear in
+/// the result plus `failures_before_stop`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{NegativeHypergeometric, Distribution};
+///
+/// let nh = NegativeHypergeometric::new(52, 13, 2).unwrap();
+/// let v = nh.sample(&mut rand::thread_rng());
+/// println!("{} hearts were drawn before the second other card", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeHypergeometric {
+    population: u64,
+    successes: u64,
+    failures_before_stop: u64,
+}
+
+impl NegativeHypergeometric {
+    /// Construct a new `NegativeHypergeometric`, drawing from `population`
+    /// items of which `successes` are successes, until `failures_before_stop`
+    /// failures have been drawn.
+    ///
+    /// Returns an error if `successes > population`, or unless
+    /// `0 < failures_before_stop <= population - successes`.
+    pub fn new(population: u64, successes: u64, failures_before_stop: u64)
+        -> Result<NegativeHypergeometric, DistError>
+    {
+        if successes > population {
+            return Err(DistError::CountOutOfRange);
+        }
+        if failures_before_stop == 0 || failures_before_stop > population - successes {
+            return Err(DistError::CountOutOfRange);
+        }
+        Ok(NegativeHypergeometric { population, successes, failures_before_stop })
+    }
+
+    /// Construct a new `NegativeHypergeometric`, like [`new`](#method.new).
+    ///
+    /// Panics if `successes > population`, or unless
+    /// `0 < failures_before_stop <= population - successes`.
+    pub fn new_unchecked(population: u64, successes: u64, failures_before_stop: u64)
+        -> NegativeHypergeometric
+    {
+        NegativeHypergeometric::new(population, successes, failures_before_stop)
+            .expect("NegativeHypergeometric::new_unchecked called with invalid counts")
+    }
+}
+
+impl Distribution<u64> for NegativeHypergeometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let mut successes_left = self.successes;
+        let mut failures_left = self.population - self.successes;
+        let mut failures = 0;
+        // Once only failures remain, no more successes can be drawn.
+        while failures < self.failures_before_stop && successes_left > 0 {
+            if rng.gen_range(0, successes_left + failures_left) < successes_left {
+                successes_left -= 1;
+            } else {
+                failures_left -= 1;
+                failures += 1;
+            }
+        }
+        self.successes - successes_left
+    }
+}
+
+impl fmt::Display for NegativeHypergeometric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NegativeHypergeometric(population={}, successes={}, failures_before_stop={})",
+               self.population, self.successes, self.failures_before_stop)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::NegativeHypergeometric;
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_negative_hypergeometric_moments() {
+        let mut rng = ::test::rng(163);
+        for &(n, k, r) in [(52, 13, 2), (20, 10, 10), (100, 5, 1), (1000, 900, 30)].iter() {
+            let nh = NegativeHypergeometric::new(n, k, r).unwrap();
+            let samples: Vec<f64> = (0..50_000).map(|_| {
+                let x = nh.sample(&mut rng);
+                assert!(x <= k);
+                x as f64
+            }).collect();
+            let (n, k, r) = (n as f64, k as f64, r as f64);
+            let mean = r * k / (n - k + 1.0);
+            let var = r * k * (n + 1.0) * (n - k - r + 1.0)
+                / ((n - k + 1.0) * (n - k + 1.0) * (n - k + 2.0));
+            ::test::assert_moments(&samples, mean, var, 0.03);
+        }
+    }
+
+    #[test]
+    fn test_negative_hypergeometric_extremes() {
+        let mut rng = ::test::rng(164);
+        // Without successes there is nothing to count.
+        let none = NegativeHypergeometric::new(10, 0, 3).unwrap();
+        // Waiting for every failure, either bound of the support is reached.
+        let all = NegativeHypergeometric::new(10, 4, 6).unwrap();
+        let (mut seen_min, mut seen_max) = (false, false);
+        for _ in 0..1000 {
+            assert_eq!(none.sample(&mut rng), 0);
+            let x = all.sample(&mut rng);
+            assert!(x <= 4);
+            seen_min |= x == 0;
+            seen_max |= x == 4;
+        }
+        assert!(seen_min && seen_max);
+    }
+
+    #[test]
+    fn test_negative_hypergeometric_invalid() {
+        assert_eq!(NegativeHypergeometric::new(10, 11, 1).unwrap_err(),
+                   DistError::CountOutOfRange);
+        assert_eq!(NegativeHypergeometric::new(10, 4, 0).unwrap_err(),
+                   DistError::CountOutOfRange);
+        assert_eq!(NegativeHypergeometric::new(10, 4, 7).unwrap_err(),
+                   DistError::CountOutOfRange);
+    }
+}
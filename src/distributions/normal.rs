@@ -9,9 +9,13 @@
 
 //! The normal and derived distributions.
 
+use core::ops::{Add, Sub, Mul};
+
 use Rng;
-use distributions::{ziggurat_tables, Distribution, Open01};
-use distributions::utils::ziggurat;
+use distributions::{ziggurat_tables, Distribution, ContinuousDistribution, Moments, DistError,
+    Open01};
+use distributions::utils::{ziggurat, ziggurat_f32};
+use std::fmt;
 
 /// Samples floating-point numbers according to the normal distribution
 /// `N(0, 1)` (a.k.a. a standard normal, or Gaussian). This is equivalent to
@@ -19,6 +23,9 @@ use distributions::utils::ziggurat;
 ///
 /// See `Normal` for the general normal distribution.
 ///
+/// Both `f64` and `f32` samples are supported. The `f32` variant draws a
+/// single `u32` per attempt rather than upconverting from `f64`.
+///
 /// Implemented via the ZIGNOR variant[^1] of the Ziggurat method.
 ///
 /// [^1]: Jurgen A. Doornik (2005). [*An Improved Ziggurat Method to
@@ -37,45 +44,76 @@ use distributions::utils::ziggurat;
 #[derive(Clone, Copy, Debug)]
 pub struct StandardNormal;
 
+#[inline]
+fn zero_case<R: Rng + ?Sized>(rng: &mut R, u: f64) -> f64 {
+    // compute a random number in the tail by hand
+
+    // strange initial conditions, because the loop is not
+    // do-while, so the condition should be true on the first
+    // run, they get overwritten anyway (0 < 1, so these are
+    // good).
+    let mut x = 1.0f64;
+    let mut y = 0.0f64;
+
+    while -2.0 * y < x * x {
+        let x_: f64 = rng.sample(Open01);
+        let y_: f64 = rng.sample(Open01);
+
+        x = x_.ln() / ziggurat_tables::ZIG_NORM_R;
+        y = y_.ln();
+    }
+
+    if u < 0.0 { x - ziggurat_tables::ZIG_NORM_R } else { ziggurat_tables::ZIG_NORM_R - x }
+}
+
 impl Distribution<f64> for StandardNormal {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         #[inline]
         fn pdf(x: f64) -> f64 {
             (-x*x/2.0).exp()
         }
-        #[inline]
-        fn zero_case<R: Rng + ?Sized>(rng: &mut R, u: f64) -> f64 {
-            // compute a random number in the tail by hand
-
-            // strange initial conditions, because the loop is not
-            // do-while, so the condition should be true on the first
-            // run, they get overwritten anyway (0 < 1, so these are
-            // good).
-            let mut x = 1.0f64;
-            let mut y = 0.0f64;
-
-            while -2.0 * y < x * x {
-                let x_: f64 = rng.sample(Open01);
-                let y_: f64 = rng.sample(Open01);
-
-                x = x_.ln() / ziggurat_tables::ZIG_NORM_R;
-                y = y_.ln();
-            }
-
-            if u < 0.0 { x - ziggurat_tables::ZIG_NORM_R } else { ziggurat_tables::ZIG_NORM_R - x }
-        }
 
         ziggurat(rng, true, // this is symmetric
                  &ziggurat_tables::ZIG_NORM_X,
                  &ziggurat_tables::ZIG_NORM_F,
                  pdf, zero_case)
     }
+
+    /// The ziggurat method almost always accepts the first 64-bit draw.
+    fn entropy_bits(&self) -> Option<f64> {
+        Some(64.0)
+    }
+}
+
+impl Distribution<f32> for StandardNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        #[inline]
+        fn pdf(x: f32) -> f32 {
+            (-x*x/2.0).exp()
+        }
+        // The tail is rarely hit, so it is fine to sample it in `f64`.
+        #[inline]
+        fn zero_case_f32<R: Rng + ?Sized>(rng: &mut R, u: f32) -> f32 {
+            zero_case(rng, u as f64) as f32
+        }
+
+        ziggurat_f32(rng, true, // this is symmetric
+                     &ziggurat_tables::ZIG_NORM_X,
+                     &ziggurat_tables::ZIG_NORM_F,
+                     pdf, zero_case_f32)
+    }
+
+    /// The ziggurat method almost always accepts the first 32-bit draw.
+    fn entropy_bits(&self) -> Option<f64> {
+        Some(32.0)
+    }
 }
 
 /// The normal distribution `N(mean, std_dev**2)`.
 ///
 /// This uses the ZIGNOR variant of the Ziggurat method, see `StandardNormal`
-/// for more details.
+/// for more details. The parameter type `F` is the type of the samples, and
+/// can be `f64` (the default) or `f32`.
 ///
 /// # Example
 ///
@@ -83,37 +121,111 @@ impl Distribution<f64> for StandardNormal {
 /// use rand::distributions::{Normal, Distribution};
 ///
 /// // mean 2, standard deviation 3
-/// let normal = Normal::new(2.0, 3.0);
+/// let normal = Normal::new(2.0, 3.0).unwrap();
 /// let v = normal.sample(&mut rand::thread_rng());
+/// println!("{} is from a N(2, 9) distribution", v);
+///
+/// // the same distribution, sampling `f32`
+/// let normal = Normal::new(2.0f32, 3.0).unwrap();
+/// let v: f32 = normal.sample(&mut rand::thread_rng());
 /// println!("{} is from a N(2, 9) distribution", v)
 /// ```
 #[derive(Clone, Copy, Debug)]
-pub struct Normal {
-    mean: f64,
-    std_dev: f64,
+pub struct Normal<F = f64> {
+    mean: F,
+    std_dev: F,
 }
 
-impl Normal {
+impl<F: PartialOrd + From<f32>> Normal<F> {
     /// Construct a new `Normal` distribution with the given mean and
     /// standard deviation.
     ///
+    /// Returns an error if `std_dev < 0`.
+    #[inline]
+    pub fn new(mean: F, std_dev: F) -> Result<Normal<F>, DistError> {
+        if !(std_dev >= F::from(0.0)) {
+            return Err(DistError::StdDevNegative);
+        }
+        Ok(Normal {
+            mean,
+            std_dev
+        })
+    }
+
+    /// Construct a new `Normal`, like [`new`](#method.new).
+    ///
     /// # Panics
     ///
     /// Panics if `std_dev < 0`.
     #[inline]
-    pub fn new(mean: f64, std_dev: f64) -> Normal {
-        assert!(std_dev >= 0.0, "Normal::new called with `std_dev` < 0");
-        Normal {
-            mean,
-            std_dev
-        }
+    pub fn new_unchecked(mean: F, std_dev: F) -> Normal<F> {
+        Normal::new(mean, std_dev).expect("Normal::new_unchecked called with `std_dev` < 0")
+    }
+
+    /// Return whether `value` lies within `sigmas` standard deviations of
+    /// the mean, bounds included.
+    pub fn is_within_sigma(&self, value: F, sigmas: F) -> bool
+        where F: Copy + Add<Output = F> + Sub<Output = F> + Mul<Output = F>
+    {
+        let radius = sigmas * self.std_dev;
+        self.mean - radius <= value && value <= self.mean + radius
     }
 }
-impl Distribution<f64> for Normal {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        let n = rng.sample(StandardNormal);
+impl<F> Distribution<F> for Normal<F>
+    where F: Copy + Add<Output = F> + Mul<Output = F>, StandardNormal: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let n: F = rng.sample(StandardNormal);
         self.mean + self.std_dev * n
     }
+
+    fn entropy_bits(&self) -> Option<f64> {
+        <StandardNormal as Distribution<F>>::entropy_bits(&StandardNormal)
+    }
+}
+
+impl ContinuousDistribution for Normal<f64> {
+    /// For `std_dev == 0` this is `+∞` at the mean and `-∞` elsewhere.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if self.std_dev == 0.0 {
+            return if x == self.mean { ::std::f64::INFINITY } else { ::std::f64::NEG_INFINITY };
+        }
+        let z = (x - self.mean) / self.std_dev;
+        ln_pdf_standard(z) - self.std_dev.ln()
+    }
+
+    /// The density is computed from the standard normal variate before
+    /// scaling.
+    fn sample_with_ln_pdf<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        if self.std_dev == 0.0 {
+            return (self.mean, ::std::f64::INFINITY);
+        }
+        let z: f64 = rng.sample(StandardNormal);
+        (self.mean + self.std_dev * z, ln_pdf_standard(z) - self.std_dev.ln())
+    }
+}
+
+/// `ln` of the standard normal density at `z`.
+fn ln_pdf_standard(z: f64) -> f64 {
+    use std::f64::consts::PI;
+    -0.5 * (z * z + (2.0 * PI).ln())
+}
+
+impl Moments for Normal<f64> {
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The variance `std_dev^2`.
+    fn variance(&self) -> f64 {
+        self.std_dev * self.std_dev
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for Normal<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Normal(mean={}, std_dev={})", self.mean, self.std_dev)
+    }
 }
 
 
@@ -128,7 +240,7 @@ impl Distribution<f64> for Normal {
 /// use rand::distributions::{LogNormal, Distribution};
 ///
 /// // mean 2, standard deviation 3
-/// let log_normal = LogNormal::new(2.0, 3.0);
+/// let log_normal = LogNormal::new(2.0, 3.0).unwrap();
 /// let v = log_normal.sample(&mut rand::thread_rng());
 /// println!("{} is from an ln N(2, 9) distribution", v)
 /// ```
@@ -141,13 +253,20 @@ impl LogNormal {
     /// Construct a new `LogNormal` distribution with the given mean
     /// and standard deviation.
     ///
+    /// Returns an error if `std_dev < 0`.
+    #[inline]
+    pub fn new(mean: f64, std_dev: f64) -> Result<LogNormal, DistError> {
+        Ok(LogNormal { norm: Normal::new(mean, std_dev)? })
+    }
+
+    /// Construct a new `LogNormal`, like [`new`](#method.new).
+    ///
     /// # Panics
     ///
     /// Panics if `std_dev < 0`.
     #[inline]
-    pub fn new(mean: f64, std_dev: f64) -> LogNormal {
-        assert!(std_dev >= 0.0, "LogNormal::new called with `std_dev` < 0");
-        LogNormal { norm: Normal::new(mean, std_dev) }
+    pub fn new_unchecked(mean: f64, std_dev: f64) -> LogNormal {
+        LogNormal::new(mean, std_dev).expect("LogNormal::new_unchecked called with `std_dev` < 0")
     }
 }
 impl Distribution<f64> for LogNormal {
@@ -156,37 +275,401 @@ impl Distribution<f64> for LogNormal {
     }
 }
 
+impl Moments for LogNormal {
+    /// The mean `exp(mean + std_dev^2 / 2)`, in terms of the parameters of
+    /// the underlying normal distribution.
+    fn mean(&self) -> f64 {
+        (self.norm.mean + 0.5 * self.norm.variance()).exp()
+    }
+
+    /// The variance `(exp(std_dev^2) - 1) exp(2 mean + std_dev^2)`.
+    fn variance(&self) -> f64 {
+        let var = self.norm.variance();
+        var.exp_m1() * (2.0 * self.norm.mean + var).exp()
+    }
+}
+
+impl fmt::Display for LogNormal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LogNormal(mean={}, std_dev={})", self.norm.mean, self.norm.std_dev)
+    }
+}
+
+/// The bivariate normal distribution of two correlated normal variables,
+/// with means `mean`, standard deviations `std_dev` and correlation
+/// coefficient `rho`.
+///
+/// Samples are generated from two independent standard normal variables
+/// `z1` and `z2` using the Cholesky decomposition of the covariance matrix:
+/// `x = mean[0] + std_dev[0] * z1` and
+/// `y = mean[1] + std_dev[1] * (rho * z1 + sqrt(1 - rho^2) * z2)`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{BivariateNormal, Distribution};
+///
+/// let bvn = BivariateNormal::new([0.0, 10.0], [1.0, 2.0], 0.8).unwrap();
+/// let v = bvn.sample(&mut rand::thread_rng());
+/// println!("({}, {}) is from a bivariate normal distribution", v[0], v[1]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BivariateNormal {
+    mean: [f64; 2],
+    std_dev: [f64; 2],
+    rho: f64,
+    /// `sqrt(1 - rho^2)`
+    rho_c: f64,
+}
+
+impl BivariateNormal {
+    /// Construct a new `BivariateNormal` distribution.
+    ///
+    /// Returns an error if a standard deviation is not strictly positive, or
+    /// if `rho` is not in the open range `(-1, 1)`.
+    pub fn new(mean: [f64; 2], std_dev: [f64; 2], rho: f64)
+        -> Result<BivariateNormal, DistError>
+    {
+        if !(std_dev[0] > 0.0 && std_dev[1] > 0.0) {
+            return Err(DistError::StdDevNotPositive);
+        }
+        if !(rho > -1.0 && rho < 1.0) {
+            return Err(DistError::CorrelationOutOfRange);
+        }
+        Ok(BivariateNormal { mean, std_dev, rho, rho_c: (1.0 - rho * rho).sqrt() })
+    }
+
+    /// Construct a new `BivariateNormal`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a standard deviation is not strictly positive, or if `rho`
+    /// is not in the open range `(-1, 1)`.
+    pub fn new_unchecked(mean: [f64; 2], std_dev: [f64; 2], rho: f64) -> BivariateNormal {
+        BivariateNormal::new(mean, std_dev, rho)
+            .expect("BivariateNormal::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<[f64; 2]> for BivariateNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [f64; 2] {
+        let z1: f64 = rng.sample(StandardNormal);
+        let z2: f64 = rng.sample(StandardNormal);
+        [self.mean[0] + self.std_dev[0] * z1,
+         self.mean[1] + self.std_dev[1] * (self.rho * z1 + self.rho_c * z2)]
+    }
+}
+
+impl fmt::Display for BivariateNormal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BivariateNormal(mean=[{}, {}], std_dev=[{}, {}], rho={})",
+               self.mean[0], self.mean[1], self.std_dev[0], self.std_dev[1], self.rho)
+    }
+}
+
+/// The multivariate normal distribution `N(mean, cov)` with mean vector
+/// `mean` and covariance matrix `cov`.
+///
+/// The covariance matrix is factorized at construction as `cov = L L^T`
+/// (Cholesky decomposition), and samples are generated as `mean + L z`,
+/// where `z` is a vector of independent standard normal variables.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{MultivariateNormal, Distribution};
+///
+/// let mvn = MultivariateNormal::new(vec![0.0, 1.0],
+///                                   vec![vec![2.0, 0.5], vec![0.5, 1.0]]).unwrap();
+/// let v = mvn.sample(&mut rand::thread_rng());
+/// println!("{:?} is from a multivariate normal distribution", v);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    /// Lower triangular Cholesky factor of the covariance, stored row-major
+    /// as a full `d * d` matrix.
+    chol: Vec<f64>,
+}
+
+impl MultivariateNormal {
+    /// Construct a new `MultivariateNormal` distribution.
+    ///
+    /// `cov` is given as a list of rows. Returns an error if `mean` is empty,
+    /// if `cov` is not a square matrix of matching dimension, or if `cov` is
+    /// not symmetric positive-definite.
+    pub fn new(mean: Vec<f64>, cov: Vec<Vec<f64>>)
+        -> Result<MultivariateNormal, DistError>
+    {
+        let d = mean.len();
+        if d == 0 {
+            return Err(DistError::SizeTooSmall);
+        }
+        if cov.len() != d || cov.iter().any(|row| row.len() != d) {
+            return Err(DistError::DimensionMismatch);
+        }
+
+        let mut chol = vec![0.0; d * d];
+        for i in 0..d {
+            for j in 0..(i + 1) {
+                if cov[i][j] != cov[j][i] {
+                    return Err(DistError::NotPositiveDefinite);
+                }
+                let mut sum = cov[i][j];
+                for k in 0..j {
+                    sum -= chol[i * d + k] * chol[j * d + k];
+                }
+                if i == j {
+                    // Also rejects NaN.
+                    if !(sum > 0.0) {
+                        return Err(DistError::NotPositiveDefinite);
+                    }
+                    chol[i * d + i] = sum.sqrt();
+                } else {
+                    chol[i * d + j] = sum / chol[j * d + j];
+                }
+            }
+        }
+        Ok(MultivariateNormal { mean, chol })
+    }
+
+    /// Construct a new `MultivariateNormal`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are invalid.
+    pub fn new_unchecked(mean: Vec<f64>, cov: Vec<Vec<f64>>) -> MultivariateNormal {
+        MultivariateNormal::new(mean, cov)
+            .expect("MultivariateNormal::new_unchecked called with invalid parameters")
+    }
+
+    /// The dimension of the distribution.
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Write a sample into `out` instead of allocating a new vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len()` is not equal to the dimension.
+    pub fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [f64]) {
+        let d = self.dim();
+        assert_eq!(out.len(), d, "output length does not match dimension");
+        for x in out.iter_mut() {
+            *x = rng.sample(StandardNormal);
+        }
+        // Compute `mean + L z` in place: row `i` only reads entries `j <= i`,
+        // so working from the last row upwards never reads overwritten values.
+        for i in (0..d).rev() {
+            let row = &self.chol[i * d..i * d + i + 1];
+            let dot: f64 = row.iter().zip(out.iter()).map(|(l, z)| l * z).sum();
+            out[i] = self.mean[i] + dot;
+        }
+    }
+}
+
+impl Distribution<Vec<f64>> for MultivariateNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let mut out = vec![0.0; self.dim()];
+        self.sample_into(rng, &mut out);
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use distributions::Distribution;
-    use super::{Normal, LogNormal};
+    use distributions::{Distribution, DistError};
+    use super::{Normal, LogNormal, BivariateNormal, MultivariateNormal};
 
     #[test]
     fn test_normal() {
-        let norm = Normal::new(10.0, 10.0);
+        let norm = Normal::new(10.0, 10.0).unwrap();
         let mut rng = ::test::rng(210);
         for _ in 0..1000 {
             norm.sample(&mut rng);
         }
     }
     #[test]
-    #[should_panic]
+    fn test_normal_is_within_sigma() {
+        let norm = Normal::new(10.0, 2.0).unwrap();
+        assert!(norm.is_within_sigma(10.0, 0.0));
+        assert!(norm.is_within_sigma(6.0, 2.0));
+        assert!(norm.is_within_sigma(14.0, 2.0));
+        assert!(!norm.is_within_sigma(14.1, 2.0));
+        assert!(!norm.is_within_sigma(7.9, 1.0));
+        assert!(Normal::new(0.0f32, 1.0).unwrap().is_within_sigma(-0.5, 0.5));
+    }
+
+    #[test]
+    fn test_normal_moments() {
+        use distributions::Moments;
+        let normal = Normal::new(-2.0, 3.0).unwrap();
+        assert_eq!((normal.mean(), normal.variance()), (-2.0, 9.0));
+        let mut rng = ::test::rng(232);
+        let samples: Vec<f64> = normal.sample_iter(&mut rng).take(100_000).collect();
+        ::test::assert_moments(&samples, -2.0, 9.0, 0.02);
+    }
+
+    #[test]
+    fn test_normal_ln_pdf() {
+        use distributions::ContinuousDistribution;
+        let norm = Normal::new(10.0, 2.0).unwrap();
+        // 1 / sqrt(2 pi 4) at the mean, e^-0.5 times that one std_dev away.
+        assert!((norm.ln_pdf(10.0) - (-1.6120857137646178)).abs() < 1e-14);
+        assert!((norm.ln_pdf(8.0) - (-2.1120857137646178)).abs() < 1e-14);
+
+        let mut rng = ::test::rng(212);
+        for _ in 0..1000 {
+            let (x, ln_p) = norm.sample_with_ln_pdf(&mut rng);
+            assert!((ln_p - norm.ln_pdf(x)).abs() < 1e-12);
+        }
+
+        let constant = Normal::new(1.0, 0.0).unwrap();
+        assert_eq!(constant.sample_with_ln_pdf(&mut rng), (1.0, ::std::f64::INFINITY));
+        assert_eq!(constant.ln_pdf(2.0), ::std::f64::NEG_INFINITY);
+    }
+
+    #[test]
     fn test_normal_invalid_sd() {
-        Normal::new(10.0, -1.0);
+        assert_eq!(Normal::new(10.0, -1.0).unwrap_err(), DistError::StdDevNegative);
     }
 
+    #[test]
+    fn test_normal_f32_matches_f64() {
+        let n = 100_000;
+        let norm32 = Normal::new(2.0f32, 3.0).unwrap();
+        let norm64 = Normal::new(2.0f64, 3.0).unwrap();
+        let mut rng = ::test::rng(212);
+
+        let (mut sum32, mut sum_sq32) = (0.0f64, 0.0f64);
+        let (mut sum64, mut sum_sq64) = (0.0f64, 0.0f64);
+        for _ in 0..n {
+            let x: f32 = norm32.sample(&mut rng);
+            assert!(x.is_finite());
+            sum32 += x as f64;
+            sum_sq32 += (x * x) as f64;
+            let x: f64 = norm64.sample(&mut rng);
+            sum64 += x;
+            sum_sq64 += x * x;
+        }
+        let (mean32, mean64) = (sum32 / n as f64, sum64 / n as f64);
+        let var32 = sum_sq32 / n as f64 - mean32 * mean32;
+        let var64 = sum_sq64 / n as f64 - mean64 * mean64;
+        assert!((mean32 - 2.0).abs() < 0.05);
+        assert!((mean32 - mean64).abs() < 0.05);
+        assert!((var32 - 9.0).abs() < 0.2);
+        assert!((var32 - var64).abs() < 0.2);
+    }
 
     #[test]
     fn test_log_normal() {
-        let lnorm = LogNormal::new(10.0, 10.0);
+        let lnorm = LogNormal::new(10.0, 10.0).unwrap();
         let mut rng = ::test::rng(211);
         for _ in 0..1000 {
             lnorm.sample(&mut rng);
         }
     }
     #[test]
-    #[should_panic]
+    fn test_log_normal_moments() {
+        use distributions::Moments;
+        let lnorm = LogNormal::new(1.0, 0.5).unwrap();
+        assert!((lnorm.mean() - 1.125f64.exp()).abs() < 1e-12);
+        assert!((lnorm.variance() - 0.25f64.exp_m1() * 2.25f64.exp()).abs() < 1e-12);
+        let mut rng = ::test::rng(233);
+        let samples: Vec<f64> = lnorm.sample_iter(&mut rng).take(100_000).collect();
+        ::test::assert_moments(&samples, lnorm.mean(), lnorm.variance(), 0.05);
+    }
+
+    #[test]
     fn test_log_normal_invalid_sd() {
-        LogNormal::new(10.0, -1.0);
+        assert_eq!(LogNormal::new(10.0, -1.0).unwrap_err(), DistError::StdDevNegative);
+    }
+
+    #[test]
+    fn test_bivariate_normal() {
+        let mut rng = ::test::rng(213);
+        for &rho in [-0.9, 0.0, 0.5].iter() {
+            let bvn = BivariateNormal::new([1.0, -2.0], [2.0, 0.5], rho).unwrap();
+            let samples: Vec<[f64; 2]> = (0..100_000).map(|_| bvn.sample(&mut rng)).collect();
+            let xs: Vec<f64> = samples.iter().map(|s| s[0]).collect();
+            let ys: Vec<f64> = samples.iter().map(|s| s[1]).collect();
+            ::test::assert_moments(&xs, 1.0, 4.0, 0.03);
+            ::test::assert_moments(&ys, -2.0, 0.25, 0.03);
+
+            let n = samples.len() as f64;
+            let (mx, my) = (xs.iter().sum::<f64>() / n, ys.iter().sum::<f64>() / n);
+            let cov = samples.iter().map(|s| (s[0] - mx) * (s[1] - my)).sum::<f64>() / n;
+            let sx = (xs.iter().map(|x| (x - mx) * (x - mx)).sum::<f64>() / n).sqrt();
+            let sy = (ys.iter().map(|y| (y - my) * (y - my)).sum::<f64>() / n).sqrt();
+            assert!((cov / (sx * sy) - rho).abs() < 0.01);
+        }
+    }
+    #[test]
+    fn test_bivariate_normal_invalid() {
+        assert_eq!(BivariateNormal::new([0.0; 2], [0.0, 1.0], 0.0).unwrap_err(),
+                   DistError::StdDevNotPositive);
+        assert_eq!(BivariateNormal::new([0.0; 2], [1.0, -1.0], 0.0).unwrap_err(),
+                   DistError::StdDevNotPositive);
+        assert_eq!(BivariateNormal::new([0.0; 2], [1.0; 2], 1.0).unwrap_err(),
+                   DistError::CorrelationOutOfRange);
+        assert_eq!(BivariateNormal::new([0.0; 2], [1.0; 2], -1.5).unwrap_err(),
+                   DistError::CorrelationOutOfRange);
+    }
+    #[test]
+    fn test_multivariate_normal() {
+        let mut rng = ::test::rng(214);
+        let mean = vec![1.0, -1.0, 3.0];
+        let cov = vec![vec![4.0, 1.0, -0.5],
+                       vec![1.0, 2.0, 0.3],
+                       vec![-0.5, 0.3, 1.0]];
+        let mvn = MultivariateNormal::new(mean.clone(), cov.clone()).unwrap();
+        assert_eq!(mvn.dim(), 3);
+
+        let n = 100_000;
+        let mut samples = vec![0.0; 3 * n];
+        for chunk in samples.chunks_mut(3) {
+            mvn.sample_into(&mut rng, chunk);
+        }
+        let mut emp_mean = [0.0; 3];
+        for chunk in samples.chunks(3) {
+            for i in 0..3 { emp_mean[i] += chunk[i] / n as f64; }
+        }
+        for i in 0..3 {
+            assert!((emp_mean[i] - mean[i]).abs() < 0.03);
+        }
+        for i in 0..3 {
+            for j in 0..3 {
+                let c = samples.chunks(3)
+                    .map(|s| (s[i] - emp_mean[i]) * (s[j] - emp_mean[j]))
+                    .sum::<f64>() / n as f64;
+                assert!((c - cov[i][j]).abs() < 0.05);
+            }
+        }
+
+        assert_eq!(mvn.sample(&mut rng).len(), 3);
+    }
+    #[test]
+    fn test_multivariate_normal_invalid() {
+        assert_eq!(MultivariateNormal::new(vec![], vec![]).unwrap_err(),
+                   DistError::SizeTooSmall);
+        assert_eq!(MultivariateNormal::new(vec![0.0; 2], vec![vec![1.0]]).unwrap_err(),
+                   DistError::DimensionMismatch);
+        assert_eq!(MultivariateNormal::new(vec![0.0; 2],
+                   vec![vec![1.0, 0.0], vec![0.0]]).unwrap_err(),
+                   DistError::DimensionMismatch);
+        assert_eq!(MultivariateNormal::new(vec![0.0; 2],
+                   vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap_err(),
+                   DistError::NotPositiveDefinite);
+        assert_eq!(MultivariateNormal::new(vec![0.0; 2],
+                   vec![vec![1.0, 0.5], vec![0.2, 1.0]]).unwrap_err(),
+                   DistError::NotPositiveDefinite);
+    }
+    #[test]
+    #[should_panic]
+    fn test_multivariate_normal_sample_into_wrong_len() {
+        let mvn = MultivariateNormal::new_unchecked(vec![0.0; 2],
+            vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+        mvn.sample_into(&mut ::test::rng(215), &mut [0.0; 3]);
     }
 }
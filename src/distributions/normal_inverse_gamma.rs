@@ -0,0 +1,122 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The normal-inverse-gamma distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, InverseGamma, StandardNormal};
+use std::fmt;
+
+/// The normal-inverse-gamma distribution `NormalInverseGamma(mu, lambda,
+/// alpha, beta)`.
+///
+/// This is the conjugate prior of a normal distribution with unknown mean
+/// and variance. Each sample is a pair `(mean, variance)`, where the variance
+/// `σ²` has the [`InverseGamma`] distribution with shape `alpha` and scale
+/// `beta`, and given it the mean is normally distributed with mean `mu` and
+/// variance `σ² / lambda`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{NormalInverseGamma, Normal, Distribution};
+///
+/// let prior = NormalInverseGamma::new(0.0, 1.0, 3.0, 2.0).unwrap();
+/// let (mean, variance) = prior.sample(&mut rand::thread_rng());
+/// let model = Normal::new(mean, variance.sqrt()).unwrap();
+/// println!("{} is from a model drawn from the prior", model.sample(&mut rand::thread_rng()));
+/// ```
+///
+/// [`InverseGamma`]: struct.InverseGamma.html
+#[derive(Clone, Copy, Debug)]
+pub struct NormalInverseGamma {
+    mu: f64,
+    lambda: f64,
+    alpha: f64,
+    beta: f64,
+    variance: InverseGamma,
+}
+
+impl NormalInverseGamma {
+    /// Construct a new `NormalInverseGamma` with location `mu`, precision
+    /// scaling `lambda`, shape `alpha` and scale `beta`.
+    ///
+    /// Returns an error if `lambda`, `alpha` or `beta` is not positive.
+    pub fn new(mu: f64, lambda: f64, alpha: f64, beta: f64)
+        -> Result<NormalInverseGamma, DistError>
+    {
+        if !(lambda > 0.0) {
+            return Err(DistError::LambdaNotPositive);
+        }
+        if !(alpha > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if !(beta > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        Ok(NormalInverseGamma {
+            mu, lambda, alpha, beta,
+            variance: InverseGamma::new(alpha, beta)?,
+        })
+    }
+
+    /// Construct a new `NormalInverseGamma`, like [`new`](#method.new).
+    ///
+    /// Panics if `lambda`, `alpha` or `beta` is not positive.
+    pub fn new_unchecked(mu: f64, lambda: f64, alpha: f64, beta: f64) -> NormalInverseGamma {
+        NormalInverseGamma::new(mu, lambda, alpha, beta)
+            .expect("NormalInverseGamma::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<(f64, f64)> for NormalInverseGamma {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let variance = self.variance.sample(rng);
+        let n: f64 = rng.sample(StandardNormal);
+        (self.mu + n * (variance / self.lambda).sqrt(), variance)
+    }
+}
+
+impl fmt::Display for NormalInverseGamma {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NormalInverseGamma(mu={}, lambda={}, alpha={}, beta={})",
+               self.mu, self.lambda, self.alpha, self.beta)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::NormalInverseGamma;
+
+    #[test]
+    fn test_normal_inverse_gamma_marginals() {
+        let mut rng = ::test::rng(186);
+        let (mu, lambda, alpha, beta) = (3.0, 4.0, 10.0, 18.0);
+        let distr = NormalInverseGamma::new(mu, lambda, alpha, beta).unwrap();
+        let (means, variances): (Vec<f64>, Vec<f64>) =
+            (0..100_000).map(|_| distr.sample(&mut rng)).unzip();
+        assert!(variances.iter().all(|&v| v > 0.0));
+        // The inverse-gamma moments.
+        let var_mean = beta / (alpha - 1.0);
+        let var_var = var_mean * var_mean / (alpha - 2.0);
+        ::test::assert_moments(&variances, var_mean, var_var, 0.03);
+        // The mean component is a scaled Student's t variate.
+        ::test::assert_moments(&means, mu, var_mean / lambda, 0.03);
+    }
+
+    #[test]
+    fn test_normal_inverse_gamma_invalid() {
+        assert_eq!(NormalInverseGamma::new(0.0, 0.0, 1.0, 1.0).unwrap_err(),
+                   DistError::LambdaNotPositive);
+        assert_eq!(NormalInverseGamma::new(0.0, 1.0, -1.0, 1.0).unwrap_err(),
+                   DistError::ShapeNotPositive);
+        assert_eq!(NormalInverseGamma::new(0.0, 1.0, 1.0, ::std::f64::NAN).unwrap_err(),
+                   DistError::ScaleNotPositive);
+    }
+}
@@ -9,7 +9,8 @@
 //! The Pareto distribution.
 
 use Rng;
-use distributions::{Distribution, OpenClosed01};
+use distributions::{Distribution, InverseCdf, DistError, Open01};
+use std::fmt;
 
 /// Samples floating-point numbers according to the Pareto distribution
 ///
@@ -18,12 +19,13 @@ use distributions::{Distribution, OpenClosed01};
 /// use rand::prelude::*;
 /// use rand::distributions::Pareto;
 ///
-/// let val: f64 = SmallRng::from_entropy().sample(Pareto::new(1., 2.));
+/// let val: f64 = SmallRng::from_entropy().sample(Pareto::new(1., 2.).unwrap());
 /// println!("{}", val);
 /// ```
 #[derive(Clone, Copy, Debug)]
 pub struct Pareto {
     scale: f64,
+    shape: f64,
     inv_neg_shape: f64,
 }
 
@@ -33,42 +35,207 @@ impl Pareto {
     /// In the literature, `scale` is commonly written as x<sub>m</sub> or k and
     /// `shape` is often written as α.
     ///
+    /// # Errors
+    ///
+    /// `scale` and `shape` have to be non-zero and positive.
+    pub fn new(scale: f64, shape: f64) -> Result<Pareto, DistError> {
+        if !(scale > 0.) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        if !(shape > 0.) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        Ok(Pareto { scale, shape, inv_neg_shape: -1.0 / shape })
+    }
+
+    /// Construct a new Pareto distribution, like [`new`](#method.new).
+    ///
     /// # Panics
     ///
     /// `scale` and `shape` have to be non-zero and positive.
-    pub fn new(scale: f64, shape: f64) -> Pareto {
-        assert!((scale > 0.) & (shape > 0.));
-        Pareto { scale, inv_neg_shape: -1.0 / shape }
+    pub fn new_unchecked(scale: f64, shape: f64) -> Pareto {
+        Pareto::new(scale, shape).expect("Pareto::new_unchecked called with invalid parameters")
     }
 }
 
 impl Distribution<f64> for Pareto {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        let u: f64 = rng.sample(OpenClosed01);
-        self.scale * u.powf(self.inv_neg_shape)
+        self.inverse_cdf(rng.sample(Open01))
+    }
+}
+
+impl InverseCdf for Pareto {
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.scale * (1.0 - p).powf(self.inv_neg_shape)
+    }
+}
+
+impl fmt::Display for Pareto {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pareto(scale={}, shape={})", self.scale, self.shape)
+    }
+}
+
+/// Samples floating-point numbers according to the Pareto distribution
+/// truncated at an upper bound, in `[scale, upper]`.
+///
+/// Samples are generated by inverting the CDF of the truncated distribution,
+/// `F(x) = (1 - (scale / x)^shape) / (1 - (scale / upper)^shape)`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand::distributions::BoundedPareto;
+///
+/// let file_size = BoundedPareto::new(1e3, 1.2, 1e9).unwrap();
+/// let val: f64 = SmallRng::from_entropy().sample(file_size);
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BoundedPareto {
+    scale: f64,
+    shape: f64,
+    upper: f64,
+    inv_neg_shape: f64,
+    /// `(scale / upper)^shape`, the probability of the plain Pareto
+    /// distribution to exceed `upper`.
+    tail: f64,
+}
+
+impl BoundedPareto {
+    /// Construct a new bounded Pareto distribution with given `scale`,
+    /// `shape` and `upper` bound.
+    ///
+    /// # Errors
+    ///
+    /// `scale` and `shape` have to be non-zero and positive, and `scale` has
+    /// to be less than `upper`.
+    pub fn new(scale: f64, shape: f64, upper: f64) -> Result<BoundedPareto, DistError> {
+        if !(scale > 0.) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        if !(shape > 0.) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        if !(scale < upper) {
+            return Err(DistError::InvalidRange);
+        }
+        Ok(BoundedPareto {
+            scale,
+            shape,
+            upper,
+            inv_neg_shape: -1.0 / shape,
+            tail: (scale / upper).powf(shape),
+        })
+    }
+
+    /// Construct a new bounded Pareto distribution, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// `scale` and `shape` have to be non-zero and positive, and `scale` has
+    /// to be less than `upper`.
+    pub fn new_unchecked(scale: f64, shape: f64, upper: f64) -> BoundedPareto {
+        BoundedPareto::new(scale, shape, upper)
+            .expect("BoundedPareto::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for BoundedPareto {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.inverse_cdf(rng.sample(Open01))
+    }
+}
+
+impl InverseCdf for BoundedPareto {
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        // Same as `Pareto`, with `p` mapped from `[0, 1]` to `[0, 1 - tail]`.
+        let v = 1.0 - p * (1.0 - self.tail);
+        (self.scale * v.powf(self.inv_neg_shape)).min(self.upper)
+    }
+}
+
+impl fmt::Display for BoundedPareto {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BoundedPareto(scale={}, shape={}, upper={})",
+               self.scale, self.shape, self.upper)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use distributions::Distribution;
-    use super::Pareto;
+    use distributions::{Distribution, DistError};
+    use super::{Pareto, BoundedPareto};
 
     #[test]
-    #[should_panic]
     fn invalid() {
-        Pareto::new(0., 0.);
+        assert_eq!(Pareto::new(0., 1.).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Pareto::new(1., 0.).unwrap_err(), DistError::ShapeNotPositive);
+    }
+
+    #[test]
+    fn bounded_invalid() {
+        assert_eq!(BoundedPareto::new(0., 1., 2.).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(BoundedPareto::new(1., 0., 2.).unwrap_err(), DistError::ShapeNotPositive);
+        assert_eq!(BoundedPareto::new(2., 1., 2.).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(BoundedPareto::new(3., 1., 2.).unwrap_err(), DistError::InvalidRange);
+    }
+
+    #[test]
+    fn bounded_sample() {
+        let d = BoundedPareto::new(1.0, 0.5, 10.0).unwrap();
+        let mut rng = ::test::rng(173);
+        let n = 100_000;
+        let mut below_sqrt_10 = 0;
+        for _ in 0..n {
+            let r = d.sample(&mut rng);
+            assert!(r >= 1.0 && r <= 10.0);
+            if r < 10f64.sqrt() { below_sqrt_10 += 1; }
+        }
+        // `F(sqrt(10)) = (1 - 10^-0.25) / (1 - 10^-0.5)`.
+        let expected = (1.0 - 10f64.powf(-0.25)) / (1.0 - 10f64.powf(-0.5));
+        assert!((below_sqrt_10 as f64 / n as f64 - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn bounded_without_bound() {
+        // With an upper bound too large to matter, the plain Pareto
+        // distribution is recovered exactly.
+        let bounded = BoundedPareto::new(2.0, 3.0, 1e300).unwrap();
+        let plain = Pareto::new(2.0, 3.0).unwrap();
+        let mut rng1 = ::test::rng(174);
+        let mut rng2 = ::test::rng(174);
+        for _ in 0..1000 {
+            assert_eq!(bounded.sample(&mut rng1), plain.sample(&mut rng2));
+        }
     }
 
     #[test]
     fn sample() {
         let scale = 1.0;
         let shape = 2.0;
-        let d = Pareto::new(scale, shape);
+        let d = Pareto::new(scale, shape).unwrap();
         let mut rng = ::test::rng(1);
         for _ in 0..1000 {
             let r = d.sample(&mut rng);
             assert!(r >= scale);
         }
     }
+
+    #[test]
+    fn test_pareto_inverse_cdf() {
+        use distributions::InverseCdf;
+        let d = Pareto::new(2.0, 5.0).unwrap();
+        assert_eq!(d.inverse_cdf(0.0), 2.0);
+        assert!((d.inverse_cdf(0.5) - 2.0 * 2f64.powf(0.2)).abs() < 1e-12);
+
+        let mut rng = ::test::rng(197);
+        let samples: Vec<f64> = (0..100_000).map(|_| d.sample(&mut rng)).collect();
+        // Mean `2 * 5/4` and variance `2^2 * 5 / (4^2 * 3)`.
+        ::test::assert_moments(&samples, 2.5, 5.0 / 12.0, 0.05);
+
+        let bounded = BoundedPareto::new(2.0, 5.0, 3.0).unwrap();
+        assert_eq!(bounded.inverse_cdf(0.0), 2.0);
+        assert!((bounded.inverse_cdf(1.0) - 3.0).abs() < 1e-12);
+    }
 }
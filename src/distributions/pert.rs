@@ -0,0 +1,127 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//! The PERT distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Beta};
+use std::fmt;
+
+/// The PERT distribution.
+///
+/// This is a [`Beta`] distribution re-scaled to `[min, max]`, with parameters
+/// chosen such that the mode is `mode` and the mean is
+/// `(min + shape * mode + max) / (shape + 2)`. It is a smoother alternative to
+/// the [`Triangular`] distribution, often used for project estimates. The
+/// conventional choice of `shape` is `4`.
+///
+/// # Example
+///
+/// ```rust
+/// use rand::distributions::{Pert, Distribution};
+///
+/// let d = Pert::new(0., 1., 5., 4.).unwrap();
+/// let v = d.sample(&mut rand::thread_rng());
+/// println!("{} is from a PERT distribution", v);
+/// ```
+///
+/// [`Beta`]: struct.Beta.html
+/// [`Triangular`]: struct.Triangular.html
+#[derive(Clone, Copy, Debug)]
+pub struct Pert {
+    min: f64,
+    mode: f64,
+    max: f64,
+    shape: f64,
+    beta: Beta,
+}
+
+impl Pert {
+    /// Construct a new `Pert` with minimum `min`, mode `mode`, maximum `max`
+    /// and shape parameter `shape`.
+    ///
+    /// # Errors
+    ///
+    /// If `max <= min`, `mode` is not in the range `[min, max]`, or
+    /// `shape <= 0`.
+    ///
+    pub fn new(min: f64, mode: f64, max: f64, shape: f64) -> Result<Pert, DistError> {
+        if !(max > min) {
+            return Err(DistError::InvalidRange);
+        }
+        if !(max >= mode && mode >= min) {
+            return Err(DistError::ModeOutOfRange);
+        }
+        if !(shape > 0.0) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        let range = max - min;
+        let alpha = 1.0 + shape * (mode - min) / range;
+        let beta = 1.0 + shape * (max - mode) / range;
+        Ok(Pert { min, mode, max, shape, beta: Beta::new(alpha, beta)? })
+    }
+
+    /// Construct a new `Pert`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// If `max <= min`, `mode` is not in the range `[min, max]`, or
+    /// `shape <= 0`.
+    ///
+    pub fn new_unchecked(min: f64, mode: f64, max: f64, shape: f64) -> Pert {
+        Pert::new(min, mode, max, shape)
+            .expect("Pert::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for Pert {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.min + self.beta.sample(rng) * (self.max - self.min)
+    }
+}
+
+impl fmt::Display for Pert {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pert(min={}, mode={}, max={}, shape={})",
+               self.min, self.mode, self.max, self.shape)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::Pert;
+
+    #[test]
+    fn test_new_invalid() {
+        assert_eq!(Pert::new(1., 1., 1., 4.).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(Pert::new(2., 1.5, 1., 4.).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(Pert::new(0., 2., 1., 4.).unwrap_err(), DistError::ModeOutOfRange);
+        assert_eq!(Pert::new(0., -1., 1., 4.).unwrap_err(), DistError::ModeOutOfRange);
+        assert_eq!(Pert::new(0., 0.5, 1., 0.).unwrap_err(), DistError::ShapeNotPositive);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = ::test::rng(147);
+        for &(min, mode, max, shape) in &[
+            (0., 1., 5., 4.), (-2., -2., 3., 4.), (10., 20., 20., 4.), (0., 0.3, 1., 10.),
+        ] {
+            let d = Pert::new(min, mode, max, shape).unwrap();
+            let n = 20_000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let x = d.sample(&mut rng);
+                assert!(min <= x && x <= max);
+                sum += x;
+            }
+            let expected = (min + shape * mode + max) / (shape + 2.);
+            assert!((sum / n as f64 - expected).abs() < 0.01 * (max - min));
+        }
+    }
+}
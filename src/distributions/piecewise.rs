@@ -0,0 +1,153 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A continuous distribution with a piecewise-linear CDF.
+
+use Rng;
+use distributions::{Distribution, DistError};
+#[cfg(not(feature="std"))] use alloc::vec::Vec;
+
+/// A continuous distribution given by control points `(x, F(x))` of its
+/// cumulative distribution function, which is interpolated linearly between
+/// the points.
+///
+/// Between two consecutive points the density is constant, so this is the
+/// distribution of a histogram with bins `[xs[i], xs[i + 1])` of probability
+/// `cdf[i + 1] - cdf[i]`. It is useful to sample from an empirical
+/// distribution.
+///
+/// Sampling draws a uniform `u`, finds its segment of the CDF by binary
+/// search, and interpolates the inverse linearly, in `O(log n)` time.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{PiecewiseLinearCdf, Distribution};
+///
+/// // Half of the mass in [0, 1), the other half spread over [1, 5)
+/// let distr = PiecewiseLinearCdf::new(&[0.0, 1.0, 5.0], &[0.0, 0.5, 1.0]).unwrap();
+/// let v = distr.sample(&mut rand::thread_rng());
+/// assert!(v >= 0.0 && v < 5.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PiecewiseLinearCdf {
+    xs: Vec<f64>,
+    cdf: Vec<f64>,
+}
+
+impl PiecewiseLinearCdf {
+    /// Construct a new `PiecewiseLinearCdf` with the CDF taking the value
+    /// `cdf[i]` at `xs[i]`.
+    ///
+    /// # Errors
+    /// - if `xs` and `cdf` have different lengths
+    /// - if there are fewer than two points
+    /// - if `xs` is not strictly increasing and finite
+    /// - if `cdf` is not non-decreasing from `cdf[0] == 0` to exactly `1`
+    ///
+    pub fn new(xs: &[f64], cdf: &[f64]) -> Result<PiecewiseLinearCdf, DistError> {
+        if xs.len() != cdf.len() {
+            return Err(DistError::DimensionMismatch);
+        }
+        if xs.len() < 2 {
+            return Err(DistError::SizeTooSmall);
+        }
+        if xs.iter().any(|x| !x.is_finite()) || xs.windows(2).any(|w| !(w[0] < w[1])) {
+            return Err(DistError::InvalidRange);
+        }
+        if cdf[0] != 0.0 || cdf[cdf.len() - 1] != 1.0
+            || cdf.windows(2).any(|w| !(w[0] <= w[1]))
+        {
+            return Err(DistError::ProbabilityOutOfRange);
+        }
+        Ok(PiecewiseLinearCdf { xs: xs.to_vec(), cdf: cdf.to_vec() })
+    }
+
+    /// Construct a new `PiecewiseLinearCdf`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    /// If the control points are invalid.
+    pub fn new_unchecked(xs: &[f64], cdf: &[f64]) -> PiecewiseLinearCdf {
+        PiecewiseLinearCdf::new(xs, cdf)
+            .expect("PiecewiseLinearCdf::new_unchecked called with invalid control points")
+    }
+}
+
+impl Distribution<f64> for PiecewiseLinearCdf {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.gen();
+        // Invariant: `cdf[lo] <= u < cdf[hi]`, which holds initially since
+        // `u` is in `[0, 1)`. Flat segments can thus never be selected.
+        let (mut lo, mut hi) = (0, self.cdf.len() - 1);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if self.cdf[mid] <= u {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let t = (u - self.cdf[lo]) / (self.cdf[hi] - self.cdf[lo]);
+        self.xs[lo] + t * (self.xs[hi] - self.xs[lo])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::PiecewiseLinearCdf;
+
+    #[test]
+    fn test_piecewise_linear_uniform() {
+        // A linear CDF, split into uneven segments, is uniform over the range.
+        let distr = PiecewiseLinearCdf::new(&[-2.0, -1.5, 0.0, 2.0],
+                                            &[0.0, 0.125, 0.5, 1.0]).unwrap();
+        let mut rng = ::test::rng(401);
+        let n = 40_000;
+        let mut counts = [0u32; 8];
+        for _ in 0..n {
+            let x = distr.sample(&mut rng);
+            assert!(x >= -2.0 && x < 2.0);
+            counts[((x + 2.0) * 2.0) as usize] += 1;
+        }
+        for &c in counts.iter() {
+            assert!(c > 4_700 && c < 5_300);
+        }
+    }
+
+    #[test]
+    fn test_piecewise_linear_flat_segment() {
+        // No mass in [1, 2).
+        let distr = PiecewiseLinearCdf::new(&[0.0, 1.0, 2.0, 3.0],
+                                            &[0.0, 0.5, 0.5, 1.0]).unwrap();
+        let mut rng = ::test::rng(402);
+        let mut below = 0;
+        for _ in 0..10_000 {
+            let x = distr.sample(&mut rng);
+            assert!(x < 1.0 || x >= 2.0);
+            if x < 1.0 { below += 1; }
+        }
+        assert!(below > 4_800 && below < 5_200);
+    }
+
+    #[test]
+    fn test_piecewise_linear_invalid() {
+        assert_eq!(PiecewiseLinearCdf::new(&[0.0, 1.0], &[0.0]).unwrap_err(),
+                   DistError::DimensionMismatch);
+        assert_eq!(PiecewiseLinearCdf::new(&[0.0], &[1.0]).unwrap_err(),
+                   DistError::SizeTooSmall);
+        assert_eq!(PiecewiseLinearCdf::new(&[0.0, 0.0, 1.0], &[0.0, 0.5, 1.0]).unwrap_err(),
+                   DistError::InvalidRange);
+        assert_eq!(PiecewiseLinearCdf::new(&[0.0, 1.0, 2.0], &[0.0, 0.6, 0.5]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(PiecewiseLinearCdf::new(&[0.0, 1.0], &[0.1, 1.0]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+        assert_eq!(PiecewiseLinearCdf::new(&[0.0, 1.0], &[0.0, 0.9]).unwrap_err(),
+                   DistError::ProbabilityOutOfRange);
+    }
+}
@@ -10,8 +10,9 @@
 //! The Poisson distribution.
 
 use Rng;
-use distributions::{Distribution, Cauchy};
+use distributions::{Distribution, DistError, Cauchy, Moments};
 use distributions::utils::log_gamma;
+use std::fmt;
 
 /// The Poisson distribution `Poisson(lambda)`.
 ///
@@ -23,7 +24,7 @@ use distributions::utils::log_gamma;
 /// ```
 /// use rand::distributions::{Poisson, Distribution};
 ///
-/// let poi = Poisson::new(2.0);
+/// let poi = Poisson::new(2.0).unwrap();
 /// let v = poi.sample(&mut rand::thread_rng());
 /// println!("{} is from a Poisson(2) distribution", v);
 /// ```
@@ -35,90 +36,259 @@ pub struct Poisson {
     log_lambda: f64,
     sqrt_2lambda: f64,
     magic_val: f64,
+    truncated: Option<Truncated>,
+}
+
+/// Precalculated values for a Poisson distribution truncated to `0..=max`.
+#[derive(Clone, Copy, Debug)]
+struct Truncated {
+    max: u64,
+    /// Logarithm of the largest unnormalized probability in `0..=max`, which
+    /// is subtracted to keep the weights in range.
+    ln_mode: f64,
+    /// Sum of the weights over `0..=last`.
+    total: f64,
+    /// The largest `k` included in `total`: `max`, unless the tail terms
+    /// beyond it are negligible.
+    last: u64,
 }
 
 impl Poisson {
     /// Construct a new `Poisson` with the given shape parameter
-    /// `lambda`. Panics if `lambda <= 0`.
-    pub fn new(lambda: f64) -> Poisson {
-        assert!(lambda > 0.0, "Poisson::new called with lambda <= 0");
+    /// `lambda`. Returns an error if `lambda <= 0`.
+    pub fn new(lambda: f64) -> Result<Poisson, DistError> {
+        if !(lambda > 0.0) {
+            return Err(DistError::LambdaNotPositive);
+        }
         let log_lambda = lambda.ln();
-        Poisson {
+        Ok(Poisson {
             lambda,
             exp_lambda: (-lambda).exp(),
             log_lambda,
             sqrt_2lambda: (2.0 * lambda).sqrt(),
             magic_val: lambda * log_lambda - log_gamma(1.0 + lambda),
+            truncated: None,
+        })
+    }
+
+    /// Construct a new `Poisson` with the given shape parameter `lambda`,
+    /// conditioned on the result being at most `max`.
+    ///
+    /// The probability of each `k` in `0..=max` is proportional to that of
+    /// `Poisson(lambda)`. Samples are generated by inverse transform over the
+    /// truncated support rather than by rejecting results above `max`, so
+    /// sampling is efficient even when `max` is far below `lambda`. Each
+    /// sample takes `O(min(max, lambda))` time.
+    ///
+    /// Returns an error if `lambda <= 0`.
+    pub fn new_truncated(lambda: f64, max: u64) -> Result<Poisson, DistError> {
+        let mut poisson = Poisson::new(lambda)?;
+        let mode = (lambda.floor() as u64).min(max);
+        let mut truncated = Truncated { max, ln_mode: 0.0, total: 0.0, last: 0 };
+        truncated.ln_mode = poisson.ln_weight(mode, &truncated);
+        // Loop over `0..=max` without computing `max + 1`, which may overflow.
+        let mut k = 0;
+        loop {
+            let w = poisson.weight(k, &truncated);
+            truncated.total += w;
+            if k == max || (k > mode && w < truncated.total * ::std::f64::EPSILON) {
+                break;
+            }
+            k += 1;
+        }
+        truncated.last = k;
+        poisson.truncated = Some(truncated);
+        Ok(poisson)
+    }
+
+    /// Construct a new truncated `Poisson`, like
+    /// [`new_truncated`](#method.new_truncated).
+    ///
+    /// Panics if `lambda <= 0`.
+    pub fn new_truncated_unchecked(lambda: f64, max: u64) -> Poisson {
+        Poisson::new_truncated(lambda, max)
+            .expect("Poisson::new_truncated_unchecked called with lambda <= 0")
+    }
+
+    /// `ln(lambda^k / k!)`, relative to the mode of the truncated support.
+    fn ln_weight(&self, k: u64, t: &Truncated) -> f64 {
+        let k = k as f64;
+        k * self.log_lambda - log_gamma(k + 1.0) - t.ln_mode
+    }
+
+    fn weight(&self, k: u64, t: &Truncated) -> f64 {
+        self.ln_weight(k, t).exp()
+    }
+
+    /// The mean and variance over the truncated support, summed over the
+    /// same weights as `total`.
+    fn truncated_moments(&self, t: &Truncated) -> (f64, f64) {
+        let (mut m1, mut m2) = (0.0, 0.0);
+        for k in 0..t.last {
+            let w = self.weight(k, t);
+            m1 += k as f64 * w;
+            m2 += (k as f64) * (k as f64) * w;
+        }
+        let (last, w) = (t.last as f64, self.weight(t.last, t));
+        m1 += last * w;
+        m2 += last * last * w;
+        let mean = m1 / t.total;
+        (mean, (m2 / t.total - mean * mean).max(0.0))
+    }
+
+    fn sample_truncated<R: Rng + ?Sized>(&self, rng: &mut R, t: &Truncated) -> u64 {
+        let u = rng.gen::<f64>() * t.total;
+        let mut cumulative = 0.0;
+        for k in 0..t.last {
+            cumulative += self.weight(k, t);
+            if cumulative > u {
+                return k;
+            }
         }
+        // Also reached if rounding leaves the sum of the weights below `u`.
+        t.last
+    }
+
+    /// Construct a new `Poisson`, like [`new`](#method.new).
+    ///
+    /// Panics if `lambda <= 0`.
+    pub fn new_unchecked(lambda: f64) -> Poisson {
+        Poisson::new(lambda).expect("Poisson::new_unchecked called with lambda <= 0")
     }
 }
 
 impl Distribution<u64> for Poisson {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        if let Some(ref t) = self.truncated {
+            return self.sample_truncated(rng, t);
+        }
+
         // using the algorithm from Numerical Recipes in C
 
         // for low expected values use the Knuth method
         if self.lambda < 12.0 {
-            let mut result = 0;
-            let mut p = 1.0;
-            while p > self.exp_lambda {
-                p *= rng.gen::<f64>();
-                result += 1;
-            }
-            result - 1
+            self.sample_knuth(rng)
         }
         // high expected values - rejection method
         else {
-            let mut int_result: u64;
+            self.sample_rejection(rng, &Cauchy::new_unchecked(0.0, 1.0))
+        }
+    }
+}
+
+impl Poisson {
+    /// Fill `out` with samples.
+    ///
+    /// This is equivalent to sampling each element in turn, but the choice
+    /// of sampling method and the setup of the comparison distribution used
+    /// for large `lambda` are done once for the whole buffer. With the same
+    /// `rng` the output is identical to that of repeated calls to
+    /// [`sample`](trait.Distribution.html#tymethod.sample).
+    pub fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u64]) {
+        if let Some(ref t) = self.truncated {
+            for x in out.iter_mut() {
+                *x = self.sample_truncated(rng, t);
+            }
+        } else if self.lambda < 12.0 {
+            for x in out.iter_mut() {
+                *x = self.sample_knuth(rng);
+            }
+        } else {
+            let cauchy = Cauchy::new_unchecked(0.0, 1.0);
+            for x in out.iter_mut() {
+                *x = self.sample_rejection(rng, &cauchy);
+            }
+        }
+    }
+
+    fn sample_knuth<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let mut result = 0;
+        let mut p = 1.0;
+        while p > self.exp_lambda {
+            p *= rng.gen::<f64>();
+            result += 1;
+        }
+        result - 1
+    }
+
+    /// Rejection sampling with `cauchy`, the standard Cauchy distribution,
+    /// as the comparison distribution.
+    fn sample_rejection<R: Rng + ?Sized>(&self, rng: &mut R, cauchy: &Cauchy) -> u64 {
+        let mut int_result: u64;
 
-            // we use the Cauchy distribution as the comparison distribution
-            // f(x) ~ 1/(1+x^2)
-            let cauchy = Cauchy::new(0.0, 1.0);
+        // we use the Cauchy distribution as the comparison distribution
+        // f(x) ~ 1/(1+x^2)
+        loop {
+            let mut result;
+            let mut comp_dev;
 
             loop {
-                let mut result;
-                let mut comp_dev;
-
-                loop {
-                    // draw from the Cauchy distribution
-                    comp_dev = rng.sample(cauchy);
-                    // shift the peak of the comparison ditribution
-                    result = self.sqrt_2lambda * comp_dev + self.lambda;
-                    // repeat the drawing until we are in the range of possible values
-                    if result >= 0.0 {
-                        break;
-                    }
-                }
-                // now the result is a random variable greater than 0 with Cauchy distribution
-                // the result should be an integer value
-                result = result.floor();
-                int_result = result as u64;
-
-                // this is the ratio of the Poisson distribution to the comparison distribution
-                // the magic value scales the distribution function to a range of approximately 0-1
-                // since it is not exact, we multiply the ratio by 0.9 to avoid ratios greater than 1
-                // this doesn't change the resulting distribution, only increases the rate of failed drawings
-                let check = 0.9 * (1.0 + comp_dev * comp_dev)
-                    * (result * self.log_lambda - log_gamma(1.0 + result) - self.magic_val).exp();
-
-                // check with uniform random value - if below the threshold, we are within the target distribution
-                if rng.gen::<f64>() <= check {
+                // draw from the Cauchy distribution
+                comp_dev = rng.sample(cauchy);
+                // shift the peak of the comparison ditribution
+                result = self.sqrt_2lambda * comp_dev + self.lambda;
+                // repeat the drawing until we are in the range of possible values
+                if result >= 0.0 {
                     break;
                 }
             }
-            int_result
+            // now the result is a random variable greater than 0 with Cauchy distribution
+            // the result should be an integer value
+            result = result.floor();
+            int_result = result as u64;
+
+            // this is the ratio of the Poisson distribution to the comparison distribution
+            // the magic value scales the distribution function to a range of approximately 0-1
+            // since it is not exact, we multiply the ratio by 0.9 to avoid ratios greater than 1
+            // this doesn't change the resulting distribution, only increases the rate of failed drawings
+            let check = 0.9 * (1.0 + comp_dev * comp_dev)
+                * (result * self.log_lambda - log_gamma(1.0 + result) - self.magic_val).exp();
+
+            // check with uniform random value - if below the threshold, we are within the target distribution
+            if rng.gen::<f64>() <= check {
+                break;
+            }
+        }
+        int_result
+    }
+}
+
+impl Moments for Poisson {
+    /// The mean `lambda`. For a truncated `Poisson` this is summed over the
+    /// support in `O(min(max, lambda))` time.
+    fn mean(&self) -> f64 {
+        match self.truncated {
+            None => self.lambda,
+            Some(ref t) => self.truncated_moments(t).0,
+        }
+    }
+
+    /// The variance `lambda`, or summed like the mean if truncated.
+    fn variance(&self) -> f64 {
+        match self.truncated {
+            None => self.lambda,
+            Some(ref t) => self.truncated_moments(t).1,
+        }
+    }
+}
+
+impl fmt::Display for Poisson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.truncated {
+            None => write!(f, "Poisson(lambda={})", self.lambda),
+            Some(ref t) => write!(f, "Poisson(lambda={}, max={})", self.lambda, t.max),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use distributions::Distribution;
+    use distributions::{Distribution, DistError};
     use super::Poisson;
 
     #[test]
     fn test_poisson_10() {
-        let poisson = Poisson::new(10.0);
+        let poisson = Poisson::new(10.0).unwrap();
         let mut rng = ::test::rng(123);
         let mut sum = 0;
         for _ in 0..1000 {
@@ -132,7 +302,7 @@ mod test {
     #[test]
     fn test_poisson_15() {
         // Take the 'high expected values' path
-        let poisson = Poisson::new(15.0);
+        let poisson = Poisson::new(15.0).unwrap();
         let mut rng = ::test::rng(123);
         let mut sum = 0;
         for _ in 0..1000 {
@@ -144,14 +314,103 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
-    fn test_poisson_invalid_lambda_zero() {
-        Poisson::new(0.0);
+    fn test_poisson_truncated() {
+        use distributions::utils::log_gamma;
+
+        let mut rng = ::test::rng(124);
+        for &(lambda, max) in [(4.0, 3), (2.0, 10), (100.0, 90), (50.0, 0)].iter() {
+            let poisson = Poisson::new_truncated(lambda, max).unwrap();
+            let weights: Vec<f64> = (0..(max + 1)).map(|k| {
+                let k = k as f64;
+                (k * f64::ln(lambda) - lambda - log_gamma(k + 1.0)).exp()
+            }).collect();
+            let total: f64 = weights.iter().sum();
+            let expected = weights.iter().enumerate()
+                .map(|(k, w)| k as f64 * w).sum::<f64>() / total;
+
+            let n = 20_000;
+            let mut sum = 0;
+            for _ in 0..n {
+                let k = poisson.sample(&mut rng);
+                assert!(k <= max);
+                sum += k;
+            }
+            let mean = sum as f64 / n as f64;
+            assert!((mean - expected).abs() < 0.02 * expected.max(1.0));
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_poisson_invalid_lambda_neg() {
-        Poisson::new(-10.0);
+    fn test_poisson_truncated_max_u64() {
+        use distributions::Moments;
+        let mut rng = ::test::rng(135);
+        // Truncating at `u64::MAX` is the same as not truncating at all.
+        let poisson = Poisson::new_truncated(3.0, ::std::u64::MAX).unwrap();
+        assert!((poisson.mean() - 3.0).abs() < 1e-12);
+        assert!((poisson.variance() - 3.0).abs() < 1e-12);
+        let samples: Vec<f64> = (0..100_000).map(|_| poisson.sample(&mut rng) as f64).collect();
+        ::test::assert_moments(&samples, 3.0, 3.0, 0.02);
+
+        // Sampling stops at the last weight in the total, not at `max`.
+        let t = poisson.truncated.unwrap();
+        assert!(t.last > 3 && t.last < 100, "{}", t.last);
+        for _ in 0..100_000 {
+            assert!(poisson.sample(&mut rng) <= t.last);
+        }
+        let poisson = Poisson::new_truncated(2.0, 5).unwrap();
+        assert_eq!(poisson.truncated.unwrap().last, 5);
+    }
+
+    #[test]
+    fn test_poisson_moments() {
+        use distributions::Moments;
+        let mut rng = ::test::rng(234);
+        let poisson = Poisson::new(7.5).unwrap();
+        assert_eq!((poisson.mean(), poisson.variance()), (7.5, 7.5));
+        let samples: Vec<f64> = (0..100_000).map(|_| poisson.sample(&mut rng) as f64).collect();
+        ::test::assert_moments(&samples, 7.5, 7.5, 0.03);
+
+        // Truncated to `0..=1`, a Bernoulli variable with
+        // `p = lambda / (1 + lambda)`.
+        let bernoulli = Poisson::new_truncated(3.0, 1).unwrap();
+        assert!((bernoulli.mean() - 0.75).abs() < 1e-12);
+        assert!((bernoulli.variance() - 0.1875).abs() < 1e-12);
+        // A high cutoff changes nothing.
+        let untruncated = Poisson::new_truncated(4.0, 1000).unwrap();
+        assert!((untruncated.mean() - 4.0).abs() < 1e-12);
+        assert!((untruncated.variance() - 4.0).abs() < 1e-12);
+
+        let truncated = Poisson::new_truncated(100.0, 90).unwrap();
+        let samples: Vec<f64> = (0..100_000).map(|_| truncated.sample(&mut rng) as f64).collect();
+        ::test::assert_moments(&samples, truncated.mean(), truncated.variance(), 0.03);
+        assert!(truncated.variance() < 100.0);
+    }
+
+    #[test]
+    fn test_poisson_sample_into() {
+        for &lambda in [5.0, 50.0].iter() {
+            let poisson = Poisson::new(lambda).unwrap();
+            let mut buf = [0u64; 20_000];
+            poisson.sample_into(&mut ::test::rng(125), &mut buf);
+
+            // The batch matches single-shot sampling from the same seed.
+            let mut rng = ::test::rng(125);
+            for &x in buf[..100].iter() {
+                assert_eq!(x, poisson.sample(&mut rng));
+            }
+
+            let samples: Vec<f64> = buf.iter().map(|&x| x as f64).collect();
+            ::test::assert_moments(&samples, lambda, lambda, 0.05);
+        }
+
+        let truncated = Poisson::new_truncated(20.0, 10).unwrap();
+        let mut buf = [0u64; 100];
+        truncated.sample_into(&mut ::test::rng(126), &mut buf);
+        assert!(buf.iter().all(|&x| x <= 10));
+    }
+
+    #[test]
+    fn test_poisson_invalid_lambda_zero() {
+        assert_eq!(Poisson::new(0.0).unwrap_err(), DistError::LambdaNotPositive);
     }
 }
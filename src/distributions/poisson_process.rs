@@ -0,0 +1,172 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The homogeneous Poisson process.
+
+use Rng;
+use distributions::{Distribution, DistError, Exp};
+use std::fmt;
+
+/// A homogeneous Poisson process with a given `rate`, generating the times
+/// of independent events occurring at a constant average rate.
+///
+/// The times between consecutive events are independent and exponentially
+/// distributed with parameter `rate`, so the number of events in an interval
+/// of length `t` has a [`Poisson`] distribution with mean `rate * t`. This
+/// is the usual model of arrivals in discrete-event simulations, e.g. of
+/// customers at a queue.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::PoissonProcess;
+///
+/// // On average two arrivals per minute, during an hour.
+/// let process = PoissonProcess::new(2.0).unwrap();
+/// let arrivals = process.arrivals(&mut rand::thread_rng(), 60.0);
+/// println!("{} arrivals, the first at {:?}", arrivals.len(), arrivals.first());
+/// ```
+///
+/// [`Poisson`]: struct.Poisson.html
+#[derive(Clone, Copy, Debug)]
+pub struct PoissonProcess {
+    rate: f64,
+    inter_arrival: Exp,
+}
+
+impl PoissonProcess {
+    /// Construct a new `PoissonProcess` with the given `rate` of events per
+    /// unit of time. Returns an error if `rate <= 0`.
+    pub fn new(rate: f64) -> Result<PoissonProcess, DistError> {
+        Ok(PoissonProcess { rate, inter_arrival: Exp::new(rate)? })
+    }
+
+    /// Construct a new `PoissonProcess`, like [`new`](#method.new).
+    ///
+    /// Panics if `rate <= 0`.
+    pub fn new_unchecked(rate: f64) -> PoissonProcess {
+        PoissonProcess::new(rate).expect("PoissonProcess::new_unchecked called with rate <= 0")
+    }
+
+    /// Generate the times of the events in `[0, until)`, in increasing
+    /// order, by accumulating exponential inter-arrival times.
+    pub fn arrivals<R: Rng + ?Sized>(&self, rng: &mut R, until: f64) -> Vec<f64> {
+        self.arrivals_from(rng, 0.0, until)
+    }
+
+    /// Generate the times of the events in `[start, until)`, in increasing
+    /// order.
+    ///
+    /// Since the exponential distribution is memoryless, the events of
+    /// consecutive windows `[t0, t1)`, `[t1, t2)`, ... generated with this
+    /// method together form a sample of the process on `[t0, tn)`; windows
+    /// can thus be generated on demand along a shared timeline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::PoissonProcess;
+    ///
+    /// let process = PoissonProcess::new(2.0).unwrap();
+    /// let mut rng = rand::thread_rng();
+    /// let mut arrivals = process.arrivals_from(&mut rng, 0.0, 30.0);
+    /// arrivals.extend(process.arrivals_from(&mut rng, 30.0, 60.0));
+    /// assert!(arrivals.iter().all(|&t| t >= 0.0 && t < 60.0));
+    /// ```
+    pub fn arrivals_from<R: Rng + ?Sized>(&self, rng: &mut R, start: f64, until: f64)
+        -> Vec<f64>
+    {
+        let mut times = Vec::new();
+        let mut t = start;
+        loop {
+            t += self.inter_arrival.sample(rng);
+            if !(t < until) {
+                return times;
+            }
+            times.push(t);
+        }
+    }
+}
+
+impl fmt::Display for PoissonProcess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PoissonProcess(rate={})", self.rate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::DistError;
+    use super::PoissonProcess;
+
+    #[test]
+    fn test_poisson_process_counts() {
+        let mut rng = ::test::rng(207);
+        let process = PoissonProcess::new(2.5).unwrap();
+        let counts: Vec<f64> = (0..20_000).map(|_| {
+            let arrivals = process.arrivals(&mut rng, 4.0);
+            assert!(arrivals.windows(2).all(|w| w[0] <= w[1]));
+            assert!(arrivals.iter().all(|&t| t >= 0.0 && t < 4.0));
+            arrivals.len() as f64
+        }).collect();
+        // The counts are `Poisson(10)`, with equal mean and variance.
+        ::test::assert_moments(&counts, 10.0, 10.0, 0.03);
+        let zeros = counts.iter().filter(|&&c| c == 0.0).count() as f64;
+        assert!((zeros / 20_000.0 - (-10f64).exp()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_poisson_process_arrivals_from() {
+        let mut rng = ::test::rng(224);
+        let process = PoissonProcess::new(2.0).unwrap();
+        let mut counts = [Vec::new(), Vec::new(), Vec::new()];
+        let mut waits = Vec::new();
+        for _ in 0..20_000 {
+            let first = process.arrivals_from(&mut rng, 1.0, 3.0);
+            let second = process.arrivals_from(&mut rng, 3.0, 9.0);
+            assert!(first.iter().all(|&t| t >= 1.0 && t < 3.0));
+            assert!(second.iter().all(|&t| t >= 3.0 && t < 9.0));
+            counts[0].push(first.len() as f64);
+            counts[1].push(second.len() as f64);
+            counts[2].push((first.len() + second.len()) as f64);
+            // The process is memoryless: the wait for the first event of
+            // the second window doesn't depend on the first window.
+            if let Some(&t) = second.first() {
+                waits.push(t - 3.0);
+            }
+        }
+        ::test::assert_moments(&counts[0], 4.0, 4.0, 0.03);
+        ::test::assert_moments(&counts[1], 12.0, 12.0, 0.03);
+        ::test::assert_moments(&counts[2], 16.0, 16.0, 0.03);
+        ::test::assert_moments(&waits, 0.5, 0.25, 0.03);
+        // The counts of the windows are independent.
+        assert!(::test::correlation(&counts[0], &counts[1]).abs() < 0.03);
+
+        // From zero, this is the same as `arrivals`.
+        let mut a = ::test::rng(225);
+        let mut b = ::test::rng(225);
+        assert_eq!(process.arrivals(&mut a, 5.0), process.arrivals_from(&mut b, 0.0, 5.0));
+        assert!(process.arrivals_from(&mut a, 5.0, 5.0).is_empty());
+        assert!(process.arrivals_from(&mut a, 5.0, 4.0).is_empty());
+    }
+
+    #[test]
+    fn test_poisson_process_empty_interval() {
+        let mut rng = ::test::rng(208);
+        let process = PoissonProcess::new(100.0).unwrap();
+        assert!(process.arrivals(&mut rng, 0.0).is_empty());
+        assert!(process.arrivals(&mut rng, -1.0).is_empty());
+        assert!(process.arrivals(&mut rng, ::std::f64::NAN).is_empty());
+    }
+
+    #[test]
+    fn test_poisson_process_invalid() {
+        assert_eq!(PoissonProcess::new(0.0).unwrap_err(), DistError::LambdaNotPositive);
+        assert_eq!(PoissonProcess::new(-1.0).unwrap_err(), DistError::LambdaNotPositive);
+    }
+}
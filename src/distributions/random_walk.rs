@@ -0,0 +1,89 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A random walk with steps from a real-valued distribution.
+
+use Rng;
+use distributions::Distribution;
+#[cfg(not(feature="std"))] use alloc::vec::Vec;
+
+/// A one-dimensional random walk, whose steps are independent samples of the
+/// distribution `D`.
+///
+/// With normally distributed steps this approximates Brownian motion; a
+/// distribution with heavier tails, such as Laplace or [`Cauchy`], gives
+/// occasional large jumps.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Normal, RandomWalk};
+///
+/// let walk = RandomWalk::new(Normal::new(0.0, 1.0).unwrap());
+/// let path = walk.path(&mut rand::thread_rng(), 100, 10.0);
+/// assert_eq!(path.len(), 101);
+/// assert_eq!(path[0], 10.0);
+/// ```
+///
+/// [`Cauchy`]: struct.Cauchy.html
+#[derive(Clone, Copy, Debug)]
+pub struct RandomWalk<D> {
+    step: D,
+}
+
+impl<D: Distribution<f64>> RandomWalk<D> {
+    /// Construct a new `RandomWalk` taking steps sampled from `step`.
+    pub fn new(step: D) -> RandomWalk<D> {
+        RandomWalk { step }
+    }
+
+    /// Return the positions of a walk of `n` steps beginning at `start`.
+    ///
+    /// The result has `n + 1` elements: `start`, followed by the position
+    /// after each step.
+    pub fn path<R: Rng + ?Sized>(&self, rng: &mut R, n: usize, start: f64) -> Vec<f64> {
+        let mut path = Vec::with_capacity(n + 1);
+        let mut x = start;
+        path.push(x);
+        for _ in 0..n {
+            x += self.step.sample(rng);
+            path.push(x);
+        }
+        path
+    }
+}
+
+#[cfg(all(test, feature="std"))]
+mod test {
+    use distributions::{Dirac, Normal};
+    use super::RandomWalk;
+
+    #[test]
+    fn test_random_walk_variance() {
+        let mut rng = ::test::rng(181);
+        let walk = RandomWalk::new(Normal::new(0.0, 0.5).unwrap());
+        for &n in [1usize, 10, 100].iter() {
+            let ends: Vec<f64> = (0..20_000).map(|_| {
+                let path = walk.path(&mut rng, n, 2.0);
+                assert_eq!(path.len(), n + 1);
+                assert_eq!(path[0], 2.0);
+                path[n]
+            }).collect();
+            // The variance of the endpoint grows linearly with the steps.
+            ::test::assert_moments(&ends, 2.0, 0.25 * n as f64, 0.05);
+        }
+    }
+
+    #[test]
+    fn test_random_walk_constant_step() {
+        let mut rng = ::test::rng(182);
+        let walk = RandomWalk::new(Dirac::new(1.5));
+        assert_eq!(walk.path(&mut rng, 3, -1.0), vec![-1.0, 0.5, 2.0, 3.5]);
+        assert_eq!(walk.path(&mut rng, 0, 4.0), vec![4.0]);
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rejection sampling from an arbitrary target density.
+
+use core::fmt;
+
+use Rng;
+use distributions::{Distribution, DistError};
+
+/// Maximum number of proposals drawn for a single sample before giving up.
+const MAX_ITERATIONS: u32 = 1_000_000;
+
+/// Sample from an arbitrary density `target` by rejection sampling.
+///
+/// Candidates `x` are drawn from the `proposal` distribution, which has
+/// density `proposal_density`, and accepted with probability
+/// `target(x) / (m * proposal_density(x))`. The densities do not need to be
+/// normalized, but `target(x) <= m * proposal_density(x)` must hold for all
+/// `x`; the expected number of proposals per sample is the ratio of the
+/// integrals of `m * proposal_density` and `target`.
+///
+/// # Panics
+///
+/// Sampling panics if a candidate with `target(x) > m * proposal_density(x)`
+/// is encountered, since the bound `m` is then too small and the samples
+/// would be biased, or if no candidate is accepted after a large number of
+/// attempts.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, Rejection, Uniform};
+///
+/// // The density 3x^2 on [0, 1], bounded by 3 times the uniform density.
+/// let distr = Rejection::new(|x: f64| 3.0 * x * x,
+///                            Uniform::new(0.0, 1.0),
+///                            |_| 1.0,
+///                            3.0).unwrap();
+/// let v = distr.sample(&mut rand::thread_rng());
+/// assert!(v >= 0.0 && v < 1.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Rejection<F, D, G> {
+    target: F,
+    proposal: D,
+    proposal_density: G,
+    m: f64,
+}
+
+impl<F, D, G> Rejection<F, D, G>
+    where F: Fn(f64) -> f64, D: Distribution<f64>, G: Fn(f64) -> f64
+{
+    /// Construct a new `Rejection` sampler for the density `target`, using
+    /// the `proposal` distribution with density `proposal_density` and the
+    /// bound `m`.
+    ///
+    /// Returns an error if `m` is not strictly positive and finite.
+    pub fn new(target: F, proposal: D, proposal_density: G, m: f64)
+        -> Result<Rejection<F, D, G>, DistError>
+    {
+        if !(m > 0.0 && m.is_finite()) {
+            return Err(DistError::BoundNotPositive);
+        }
+        Ok(Rejection { target, proposal, proposal_density, m })
+    }
+
+    /// Construct a new `Rejection` sampler, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is not strictly positive and finite.
+    pub fn new_unchecked(target: F, proposal: D, proposal_density: G, m: f64)
+        -> Rejection<F, D, G>
+    {
+        Rejection::new(target, proposal, proposal_density, m)
+            .expect("Rejection::new_unchecked called with invalid bound `m`")
+    }
+}
+
+impl<F, D, G> Distribution<f64> for Rejection<F, D, G>
+    where F: Fn(f64) -> f64, D: Distribution<f64>, G: Fn(f64) -> f64
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        for _ in 0..MAX_ITERATIONS {
+            let x = self.proposal.sample(rng);
+            let bound = self.m * (self.proposal_density)(x);
+            let t = (self.target)(x);
+            if t > bound {
+                panic!("Rejection: target density {} exceeds the bound {} at {}; \
+                        the bound `m` is too small", t, bound, x);
+            }
+            if rng.gen::<f64>() * bound < t {
+                return x;
+            }
+        }
+        panic!("Rejection: no sample accepted after {} proposals; the bound `m` \
+                may be far too large or the target density zero", MAX_ITERATIONS);
+    }
+}
+
+impl<F, D: fmt::Debug, G> fmt::Debug for Rejection<F, D, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Rejection")
+            .field("proposal", &self.proposal)
+            .field("m", &self.m)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError, Uniform};
+    use super::Rejection;
+
+    #[test]
+    fn test_rejection_quadratic() {
+        let mut rng = ::test::rng(401);
+        let distr = Rejection::new(|x: f64| 3.0 * x * x,
+                                   Uniform::new(0.0, 1.0),
+                                   |_| 1.0,
+                                   3.0).unwrap();
+        let n = 10_000;
+        let mut sum = 0.0;
+        let mut below_half = 0;
+        for _ in 0..n {
+            let x = distr.sample(&mut rng);
+            assert!(x >= 0.0 && x < 1.0);
+            sum += x;
+            if x < 0.5 { below_half += 1; }
+        }
+        // E[X] = 3/4 and P(X < 1/2) = 1/8
+        assert!((sum / n as f64 - 0.75).abs() < 0.01);
+        assert!((below_half as f64 / n as f64 - 0.125).abs() < 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rejection_bound_too_small() {
+        let mut rng = ::test::rng(402);
+        let distr = Rejection::new(|x: f64| 3.0 * x * x,
+                                   Uniform::new(0.0, 1.0),
+                                   |_| 1.0,
+                                   1.0).unwrap();
+        for _ in 0..1000 {
+            distr.sample(&mut rng);
+        }
+    }
+
+    #[test]
+    fn test_rejection_invalid_bound() {
+        let new = |m| Rejection::new(|x: f64| x, Uniform::new(0.0, 1.0), |_| 1.0, m);
+        assert_eq!(new(0.0).unwrap_err(), DistError::BoundNotPositive);
+        assert_eq!(new(::core::f64::INFINITY).unwrap_err(), DistError::BoundNotPositive);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The uniform distribution on the probability simplex.
+
+use Rng;
+use distributions::{Distribution, DistError};
+use std::fmt;
+
+/// The uniform distribution on the probability simplex: vectors of `dim`
+/// non-negative numbers which sum to one.
+///
+/// This is the same distribution as `Dirichlet(1, ..., 1)`, but sampled more
+/// cheaply: `dim - 1` uniform numbers are drawn and sorted, and the gaps
+/// between them (and the endpoints `0` and `1`) are returned.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{UniformSimplex, Distribution};
+///
+/// let simplex = UniformSimplex::new(3).unwrap();
+/// let v = simplex.sample(&mut rand::thread_rng());
+/// println!("{:?} is uniform on the 3-simplex", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformSimplex {
+    dim: usize,
+}
+
+impl UniformSimplex {
+    /// Construct a new `UniformSimplex` producing vectors of length `dim`.
+    ///
+    /// Returns an error if `dim < 1`.
+    #[inline]
+    pub fn new(dim: usize) -> Result<UniformSimplex, DistError> {
+        if dim < 1 {
+            return Err(DistError::SizeTooSmall);
+        }
+        Ok(UniformSimplex { dim })
+    }
+
+    /// Construct a new `UniformSimplex`, like [`new`](#method.new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dim < 1`.
+    #[inline]
+    pub fn new_unchecked(dim: usize) -> UniformSimplex {
+        UniformSimplex::new(dim).expect("UniformSimplex::new_unchecked called with `dim` < 1")
+    }
+}
+
+impl Distribution<Vec<f64>> for UniformSimplex {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let mut cuts: Vec<f64> = (1..self.dim).map(|_| rng.gen()).collect();
+        cuts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        cuts.push(1.0);
+
+        let mut prev = 0.0;
+        for cut in cuts.iter_mut() {
+            let x = *cut;
+            *cut = x - prev;
+            prev = x;
+        }
+        cuts
+    }
+}
+
+impl fmt::Display for UniformSimplex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UniformSimplex(dim={})", self.dim)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::UniformSimplex;
+
+    #[test]
+    fn test_simplex() {
+        let mut rng = ::test::rng(371);
+        let dim = 5;
+        let simplex = UniformSimplex::new(dim).unwrap();
+        let n = 10_000;
+        let mut means = vec![0.0; dim];
+        for _ in 0..n {
+            let v = simplex.sample(&mut rng);
+            assert_eq!(v.len(), dim);
+            assert!(v.iter().all(|&x| x >= 0.0));
+            assert!((v.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            for (m, x) in means.iter_mut().zip(v.iter()) {
+                *m += x / n as f64;
+            }
+        }
+        for m in means {
+            assert!((m - 1.0 / dim as f64).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_simplex_dim_one() {
+        let mut rng = ::test::rng(372);
+        let simplex = UniformSimplex::new(1).unwrap();
+        assert_eq!(simplex.sample(&mut rng), vec![1.0]);
+    }
+
+    #[test]
+    fn test_simplex_invalid_dim() {
+        assert_eq!(UniformSimplex::new(0).unwrap_err(), DistError::SizeTooSmall);
+    }
+}
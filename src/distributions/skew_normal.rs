@@ -0,0 +1,141 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The skew-normal distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, StandardNormal};
+use std::fmt;
+
+/// The skew-normal distribution `SkewNormal(location, scale, shape)`.
+///
+/// This generalises the normal distribution with a `shape` parameter
+/// controlling the skewness: positive values give a longer right tail,
+/// negative values a longer left tail, and `shape == 0` is the normal
+/// distribution `Normal(location, scale)`.
+///
+/// Samples are constructed from two standard normal variates `u` and `v` as
+/// `location + scale (δ |u| + sqrt(1 - δ²) v)`, where
+/// `δ = shape / sqrt(1 + shape²)`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{SkewNormal, Distribution};
+///
+/// let skew_normal = SkewNormal::new(2.0, 3.0, 4.0).unwrap();
+/// let v = skew_normal.sample(&mut rand::thread_rng());
+/// println!("{} is from a SkewNormal(2, 3, 4) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SkewNormal {
+    location: f64,
+    scale: f64,
+    shape: f64,
+    /// `shape / sqrt(1 + shape^2)`.
+    delta: f64,
+    /// `sqrt(1 - delta^2)`.
+    delta_c: f64,
+}
+
+impl SkewNormal {
+    /// Construct a new `SkewNormal` with the given location, scale and shape.
+    ///
+    /// Returns an error if `scale <= 0`, or if `shape` is infinite or NaN.
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<SkewNormal, DistError> {
+        if !(scale > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        if !shape.is_finite() {
+            return Err(DistError::ShapeOutOfRange);
+        }
+        let delta = shape / (1.0 + shape * shape).sqrt();
+        Ok(SkewNormal {
+            location, scale, shape, delta,
+            delta_c: (1.0 - delta * delta).sqrt(),
+        })
+    }
+
+    /// Construct a new `SkewNormal`, like [`new`](#method.new).
+    ///
+    /// Panics if `scale <= 0`, or if `shape` is infinite or NaN.
+    pub fn new_unchecked(location: f64, scale: f64, shape: f64) -> SkewNormal {
+        SkewNormal::new(location, scale, shape)
+            .expect("SkewNormal::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for SkewNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let v: f64 = rng.sample(StandardNormal);
+        if self.delta == 0.0 {
+            // The normal distribution; skip the half-normal variate.
+            return self.location + self.scale * v;
+        }
+        let u: f64 = rng.sample(StandardNormal);
+        self.location + self.scale * (self.delta * u.abs() + self.delta_c * v)
+    }
+}
+
+impl fmt::Display for SkewNormal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SkewNormal(location={}, scale={}, shape={})",
+               self.location, self.scale, self.shape)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError, Normal};
+    use super::SkewNormal;
+    use std::f64::consts::PI;
+
+    fn skewness(samples: &[f64]) -> f64 {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let m2 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let m3 = samples.iter().map(|x| (x - mean).powi(3)).sum::<f64>() / n;
+        m3 / m2.powf(1.5)
+    }
+
+    #[test]
+    fn test_skew_normal_zero_shape() {
+        let skew_normal = SkewNormal::new(2.0, 3.0, 0.0).unwrap();
+        let normal = Normal::new(2.0, 3.0).unwrap();
+        let mut rng1 = ::test::rng(190);
+        let mut rng2 = ::test::rng(190);
+        for _ in 0..1000 {
+            assert_eq!(skew_normal.sample(&mut rng1), normal.sample(&mut rng2));
+        }
+    }
+
+    #[test]
+    fn test_skew_normal_moments() {
+        let mut rng = ::test::rng(191);
+        let (location, scale) = (1.0, 2.0);
+        for &shape in [5.0, -2.0].iter() {
+            let distr = SkewNormal::new(location, scale, shape).unwrap();
+            let samples: Vec<f64> = (0..100_000).map(|_| distr.sample(&mut rng)).collect();
+            let delta = shape / (1.0f64 + shape * shape).sqrt();
+            let b = delta * (2.0 / PI).sqrt();
+            ::test::assert_moments(&samples, location + scale * b,
+                                   scale * scale * (1.0 - b * b), 0.03);
+            let expected = (4.0 - PI) / 2.0 * b.powi(3) / (1.0 - b * b).powf(1.5);
+            let skew = skewness(&samples);
+            assert!(skew * shape > 0.0);
+            assert!((skew - expected).abs() < 0.05, "{} != {}", skew, expected);
+        }
+    }
+
+    #[test]
+    fn test_skew_normal_invalid() {
+        assert_eq!(SkewNormal::new(0.0, 0.0, 1.0).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(SkewNormal::new(0.0, 1.0, ::std::f64::INFINITY).unwrap_err(),
+                   DistError::ShapeOutOfRange);
+    }
+}
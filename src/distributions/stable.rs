@@ -0,0 +1,194 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The alpha-stable distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, Exp1, Uniform};
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::fmt;
+
+/// The alpha-stable distribution `Stable(α, β, scale, location)`.
+///
+/// A linear combination of independent stable random variables with the
+/// same stability `α` is again stable, which makes these distributions the
+/// possible limits of sums of independent variables. `α` in `(0, 2]`
+/// controls the heaviness of the tails, and the skewness `β` in `[-1, 1]`
+/// their asymmetry. The parametrization is the usual one with
+/// characteristic function `exp(i μ t - |σ t|^α (1 - i β sign(t) Φ))`, where
+/// `Φ = tan(π α / 2)` for `α != 1` and `-(2 / π) ln |t|` for `α = 1`. Special
+/// cases are:
+///
+/// - `α = 2`: the normal distribution with mean `location` and standard
+///   deviation `sqrt(2) scale`; `β` has no effect.
+/// - `α = 1` and `β = 0`: the [`Cauchy`] distribution with median `location`
+///   and the same scale.
+/// - `α = 1/2` and `β = 1`: the [`Levy`] distribution with the same location
+///   and scale.
+///
+/// Samples are generated by the method of Chambers, Mallows and Stuck.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Stable, Distribution};
+///
+/// let stable = Stable::new(1.7, 0.5, 1.0, 0.0).unwrap();
+/// let v = stable.sample(&mut rand::thread_rng());
+/// println!("{} is from a Stable(1.7, 0.5, 1, 0) distribution", v);
+/// ```
+///
+/// [`Cauchy`]: struct.Cauchy.html
+/// [`Levy`]: struct.Levy.html
+#[derive(Clone, Copy, Debug)]
+pub struct Stable {
+    alpha: f64,
+    beta: f64,
+    scale: f64,
+    location: f64,
+    /// `atan(β tan(π α / 2)) / α`, for `α != 1`.
+    b: f64,
+    /// `(1 + β^2 tan^2(π α / 2))^(1 / (2 α))`, for `α != 1`.
+    s: f64,
+}
+
+impl Stable {
+    /// Construct a new `Stable` with stability `alpha`, skewness `beta`,
+    /// `scale` and `location`.
+    ///
+    /// # Errors
+    /// - `ShapeOutOfRange` unless `0 < alpha <= 2` and `-1 <= beta <= 1`
+    /// - `ScaleNotPositive` unless `scale > 0`
+    ///
+    pub fn new(alpha: f64, beta: f64, scale: f64, location: f64)
+        -> Result<Stable, DistError>
+    {
+        if !(alpha > 0.0 && alpha <= 2.0 && beta >= -1.0 && beta <= 1.0) {
+            return Err(DistError::ShapeOutOfRange);
+        }
+        if !(scale > 0.0) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        let t = beta * (FRAC_PI_2 * alpha).tan();
+        Ok(Stable {
+            alpha, beta, scale, location,
+            b: t.atan() / alpha,
+            s: (1.0 + t * t).powf(0.5 / alpha),
+        })
+    }
+
+    /// Construct a new `Stable`, like [`new`](#method.new).
+    ///
+    /// Panics unless `0 < alpha <= 2`, `-1 <= beta <= 1` and `scale > 0`.
+    pub fn new_unchecked(alpha: f64, beta: f64, scale: f64, location: f64) -> Stable {
+        Stable::new(alpha, beta, scale, location)
+            .expect("Stable::new_unchecked called with invalid parameters")
+    }
+}
+
+impl Distribution<f64> for Stable {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let v = rng.sample(Uniform::new(-FRAC_PI_2, FRAC_PI_2));
+        let w: f64 = rng.sample(Exp1);
+        let (alpha, beta) = (self.alpha, self.beta);
+        if alpha == 1.0 {
+            let c = FRAC_PI_2 + beta * v;
+            let x = (c * v.tan() - beta * (FRAC_PI_2 * w * v.cos() / c).ln()) * 2.0 / PI;
+            self.scale * x + self.location + 2.0 / PI * beta * self.scale * self.scale.ln()
+        } else {
+            let a = alpha * (v + self.b);
+            let x = self.s * a.sin() / v.cos().powf(1.0 / alpha)
+                * ((v - a).cos() / w).powf((1.0 - alpha) / alpha);
+            self.scale * x + self.location
+        }
+    }
+}
+
+impl fmt::Display for Stable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Stable(alpha={}, beta={}, scale={}, location={})",
+               self.alpha, self.beta, self.scale, self.location)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError, Cauchy, Levy, Normal};
+    use super::Stable;
+
+    /// The quartiles and the median of `n` samples of `distr`.
+    fn quartiles<D: Distribution<f64>>(distr: &D, seed: u64, n: usize) -> [f64; 3] {
+        let mut rng = ::test::rng(seed);
+        let mut samples: Vec<f64> = distr.sample_iter(&mut rng).take(n).collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        [samples[n / 4], samples[n / 2], samples[3 * n / 4]]
+    }
+
+    fn assert_close(a: [f64; 3], b: [f64; 3], tol: f64) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < tol, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_stable_normal() {
+        let mut rng = ::test::rng(221);
+        let stable = Stable::new(2.0, 0.7, 1.5, -3.0).unwrap();
+        let samples: Vec<f64> = stable.sample_iter(&mut rng).take(100_000).collect();
+        ::test::assert_moments(&samples, -3.0, 2.0 * 1.5 * 1.5, 0.02);
+        let normal = Normal::new(-3.0, 1.5 * 2f64.sqrt()).unwrap();
+        assert_close(quartiles(&stable, 222, 100_000), quartiles(&normal, 223, 100_000), 0.03);
+    }
+
+    #[test]
+    fn test_stable_cauchy() {
+        let stable = Stable::new(1.0, 0.0, 2.0, 5.0).unwrap();
+        let cauchy = Cauchy::new(5.0, 2.0).unwrap();
+        let q = quartiles(&stable, 224, 100_000);
+        assert_close(q, quartiles(&cauchy, 225, 100_000), 0.06);
+        assert_close(q, [3.0, 5.0, 7.0], 0.06);
+    }
+
+    #[test]
+    fn test_stable_levy() {
+        let stable = Stable::new(0.5, 1.0, 2.0, 1.0).unwrap();
+        let levy = Levy::new(1.0, 2.0).unwrap();
+        let q = quartiles(&stable, 226, 100_000);
+        assert!(q[0] > 1.0);
+        let expected = quartiles(&levy, 227, 100_000);
+        for i in 0..3 {
+            assert!((q[i] - expected[i]).abs() < 0.03 * expected[i], "{:?} != {:?}", q, expected);
+        }
+    }
+
+    #[test]
+    fn test_stable_skewed() {
+        // Totally skewed to the left with `α < 1`: bounded above by the
+        // location.
+        let stable = Stable::new(0.7, -1.0, 1.0, 2.0).unwrap();
+        let mut rng = ::test::rng(228);
+        for _ in 0..1000 {
+            assert!(stable.sample(&mut rng) <= 2.0);
+        }
+        // The skewness moves the median.
+        let right = quartiles(&Stable::new(1.0, 0.8, 1.0, 0.0).unwrap(), 229, 10_000);
+        let left = quartiles(&Stable::new(1.0, -0.8, 1.0, 0.0).unwrap(), 230, 10_000);
+        assert!(right[1] > 0.1 && left[1] < -0.1);
+        assert!((right[1] + left[1]).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_stable_invalid() {
+        assert_eq!(Stable::new(0.0, 0.0, 1.0, 0.0).unwrap_err(), DistError::ShapeOutOfRange);
+        assert_eq!(Stable::new(2.1, 0.0, 1.0, 0.0).unwrap_err(), DistError::ShapeOutOfRange);
+        assert_eq!(Stable::new(1.5, 1.1, 1.0, 0.0).unwrap_err(), DistError::ShapeOutOfRange);
+        assert_eq!(Stable::new(1.5, ::std::f64::NAN, 1.0, 0.0).unwrap_err(),
+                   DistError::ShapeOutOfRange);
+        assert_eq!(Stable::new(1.5, 0.0, 0.0, 0.0).unwrap_err(), DistError::ScaleNotPositive);
+    }
+}
@@ -8,7 +8,8 @@
 //! The triangular distribution.
 
 use Rng;
-use distributions::{Distribution, Standard};
+use distributions::{Distribution, DistError, Standard};
+use std::fmt;
 
 /// The triangular distribution.
 ///
@@ -17,7 +18,7 @@ use distributions::{Distribution, Standard};
 /// ```rust
 /// use rand::distributions::{Triangular, Distribution};
 ///
-/// let d = Triangular::new(0., 5., 2.5);
+/// let d = Triangular::new(0., 5., 2.5).unwrap();
 /// let v = d.sample(&mut rand::thread_rng());
 /// println!("{} is from a triangular distribution", v);
 /// ```
@@ -32,16 +33,31 @@ impl Triangular {
     /// Construct a new `Triangular` with minimum `min`, maximum `max` and mode
     /// `mode`.
     ///
+    /// # Errors
+    ///
+    /// If `max <= min`, or `mode` is not in the range `[min, max]`.
+    ///
+    #[inline]
+    pub fn new(min: f64, max: f64, mode: f64) -> Result<Triangular, DistError> {
+        if !(max > min) {
+            return Err(DistError::InvalidRange);
+        }
+        if !(max >= mode && mode >= min) {
+            return Err(DistError::ModeOutOfRange);
+        }
+        Ok(Triangular { min, max, mode })
+    }
+
+    /// Construct a new `Triangular`, like [`new`](#method.new).
+    ///
     /// # Panics
     ///
-    /// If `max < mode`, `mode < max` or `max == min`.
+    /// If `max <= min`, or `mode` is not in the range `[min, max]`.
     ///
     #[inline]
-    pub fn new(min: f64, max: f64, mode: f64) -> Triangular {
-        assert!(max >= mode);
-        assert!(mode >= min);
-        assert!(max != min);
-        Triangular { min, max, mode }
+    pub fn new_unchecked(min: f64, max: f64, mode: f64) -> Triangular {
+        Triangular::new(min, max, mode)
+            .expect("Triangular::new_unchecked called with invalid parameters")
     }
 }
 
@@ -59,9 +75,15 @@ impl Distribution<f64> for Triangular {
     }
 }
 
+impl fmt::Display for Triangular {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Triangular(min={}, max={}, mode={})", self.min, self.max, self.mode)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use distributions::Distribution;
+    use distributions::{Distribution, DistError};
     use super::Triangular;
 
     #[test]
@@ -71,13 +93,21 @@ mod test {
             (0., 1., 0.9), (-4., -0.5, -2.), (-13.039, 8.41, 1.17),
         ] {
             println!("{} {} {}", min, max, mode);
-            let _ = Triangular::new(min, max, mode);
+            let _ = Triangular::new(min, max, mode).unwrap();
         }
     }
 
+    #[test]
+    fn test_new_invalid() {
+        assert_eq!(Triangular::new(1., 1., 1.).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(Triangular::new(2., 1., 1.5).unwrap_err(), DistError::InvalidRange);
+        assert_eq!(Triangular::new(0., 1., 2.).unwrap_err(), DistError::ModeOutOfRange);
+        assert_eq!(Triangular::new(0., 1., -1.).unwrap_err(), DistError::ModeOutOfRange);
+    }
+
     #[test]
     fn test_sample() {
-        let norm = Triangular::new(0., 1., 0.5);
+        let norm = Triangular::new(0., 1., 0.5).unwrap();
         let mut rng = ::test::rng(1);
         for _ in 0..1000 {
             norm.sample(&mut rng);
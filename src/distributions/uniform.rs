@@ -24,6 +24,10 @@
 //! ranges as well as from `std::time::Duration`; these types do not normally
 //! need to be used directly (unless implementing a derived back-end).
 //!
+//! Types which can be mapped to and from `f64`, such as timestamps, can be
+//! sampled without a back-end with [`Uniform::new_by`], which returns a
+//! [`UniformBy`].
+//!
 //! # Example usage
 //!
 //! ```
@@ -107,13 +111,18 @@
 //! [`UniformInt`]: struct.UniformInt.html
 //! [`UniformFloat`]: struct.UniformFloat.html
 //! [`UniformDuration`]: struct.UniformDuration.html
+//! [`Uniform::new_by`]: struct.Uniform.html#method.new_by
+//! [`UniformBy`]: struct.UniformBy.html
 //! [`SampleBorrow::borrow`]: trait.SampleBorrow.html#method.borrow
 
 #[cfg(feature = "std")]
 use std::time::Duration;
 
+use core::fmt;
+use core::marker::PhantomData;
+
 use Rng;
-use distributions::Distribution;
+use distributions::{Distribution, AntitheticDistribution, InverseCdf};
 use distributions::float::IntoFloat;
 use distributions::utils::{WideningMultiply, FloatSIMDUtils, FloatAsSIMD, BoolAsSIMD};
 
@@ -198,6 +207,113 @@ impl<X: SampleUniform> Distribution<X> for Uniform<X> {
     }
 }
 
+impl Uniform<u32> {
+    /// Fill `out` with samples from this distribution.
+    ///
+    /// The whole slice is first filled with raw random words in one call to
+    /// [`Rng::fill`], which is much cheaper than generating them one by one
+    /// for block-based generators. Each word is then mapped into the range
+    /// with a widening multiply; only the rare words which would cause bias
+    /// are replaced, one at a time, by rejection sampling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::Uniform;
+    ///
+    /// let mut rolls = [0u32; 100];
+    /// Uniform::new_inclusive(1, 6).fill(&mut rand::thread_rng(), &mut rolls);
+    /// assert!(rolls.iter().all(|&x| 1 <= x && x <= 6));
+    /// ```
+    ///
+    /// [`Rng::fill`]: ../trait.Rng.html#method.fill
+    pub fn fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u32]) {
+        let UniformInt { low, range, zone } = self.inner;
+        rng.fill(out);
+        if range == 0 {
+            // The entire integer range; the raw words are already uniform.
+            return;
+        }
+        for x in out.iter_mut() {
+            let (mut hi, mut lo) = x.wmul(range);
+            while lo > zone {
+                let (h, l) = rng.next_u32().wmul(range);
+                hi = h;
+                lo = l;
+            }
+            *x = low.wrapping_add(hi);
+        }
+    }
+}
+
+impl Uniform<f64> {
+    /// Create a new [`UniformBy`] instance which samples uniformly from the
+    /// half open range `[low, high)` of a type `T` mapped to `f64`.
+    ///
+    /// The range is sampled as `from_f64(x)`, where `x` is uniform on
+    /// `[key(low), key(high))`. So that the samples are within the range,
+    /// `from_f64` should be increasing and invert `key`. Panics if
+    /// `key(low) >= key(high)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{Distribution, Uniform};
+    ///
+    /// #[derive(Debug, PartialEq, PartialOrd)]
+    /// struct Day(i64);
+    ///
+    /// let days = Uniform::new_by(Day(100), Day(200),
+    ///                            |d: &Day| d.0 as f64,
+    ///                            |x| Day(x.floor() as i64));
+    /// let day = days.sample(&mut rand::thread_rng());
+    /// assert!(Day(100) <= day && day < Day(200));
+    /// ```
+    ///
+    /// [`UniformBy`]: struct.UniformBy.html
+    pub fn new_by<T, K, F>(low: T, high: T, key: K, from_f64: F) -> UniformBy<T, F>
+        where K: Fn(&T) -> f64, F: Fn(f64) -> T
+    {
+        UniformBy {
+            keys: Uniform::new(key(&low), key(&high)),
+            from_f64,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Sample values uniformly between two bounds of a type mapped to `f64`.
+///
+/// This `struct` is created by [`Uniform::new_by`]; see its documentation
+/// for more.
+///
+/// [`Uniform::new_by`]: struct.Uniform.html#method.new_by
+pub struct UniformBy<T, F> {
+    keys: Uniform<f64>,
+    from_f64: F,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, F: Clone> Clone for UniformBy<T, F> {
+    fn clone(&self) -> Self {
+        UniformBy { keys: self.keys, from_f64: self.from_f64.clone(), phantom: PhantomData }
+    }
+}
+
+impl<T, F: Fn(f64) -> T> Distribution<T> for UniformBy<T, F> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        (self.from_f64)(self.keys.sample(rng))
+    }
+}
+
+impl<T, F> fmt::Debug for UniformBy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UniformBy")
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
+
 /// Helper trait for creating objects using the correct implementation of
 /// [`UniformSampler`] for the sampling type.
 ///
@@ -824,6 +940,24 @@ uniform_float_impl! { f64x4, u64x4, f64, u64, 64 - 52 }
 #[cfg(feature="simd_support")]
 uniform_float_impl! { f64x8, u64x8, f64, u64, 64 - 52 }
 
+impl AntitheticDistribution for Uniform<f64> {
+    fn sample_antithetic<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        // As in `UniformFloat::sample`; the value `max_rand - u` has the same
+        // distribution as `u` in `[0, 1)`.
+        let value0_1 = (rng.gen::<u64>() >> 12).into_float_with_exponent(0) - 1.0;
+        let max_rand = (::core::u64::MAX >> 12).into_float_with_exponent(0) - 1.0;
+        let inner = &self.inner;
+        (value0_1 * inner.scale + inner.low, (max_rand - value0_1) * inner.scale + inner.low)
+    }
+}
+
+impl InverseCdf for Uniform<f64> {
+    /// Sampling maps random bits directly to the range instead of using
+    /// inversion, which has the same result.
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        p * self.inner.scale + self.inner.low
+    }
+}
 
 
 /// The back-end implementing [`UniformSampler`] for `Duration`.
@@ -1268,6 +1402,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_uniform_inclusive_upper_bound() {
+        // Small inclusive integer ranges produce every value, including the
+        // upper bound.
+        let mut rng = ::test::rng(805);
+        let dist = Uniform::new_inclusive(-2i32, 2);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            seen[(rng.sample(dist) + 2) as usize] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+
+        let dist = Uniform::new_inclusive(250u8, 255);
+        assert!((0..1000).any(|_| rng.sample(dist) == 255));
+
+        // The largest possible random value maps to the upper bound of an
+        // inclusive float range, but stays below the bound of a half-open one.
+        let mut max_rng = StepRng::new(!0, 0);
+        assert_eq!(max_rng.sample(Uniform::new_inclusive(1.0f64, 3.0)), 3.0);
+        assert_eq!(max_rng.sample(Uniform::new_inclusive(1.0f32, 3.0)), 3.0);
+        assert!(max_rng.sample(Uniform::new(1.0f64, 3.0)) < 3.0);
+        assert!(max_rng.sample(Uniform::new(1.0f32, 3.0)) < 3.0);
+    }
+
+    #[test]
+    fn test_uniform_fill() {
+        let mut rng = ::test::rng(806);
+        let dist = Uniform::new(10u32, 16);
+        let mut buf = [0u32; 60_000];
+        dist.fill(&mut rng, &mut buf);
+        let mut counts = [0u32; 6];
+        for &x in buf.iter() {
+            assert!(10 <= x && x < 16);
+            counts[(x - 10) as usize] += 1;
+        }
+        for &c in counts.iter() {
+            assert!((c as i32 - 10_000).abs() < 400);
+        }
+
+        // A range which rejects almost half of all words.
+        let dist = Uniform::new(0u32, (1 << 31) + 1);
+        dist.fill(&mut rng, &mut buf);
+        assert!(buf.iter().all(|&x| x <= 1 << 31));
+        assert!(buf.iter().any(|&x| x >= 1 << 30));
+
+        // The full range passes the raw words through.
+        let mut full = [0u32; 4];
+        Uniform::new_inclusive(0, u32::max_value()).fill(&mut StepRng::new(7, 0), &mut full);
+        assert_eq!(full, [7, 0, 7, 0]);
+    }
+
     #[test]
     fn test_uniform_from_std_range() {
         let r = Uniform::from(2u32..7);
@@ -1289,4 +1474,55 @@ mod tests {
         assert!(r.inner.scale > 5.0);
         assert!(r.inner.scale < 5.0 + 1e-14);
     }
+
+    #[cfg(feature="std")]
+    #[test]
+    fn test_uniform_antithetic() {
+        use distributions::AntitheticDistribution;
+        let mut rng = ::test::rng(183);
+        let distr = Uniform::new(-2.0, 3.0);
+        let (xs, ys): (Vec<f64>, Vec<f64>) =
+            (0..10_000).map(|_| distr.sample_antithetic(&mut rng)).unzip();
+        for (&x, &y) in xs.iter().zip(ys.iter()) {
+            assert!(x >= -2.0 && x < 3.0 && y >= -2.0 && y < 3.0);
+            assert!((x + y - 1.0).abs() < 1e-12);
+        }
+        ::test::assert_moments(&ys, 0.5, 25.0 / 12.0, 0.05);
+        assert!(::test::correlation(&xs, &ys) < -0.99);
+    }
+
+    #[cfg(feature="std")]
+    #[test]
+    fn test_uniform_new_by() {
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        struct Day(i32);
+
+        let mut rng = ::test::rng(198);
+        let (low, high) = (Day(-3), Day(7));
+        let days = Uniform::new_by(low, high, |d: &Day| d.0 as f64,
+                                   |x| Day(x.floor() as i32));
+        let mut seen = [false; 10];
+        for _ in 0..1000 {
+            let day = rng.sample(&days);
+            assert!(low <= day && day < high, "{:?}", day);
+            seen[(day.0 + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert!(format!("{:?}", days).starts_with("UniformBy { keys: Uniform"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uniform_new_by_empty() {
+        Uniform::new_by(2u8, 2u8, |&x| x as f64, |x| x as u8);
+    }
+
+    #[test]
+    fn test_uniform_inverse_cdf() {
+        use distributions::InverseCdf;
+        let distr = Uniform::new(-2.0, 3.0);
+        assert_eq!(distr.inverse_cdf(0.0), -2.0);
+        assert_eq!(distr.inverse_cdf(0.5), 0.5);
+        assert!(distr.inverse_cdf(1.0) <= 3.0);
+    }
 }
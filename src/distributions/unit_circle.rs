@@ -91,6 +91,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn angle_uniform() {
+        let mut rng = ::test::rng(3);
+        let dist = UnitCircle::new();
+        let n = 80_000;
+        let mut counts = [0u32; 8];
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            let angle = x[1].atan2(x[0]) + ::std::f64::consts::PI;
+            let bin = (angle / (2.0 * ::std::f64::consts::PI) * 8.0) as usize;
+            counts[bin.min(7)] += 1;
+        }
+        for &c in counts.iter() {
+            assert!(c > 9_600 && c < 10_400);
+        }
+    }
+
     #[test]
     fn value_stability() {
         let mut rng = ::test::rng(2);
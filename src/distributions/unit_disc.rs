@@ -0,0 +1,85 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use Rng;
+use distributions::{Distribution, Uniform};
+
+/// Samples uniformly from the inside of the unit disc in two dimensions.
+///
+/// Points are drawn uniformly from the square `[-1, 1]^2` and rejected if they
+/// lie outside the disc, which happens with probability `1 - pi/4`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{UnitDisc, Distribution};
+///
+/// let disc = UnitDisc::new();
+/// let v = disc.sample(&mut rand::thread_rng());
+/// println!("{:?} is from the unit disc.", v)
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitDisc {
+    uniform: Uniform<f64>,
+}
+
+impl UnitDisc {
+    /// Construct a new `UnitDisc` distribution.
+    #[inline]
+    pub fn new() -> UnitDisc {
+        UnitDisc { uniform: Uniform::new(-1., 1.) }
+    }
+}
+
+impl Distribution<[f64; 2]> for UnitDisc {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [f64; 2] {
+        loop {
+            let x1 = self.uniform.sample(rng);
+            let x2 = self.uniform.sample(rng);
+            if x1*x1 + x2*x2 <= 1. {
+                return [x1, x2];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use distributions::Distribution;
+    use super::UnitDisc;
+
+    #[test]
+    fn norm() {
+        let mut rng = ::test::rng(1);
+        let dist = UnitDisc::new();
+        for _ in 0..1000 {
+            let x = dist.sample(&mut rng);
+            assert!(x[0]*x[0] + x[1]*x[1] <= 1.);
+        }
+    }
+
+    #[test]
+    fn radius_distribution() {
+        // For a uniform point in the disc `P(r < t) = t^2`, so the annulus
+        // `[i/4, (i+1)/4)` has probability `(2i + 1) / 16`.
+        let mut rng = ::test::rng(2);
+        let dist = UnitDisc::new();
+        let n = 80_000;
+        let mut counts = [0u32; 4];
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            let r = (x[0]*x[0] + x[1]*x[1]).sqrt();
+            counts[((r * 4.) as usize).min(3)] += 1;
+        }
+        for (i, &c) in counts.iter().enumerate() {
+            let expected = n as f64 * (2 * i + 1) as f64 / 16.;
+            assert!((c as f64 - expected).abs() < 0.03 * expected);
+        }
+    }
+}
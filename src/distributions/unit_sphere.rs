@@ -86,6 +86,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn coordinates_uniform() {
+        // By Archimedes' hat-box theorem, each coordinate of a uniform point on
+        // the sphere is uniformly distributed on `[-1, 1]`.
+        let mut rng = ::test::rng(3);
+        let dist = UnitSphereSurface::new();
+        let n = 80_000;
+        let mut counts = [[0u32; 8]; 3];
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            for i in 0..3 {
+                let bin = ((x[i] + 1.0) * 4.0) as usize;
+                counts[i][bin.min(7)] += 1;
+            }
+        }
+        for c in counts.iter().flat_map(|c| c.iter()) {
+            assert!(*c > 9_600 && *c < 10_400);
+        }
+    }
+
     #[test]
     fn value_stability() {
         let mut rng = ::test::rng(2);
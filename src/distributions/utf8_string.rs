@@ -0,0 +1,169 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random strings of Unicode characters.
+
+use Rng;
+use distributions::{Distribution, Standard};
+#[cfg(not(feature="std"))] use alloc::string::String;
+#[cfg(not(feature="std"))] use alloc::vec::Vec;
+
+/// The first and one past the last surrogate code point, which are not
+/// valid `char`s.
+const SURROGATES: (u32, u32) = (0xD800, 0xE000);
+
+/// A distribution of `String`s of a fixed number of random `char`s, for
+/// example to fuzz text handling.
+///
+/// By default each `char` is sampled from [`Standard`], uniformly over all
+/// Unicode scalar values, so most are encoded with four bytes in UTF-8. The
+/// characters can be restricted to some ranges with
+/// [`with_range`](#method.with_range), picking uniformly among the characters
+/// of all ranges. Surrogate code points are never valid `char`s and always
+/// skipped.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, Utf8String};
+///
+/// // Latin and Greek letters.
+/// let distr = Utf8String::new(8)
+///     .with_range('a', 'z')
+///     .with_range('α', 'ω');
+/// let s = distr.sample(&mut rand::thread_rng());
+/// assert_eq!(s.chars().count(), 8);
+/// ```
+///
+/// [`Standard`]: struct.Standard.html
+#[derive(Clone, Debug)]
+pub struct Utf8String {
+    len_chars: usize,
+    /// Half-open ranges of code points, excluding surrogates.
+    ranges: Vec<(u32, u32)>,
+    /// Total number of code points in `ranges`.
+    total: u32,
+}
+
+impl Utf8String {
+    /// Construct a new `Utf8String` of `len_chars` characters, sampled from
+    /// all Unicode scalar values.
+    pub fn new(len_chars: usize) -> Utf8String {
+        Utf8String { len_chars, ranges: Vec::new(), total: 0 }
+    }
+
+    /// Restrict the characters to the inclusive range `[low, high]`, in
+    /// addition to any ranges added before. Characters in several
+    /// overlapping ranges are proportionally more likely.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low > high`.
+    pub fn with_range(mut self, low: char, high: char) -> Utf8String {
+        assert!(low <= high, "Utf8String::with_range called with low > high");
+        let (low, end) = (low as u32, high as u32 + 1);
+        // `char`s are never surrogates, so at most the range contains all of
+        // them and is split in two.
+        if low < SURROGATES.0 && end > SURROGATES.1 {
+            self.push_range(low, SURROGATES.0);
+            self.push_range(SURROGATES.1, end);
+        } else {
+            self.push_range(low, end);
+        }
+        self
+    }
+
+    fn push_range(&mut self, low: u32, end: u32) {
+        self.ranges.push((low, end));
+        self.total += end - low;
+    }
+
+    /// The number of characters of each sample.
+    pub fn len_chars(&self) -> usize {
+        self.len_chars
+    }
+
+    fn sample_char<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        let mut n = rng.gen_range(0, self.total);
+        for &(low, end) in self.ranges.iter() {
+            if n < end - low {
+                return ::core::char::from_u32(low + n).unwrap();
+            }
+            n -= end - low;
+        }
+        unreachable!()
+    }
+}
+
+impl Distribution<String> for Utf8String {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let mut s = String::with_capacity(self.len_chars);
+        for _ in 0..self.len_chars {
+            let c = if self.ranges.is_empty() {
+                Standard.sample(rng)
+            } else {
+                self.sample_char(rng)
+            };
+            s.push(c);
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::Distribution;
+    use super::Utf8String;
+
+    #[test]
+    fn test_utf8_string() {
+        let mut rng = ::test::rng(213);
+        let distr = Utf8String::new(50);
+        assert_eq!(distr.len_chars(), 50);
+        let mut multi_byte = 0;
+        for _ in 0..100 {
+            let s = distr.sample(&mut rng);
+            // `String` is always valid UTF-8; check the encoding round trips.
+            assert!(::core::str::from_utf8(s.as_bytes()).is_ok());
+            assert_eq!(s.chars().count(), 50);
+            multi_byte += s.chars().filter(|c| c.len_utf8() > 1).count();
+        }
+        assert!(multi_byte > 4000);
+        assert_eq!(Utf8String::new(0).sample(&mut rng), "");
+    }
+
+    #[test]
+    fn test_utf8_string_ranges() {
+        let mut rng = ::test::rng(214);
+        let distr = Utf8String::new(1000).with_range('a', 'c').with_range('€', '€');
+        let s = distr.sample(&mut rng);
+        assert_eq!(s.chars().count(), 1000);
+        let mut counts = [0u32; 4];
+        for c in s.chars() {
+            let i = match c { 'a' => 0, 'b' => 1, 'c' => 2, '€' => 3, _ => panic!("{:?}", c) };
+            counts[i] += 1;
+        }
+        for &count in counts.iter() {
+            assert!(count > 180 && count < 320, "{:?}", counts);
+        }
+        // Three bytes for each '€'.
+        assert_eq!(s.len(), 1000 + 2 * counts[3] as usize);
+
+        // Ranges spanning the surrogates skip them.
+        let distr = Utf8String::new(1000).with_range('\u{D7FF}', '\u{E000}');
+        let s = distr.sample(&mut rng);
+        assert!(s.chars().all(|c| c == '\u{D7FF}' || c == '\u{E000}'));
+        assert!(s.contains('\u{D7FF}') && s.contains('\u{E000}'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_utf8_string_invalid_range() {
+        Utf8String::new(1).with_range('z', 'a');
+    }
+}
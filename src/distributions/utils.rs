@@ -439,6 +439,60 @@ pub fn log_gamma(x: f64) -> f64 {
     log + (2.5066282746310005 * a / x).ln()
 }
 
+/// Run `attempt` until it returns `Some`, but at most `max_iters` times.
+///
+/// Returns `None` if every attempt was rejected, so that rejection samplers
+/// can fall back to a slower but bounded method instead of looping for a
+/// very long time in pathological parameter regimes.
+#[cfg(feature="std")]
+#[inline]
+pub fn rejection_loop<T, F>(max_iters: usize, mut attempt: F) -> Option<T>
+    where F: FnMut() -> Option<T>
+{
+    for _ in 0..max_iters {
+        if let Some(x) = attempt() {
+            return Some(x);
+        }
+    }
+    None
+}
+
+/// Calculates the quantile function (inverse CDF) of the standard normal
+/// distribution, for `p` in `(0, 1)`.
+///
+/// This uses Acklam's rational approximation, which has a relative error of
+/// at most `1.15e-9`, split into a central region and the two tails.
+#[cfg(feature="std")]
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [-3.969683028665376e+01, 2.209460984245205e+02,
+                         -2.759285104469687e+02, 1.383577518672690e+02,
+                         -3.066479806614716e+01, 2.506628277459239e+00];
+    const B: [f64; 5] = [-5.447609879822406e+01, 1.615858368580409e+02,
+                         -1.556989798598866e+02, 6.680131188771972e+01,
+                         -1.328068155288572e+01];
+    const C: [f64; 6] = [-7.784894002430293e-03, -3.223964580411365e-01,
+                         -2.400758277161838e+00, -2.549732539343734e+00,
+                         4.374664141464968e+00, 2.938163982698783e+00];
+    const D: [f64; 4] = [7.784695709041462e-03, 3.224671290700398e-01,
+                         2.445134137142996e+00, 3.754408661907416e+00];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5]) /
+            ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q /
+            (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    }
+}
+
 /// Sample a random number using the Ziggurat method (specifically the
 /// ZIGNOR variant from Doornik 2005). Most of the arguments are
 /// directly from the paper:
@@ -502,3 +556,46 @@ pub fn ziggurat<R: Rng + ?Sized, P, Z>(
         }
     }
 }
+
+/// Single-precision variant of [`ziggurat`], drawing a single `u32` per
+/// attempt instead of a `u64`. The arguments are the same, except that `pdf`
+/// and `zero_case` work on `f32`.
+#[cfg(feature="std")]
+#[inline(always)]
+pub fn ziggurat_f32<R: Rng + ?Sized, P, Z>(
+            rng: &mut R,
+            symmetric: bool,
+            x_tab: ziggurat_tables::ZigTable,
+            f_tab: ziggurat_tables::ZigTable,
+            mut pdf: P,
+            mut zero_case: Z)
+            -> f32 where P: FnMut(f32) -> f32, Z: FnMut(&mut R, f32) -> f32 {
+    use distributions::float::IntoFloat;
+    loop {
+        // The 8 least significant bits construct `i`, the 23 most significant
+        // bits the fraction of `u`.
+        let bits = rng.next_u32();
+        let i = bits as usize & 0xff;
+
+        let u = if symmetric {
+            (bits >> 9).into_float_with_exponent(1) - 3.0
+        } else {
+            (bits >> 9).into_float_with_exponent(0)
+            - (1.0 - ::core::f32::EPSILON / 2.0)
+        };
+        let x = u * x_tab[i] as f32;
+
+        let test_x = if symmetric { x.abs() } else {x};
+
+        if test_x < x_tab[i + 1] as f32 {
+            return x;
+        }
+        if i == 0 {
+            return zero_case(rng, u);
+        }
+        let f1 = f_tab[i + 1] as f32;
+        if f1 + (f_tab[i] as f32 - f1) * rng.gen::<f32>() < pdf(x) {
+            return x;
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Value-stability tests, pinning the samples of distributions for a fixed
+//! seed.
+//!
+//! Given a seeded generator, a distribution produces the same sequence of
+//! values across patch releases. A change to a sampling algorithm, or to the
+//! way it consumes random numbers, which alters these sequences is a
+//! breaking change: it may only be made in a release which is allowed to
+//! break the API, and must update the reference values here and be mentioned
+//! in the changelog. Additions to a distribution's methods do not affect its
+//! `sample` and need not change anything here.
+//!
+//! Integers are compared exactly. Floats are compared with a tiny relative
+//! tolerance, since the transcendental functions of the platform's math
+//! library may differ in the last bit.
+
+use distributions::{Distribution, Bernoulli, Beta, Binomial, Cauchy, Exp, Gamma,
+    Geometric, NegativeBinomial, Normal, Pareto, Poisson, Triangular, Uniform, Weibull};
+use Rng;
+
+fn check_ints<D: Distribution<u64>>(distr: D, seed: u64, expected: &[u64]) {
+    let mut rng = ::test::rng(seed);
+    let values: Vec<u64> = (0..expected.len()).map(|_| distr.sample(&mut rng)).collect();
+    assert_eq!(values, expected);
+}
+
+fn check_floats<D: Distribution<f64>>(distr: D, seed: u64, expected: &[f64]) {
+    let mut rng = ::test::rng(seed);
+    for &e in expected.iter() {
+        let x = distr.sample(&mut rng);
+        assert!((x - e).abs() <= 1e-14 * e.abs(), "{} != {}", x, e);
+    }
+}
+
+#[test]
+fn binomial_stability() {
+    // One sequence per sampling method.
+    check_ints(Binomial::new(20, 0.3).unwrap(), 123, &[7, 6, 5, 5, 6, 9]);
+    check_ints(Binomial::from_ratio(20, 1, 3).unwrap(), 123, &[7, 6, 6, 5, 6, 10]);
+    check_ints(Binomial::new(20, 0.25).unwrap(), 123, &[2, 11, 4, 4, 9, 4]);
+    check_ints(Binomial::new(10_000, 0.4).unwrap(), 123,
+               &[4007, 4031, 4041, 3984, 3964, 4040]);
+    check_ints(Binomial::new(1000, 0.9).unwrap(), 123, &[902, 894, 888, 884, 901, 893]);
+}
+
+#[test]
+fn geometric_stability() {
+    check_ints(Geometric::new(0.1).unwrap(), 124, &[1, 3, 0, 4, 7, 6]);
+    check_ints(Geometric::new(0.7).unwrap(), 124, &[3, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn poisson_stability() {
+    check_ints(Poisson::new(3.0).unwrap(), 125, &[4, 0, 2, 1, 4, 3]);
+    check_ints(Poisson::new(200.0).unwrap(), 125, &[197, 192, 202, 182, 206, 198]);
+}
+
+#[test]
+fn negative_binomial_stability() {
+    check_ints(NegativeBinomial::new(3.0, 0.25).unwrap(), 126, &[21, 7, 7, 4, 8, 10]);
+}
+
+#[test]
+fn uniform_stability() {
+    check_ints(Uniform::new(7u64, 1000), 127, &[840, 347, 570, 329, 573, 970]);
+    check_floats(Uniform::new(-2.0, 5.0), 129,
+                 &[-0.6961582985131798, 2.6940177311524094, 2.7349269899074287,
+                   -0.28795373251413503]);
+}
+
+#[test]
+fn bernoulli_stability() {
+    let mut rng = ::test::rng(128);
+    let distr = Bernoulli::new(0.3).unwrap();
+    let values: Vec<bool> = (0..12).map(|_| rng.sample(distr)).collect();
+    assert_eq!(values, [true, false, false, false, true, false,
+                        false, true, false, true, true, false]);
+}
+
+#[test]
+fn normal_stability() {
+    check_floats(Normal::new(2.0, 3.0).unwrap(), 130,
+                 &[2.9538811215871847, 0.646255152940963, 2.1545847250742103,
+                   1.4024399029903116]);
+}
+
+#[test]
+fn exp_stability() {
+    check_floats(Exp::new(1.5).unwrap(), 131,
+                 &[0.8369616465568095, 2.1954036387349607, 0.7837680125460814,
+                   0.0914837466167702]);
+}
+
+#[test]
+fn gamma_stability() {
+    // One sequence per sampling method.
+    check_floats(Gamma::new(2.5, 2.0).unwrap(), 132,
+                 &[5.049681669136048, 10.325475923624992, 5.368542952263201,
+                   1.0254167050004042]);
+    check_floats(Gamma::new(0.5, 2.0).unwrap(), 132,
+                 &[0.5159013742774419, 0.3696619153984891, 0.027702769954980513,
+                   0.16702712025984362]);
+    check_floats(Gamma::new(1.0, 2.0).unwrap(), 132,
+                 &[0.5108940723784049, 3.351080911179419, 4.95859596791421,
+                   1.083485564772803]);
+    check_floats(Beta::new(2.0, 3.0).unwrap(), 137,
+                 &[0.18402559346521571, 0.49887391576171497, 0.5170248017890771,
+                   0.18828530017169315]);
+}
+
+#[test]
+fn cauchy_stability() {
+    check_floats(Cauchy::new(10.0, 7.0).unwrap(), 133,
+                 &[6.3614786894568836, 8.832356933389473, 13.045951366430382,
+                   9.9075762595842]);
+}
+
+#[test]
+fn pareto_stability() {
+    check_floats(Pareto::new(1.0, 2.0).unwrap(), 134,
+                 &[1.0849495442875072, 2.22043277242833, 1.0221540786179413,
+                   2.460962398665947]);
+}
+
+#[test]
+fn weibull_stability() {
+    check_floats(Weibull::new(1.0, 1.5).unwrap(), 135,
+                 &[1.5187975425407976, 0.9145931863414356, 1.0812052443410785,
+                   0.18575242112522497]);
+}
+
+#[test]
+fn triangular_stability() {
+    check_floats(Triangular::new(0.0, 5.0, 1.0).unwrap(), 136,
+                 &[3.13325236800504, 0.7981849883006775, 0.41652532579850243,
+                   1.2918495398500878]);
+}
@@ -9,7 +9,8 @@
 //! The Weibull distribution.
 
 use Rng;
-use distributions::{Distribution, OpenClosed01};
+use distributions::{Distribution, AntitheticDistribution, InverseCdf, DistError, Open01};
+use std::fmt;
 
 /// Samples floating-point numbers according to the Weibull distribution
 ///
@@ -18,11 +19,12 @@ use distributions::{Distribution, OpenClosed01};
 /// use rand::prelude::*;
 /// use rand::distributions::Weibull;
 ///
-/// let val: f64 = SmallRng::from_entropy().sample(Weibull::new(1., 10.));
+/// let val: f64 = SmallRng::from_entropy().sample(Weibull::new(1., 10.).unwrap());
 /// println!("{}", val);
 /// ```
 #[derive(Clone, Copy, Debug)]
 pub struct Weibull {
+    shape: f64,
     inv_shape: f64,
     scale: f64,
 }
@@ -30,42 +32,103 @@ pub struct Weibull {
 impl Weibull {
     /// Construct a new `Weibull` distribution with given `scale` and `shape`.
     ///
+    /// # Errors
+    ///
+    /// `scale` and `shape` have to be non-zero and positive.
+    pub fn new(scale: f64, shape: f64) -> Result<Weibull, DistError> {
+        if !(scale > 0.) {
+            return Err(DistError::ScaleNotPositive);
+        }
+        if !(shape > 0.) {
+            return Err(DistError::ShapeNotPositive);
+        }
+        Ok(Weibull { shape, inv_shape: 1./shape, scale })
+    }
+
+    /// Construct a new `Weibull` distribution, like [`new`](#method.new).
+    ///
     /// # Panics
     ///
     /// `scale` and `shape` have to be non-zero and positive.
-    pub fn new(scale: f64, shape: f64) -> Weibull {
-        assert!((scale > 0.) & (shape > 0.));
-        Weibull { inv_shape: 1./shape, scale }
+    pub fn new_unchecked(scale: f64, shape: f64) -> Weibull {
+        Weibull::new(scale, shape).expect("Weibull::new_unchecked called with invalid parameters")
     }
 }
 
 impl Distribution<f64> for Weibull {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        let x: f64 = rng.sample(OpenClosed01);
-        self.scale * (-x.ln()).powf(self.inv_shape)
+        self.inverse_cdf(rng.sample(Open01))
+    }
+}
+
+impl InverseCdf for Weibull {
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.scale * (-(-p).ln_1p()).powf(self.inv_shape)
+    }
+}
+
+impl AntitheticDistribution for Weibull {
+    fn sample_antithetic<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let x: f64 = rng.sample(Open01);
+        (self.scale * (-x.ln()).powf(self.inv_shape),
+         self.scale * (-(-x).ln_1p()).powf(self.inv_shape))
+    }
+}
+
+impl fmt::Display for Weibull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Weibull(scale={}, shape={})", self.scale, self.shape)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use distributions::Distribution;
+    use distributions::{Distribution, DistError};
     use super::Weibull;
 
     #[test]
-    #[should_panic]
     fn invalid() {
-        Weibull::new(0., 0.);
+        assert_eq!(Weibull::new(0., 1.).unwrap_err(), DistError::ScaleNotPositive);
+        assert_eq!(Weibull::new(1., 0.).unwrap_err(), DistError::ShapeNotPositive);
     }
 
     #[test]
     fn sample() {
         let scale = 1.0;
         let shape = 2.0;
-        let d = Weibull::new(scale, shape);
+        let d = Weibull::new(scale, shape).unwrap();
         let mut rng = ::test::rng(1);
         for _ in 0..1000 {
             let r = d.sample(&mut rng);
             assert!(r >= 0.);
         }
     }
+
+    #[test]
+    fn test_weibull_antithetic() {
+        use distributions::AntitheticDistribution;
+        let d = Weibull::new(1.0, 2.0).unwrap();
+        let mut rng = ::test::rng(183);
+        let (xs, ys): (Vec<f64>, Vec<f64>) =
+            (0..100_000).map(|_| d.sample_antithetic(&mut rng)).unzip();
+        // Mean `Γ(1.5)` and variance `1 - π/4`.
+        let (mean, var) = (0.886226925452758, 1.0 - ::std::f64::consts::PI / 4.0);
+        ::test::assert_moments(&xs, mean, var, 0.02);
+        ::test::assert_moments(&ys, mean, var, 0.02);
+        assert!(::test::correlation(&xs, &ys) < -0.5);
+    }
+
+    #[test]
+    fn test_weibull_inverse_cdf() {
+        use distributions::InverseCdf;
+        let d = Weibull::new(1.5, 2.0).unwrap();
+        assert_eq!(d.inverse_cdf(0.0), 0.0);
+        assert!((d.inverse_cdf(0.5) - 1.5 * 2f64.ln().sqrt()).abs() < 1e-12);
+
+        let mut rng = ::test::rng(197);
+        let samples: Vec<f64> = (0..100_000).map(|_| d.sample(&mut rng)).collect();
+        // Mean `1.5 Γ(1.5)` and variance `1.5^2 (1 - π/4)`.
+        ::test::assert_moments(&samples, 1.5 * 0.886226925452758,
+                               2.25 * (1.0 - ::std::f64::consts::PI / 4.0), 0.02);
+    }
 }
@@ -24,20 +24,31 @@ use core::fmt;
 /// value of the element. The weights can use any type `X` for which an
 /// implementation of [`Uniform<X>`] exists.
 ///
+/// Weights can be changed after construction with
+/// [`add_weight`](#method.add_weight).
+///
+/// With the `serde1` feature, a `WeightedIndex` can be serialized together
+/// with its table of partial sums, and deserialized without rebuilding it;
+/// this is useful for huge numbers of weights. Deserialization checks in
+/// `O(N)` that the table is monotone, but not that its sums are exact.
+///
 /// # Performance
 ///
-/// A `WeightedIndex<X>` contains a `Vec<X>` and a [`Uniform<X>`] and so its
+/// A `WeightedIndex<X>` contains two `Vec<X>` and a [`Uniform<X>`] and so its
 /// size is the sum of the size of those objects, possibly plus some alignment.
 ///
-/// Creating a `WeightedIndex<X>` will allocate enough space to hold `N - 1`
-/// weights of type `X`, where `N` is the number of weights. However, since
-/// `Vec` doesn't guarantee a particular growth strategy, additional memory
-/// might be allocated but not used. Since the `WeightedIndex` object also
-/// contains, this might cause additional allocations, though for primitive
-/// types, ['Uniform<X>`] doesn't allocate any memory.
+/// Creating a `WeightedIndex<X>` will allocate enough space to hold `2 N`
+/// weights of type `X`, where `N` is the number of weights: the weights
+/// themselves and a Fenwick tree (binary indexed tree) of their partial sums.
+/// However, since `Vec` doesn't guarantee a particular growth strategy,
+/// additional memory might be allocated but not used. Since the
+/// `WeightedIndex` object also contains, this might cause additional
+/// allocations, though for primitive types, ['Uniform<X>`] doesn't allocate
+/// any memory.
 ///
 /// Time complexity of sampling from `WeightedIndex` is `O(log N)` where
-/// `N` is the number of weights.
+/// `N` is the number of weights, and so is the time complexity of updating
+/// a weight.
 ///
 /// Sampling from `WeightedIndex` will result in a single call to
 /// [`Uniform<X>::sample`], which typically will request a single value from
@@ -72,7 +83,12 @@ use core::fmt;
 /// [`RngCore`]: ../trait.RngCore.html
 #[derive(Debug, Clone)]
 pub struct WeightedIndex<X: SampleUniform + PartialOrd> {
-    cumulative_weights: Vec<X>,
+    weights: Vec<X>,
+    /// Fenwick tree: element `i` holds the sum of the weights in
+    /// `(i + 1 - lowbit(i + 1), i + 1)`, where `lowbit(k) = k & -k`.
+    tree: Vec<X>,
+    /// Number of weights which are not zero.
+    nonzero: usize,
     weight_distribution: X::Sampler,
 }
 
@@ -84,6 +100,10 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
     /// Returns an error if the iterator is empty, if any weight is `< 0`, or
     /// if its total value is 0.
     ///
+    /// The total of the weights must fit in `X`; integer weights which may
+    /// overflow it can be accumulated in a wider type, for instance with
+    /// [`from_u64`](#method.from_u64).
+    ///
     /// [`Distribution`]: trait.Distribution.html
     /// [`Uniform<X>`]: struct.Uniform.html
     pub fn new<I>(weights: I) -> Result<WeightedIndex<X>, WeightedError>
@@ -92,46 +112,1052 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
               X: for<'a> ::core::ops::AddAssign<&'a X> +
                  Clone +
                  Default {
-        let mut iter = weights.into_iter();
-        let mut total_weight: X = iter.next()
-                                      .ok_or(WeightedError::NoItem)?
-                                      .borrow()
-                                      .clone();
-
+        let iter = weights.into_iter();
         let zero = <X as Default>::default();
-        if total_weight < zero {
-            return Err(WeightedError::NegativeWeight);
-        }
-
         let mut weights = Vec::<X>::with_capacity(iter.size_hint().0);
+        let mut nonzero = 0;
         for w in iter {
             if *w.borrow() < zero {
                 return Err(WeightedError::NegativeWeight);
             }
-            weights.push(total_weight.clone());
-            total_weight += w.borrow();
+            if *w.borrow() != zero {
+                nonzero += 1;
+            }
+            weights.push(w.borrow().clone());
         }
 
-        if total_weight == zero {
+        if weights.is_empty() {
+            return Err(WeightedError::NoItem);
+        }
+        if nonzero == 0 {
+            return Err(WeightedError::AllWeightsZero);
+        }
+
+        let tree = build_tree(&weights);
+        let total = total_weight(&tree);
+        Ok(WeightedIndex {
+            weights,
+            tree,
+            nonzero,
+            weight_distribution: X::Sampler::new(zero, total),
+        })
+    }
+
+    /// Add `delta` to the weight of item `index`.
+    ///
+    /// This takes `O(log N)` time, where `N` is the number of weights. For
+    /// floating-point weights, rounding errors accumulate over many updates.
+    ///
+    /// Returns an error, leaving the distribution unchanged, if the new
+    /// weight would be `< 0` or if all weights would be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{WeightedIndex, Distribution};
+    ///
+    /// let mut dist = WeightedIndex::new(&[1, 0]).unwrap();
+    /// dist.add_weight(0, -1).unwrap_err();
+    /// dist.add_weight(1, 5).unwrap();
+    /// dist.add_weight(0, -1).unwrap();
+    /// assert_eq!(dist.sample(&mut rand::thread_rng()), 1);
+    /// ```
+    pub fn add_weight(&mut self, index: usize, delta: X) -> Result<(), WeightedError>
+        where X: for<'a> ::core::ops::AddAssign<&'a X> +
+                 Clone +
+                 Default {
+        let zero = <X as Default>::default();
+        let mut weight = self.weights[index].clone();
+        weight += &delta;
+        if weight < zero {
+            return Err(WeightedError::NegativeWeight);
+        }
+        let mut nonzero = self.nonzero;
+        if self.weights[index] != zero { nonzero -= 1; }
+        if weight != zero { nonzero += 1; }
+        if nonzero == 0 {
             return Err(WeightedError::AllWeightsZero);
         }
-        let distr = X::Sampler::new(zero, total_weight);
 
-        Ok(WeightedIndex { cumulative_weights: weights, weight_distribution: distr })
+        self.weights[index] = weight;
+        self.nonzero = nonzero;
+        let mut i = index + 1;
+        while i <= self.tree.len() {
+            self.tree[i - 1] += &delta;
+            i += lowbit(i);
+        }
+        self.update_sampler();
+        Ok(())
+    }
+
+    /// Rebuild the sampler from the total of the tree, after an update.
+    ///
+    /// With floating-point weights the partial sums may cancel to a total
+    /// which is not positive, although some weight is still nonzero. Then
+    /// the tree is rebuilt from the weights, discarding the rounding errors.
+    fn update_sampler(&mut self)
+        where X: for<'a> ::core::ops::AddAssign<&'a X> + Clone + Default {
+        let zero = <X as Default>::default();
+        let total = total_weight(&self.tree);
+        if total > zero {
+            self.weight_distribution = X::Sampler::new(zero, total);
+        } else {
+            self.rebuild_tree();
+        }
+    }
+
+    /// Rebuild the tree and the sampler from the weights, in `O(N)` time.
+    fn rebuild_tree(&mut self)
+        where X: for<'a> ::core::ops::AddAssign<&'a X> + Clone + Default {
+        self.tree = build_tree(&self.weights);
+        let zero = <X as Default>::default();
+        self.weight_distribution = X::Sampler::new(zero, total_weight(&self.tree));
+    }
+
+    /// Replace the weight of `index`, without the checks of `add_weight`.
+    ///
+    /// The sampler is only updated if some weight is nonzero, so this may
+    /// leave a distribution which must not be sampled.
+    fn replace_weight(&mut self, index: usize, weight: X)
+        where X: for<'a> ::core::ops::AddAssign<&'a X> +
+                 for<'a> ::core::ops::SubAssign<&'a X> +
+                 Clone +
+                 Default {
+        let zero = <X as Default>::default();
+        let old = ::core::mem::replace(&mut self.weights[index], weight.clone());
+        if old != zero { self.nonzero -= 1; }
+        if weight != zero { self.nonzero += 1; }
+        let mut i = index + 1;
+        while i <= self.tree.len() {
+            self.tree[i - 1] -= &old;
+            self.tree[i - 1] += &weight;
+            i += lowbit(i);
+        }
+        if self.nonzero > 0 {
+            self.update_sampler();
+        }
+    }
+
+    /// Sample an index, and return it together with the corresponding
+    /// element of `items`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `items` differs from the number of weights.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let items = ["common", "rare"];
+    /// let dist = WeightedIndex::new(&[9, 1]).unwrap();
+    /// let (i, item) = dist.sample_with(&mut rand::thread_rng(), &items);
+    /// assert_eq!(items[i], *item);
+    /// ```
+    pub fn sample_with<'a, T, R: Rng + ?Sized>(&self, rng: &mut R, items: &'a [T])
+        -> (usize, &'a T)
+        where X: for<'b> ::core::ops::AddAssign<&'b X> +
+                 Clone +
+                 Default {
+        assert_eq!(items.len(), self.weights.len(),
+                   "WeightedIndex::sample_with: items and weights differ in length");
+        let i = self.sample(rng);
+        (i, &items[i])
+    }
+
+    /// Sample `n` indices independently, and return the number of times each
+    /// index was chosen.
+    ///
+    /// The result has one count for each weight, and the counts sum to `n`;
+    /// they have a multinomial distribution. This takes `O(n log N)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[1, 2, 7]).unwrap();
+    /// let counts = dist.sample_counts(&mut rand::thread_rng(), 100);
+    /// assert_eq!(counts.iter().sum::<u64>(), 100);
+    /// ```
+    pub fn sample_counts<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<u64>
+        where X: for<'a> ::core::ops::AddAssign<&'a X> +
+                 Clone +
+                 Default {
+        let mut counts = vec![0; self.weights.len()];
+        self.sample_counts_into(rng, n, &mut counts);
+        counts
+    }
+
+    /// Sample `n` indices independently, and write the number of times each
+    /// index was chosen into `out`.
+    ///
+    /// This is [`sample_counts`](#method.sample_counts) without allocating:
+    /// `out` is zeroed first, so one buffer can be reused across calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` and the weights differ in length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[1, 2, 7]).unwrap();
+    /// let mut counts = [0; 3];
+    /// for _ in 0..10 {
+    ///     dist.sample_counts_into(&mut rand::thread_rng(), 100, &mut counts);
+    ///     assert_eq!(counts.iter().sum::<u64>(), 100);
+    /// }
+    /// ```
+    pub fn sample_counts_into<R: Rng + ?Sized>(&self, rng: &mut R, n: usize, out: &mut [u64])
+        where X: for<'a> ::core::ops::AddAssign<&'a X> +
+                 Clone +
+                 Default {
+        assert_eq!(out.len(), self.weights.len(),
+                   "WeightedIndex::sample_counts_into: out and weights differ in length");
+        for count in out.iter_mut() {
+            *count = 0;
+        }
+        for _ in 0..n {
+            out[self.sample(rng)] += 1;
+        }
+    }
+
+    /// Sample `n` indices independently, and return the index which was
+    /// chosen most often.
+    ///
+    /// This simulates the draws exactly, as the argmax of
+    /// [`sample_counts`](#method.sample_counts); ties are broken in favour of
+    /// the lowest index. It is not the Gumbel-max trick, which is a single
+    /// draw from the weights. For large `n` the result is the highest-weight
+    /// index with high probability. This takes `O(n log N)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[1, 2, 7]).unwrap();
+    /// let winner = dist.sample_argmax_of(&mut rand::thread_rng(), 100);
+    /// println!("{} is very likely to be 2", winner);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `n == 0`.
+    pub fn sample_argmax_of<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> usize
+        where X: for<'a> ::core::ops::AddAssign<&'a X> +
+                 Clone +
+                 Default {
+        assert!(n > 0, "WeightedIndex::sample_argmax_of called with n = 0");
+        let counts = self.sample_counts(rng, n);
+        let mut best = 0;
+        for (i, &count) in counts.iter().enumerate() {
+            if count > counts[best] {
+                best = i;
+            }
+        }
+        best
+    }
+
+    /// The mode: the index of the largest weight, the most likely result of
+    /// sampling.
+    ///
+    /// If several weights are equal to the largest one, one of their indices
+    /// is returned, each with the same probability, instead of always the
+    /// first one. Random numbers are only used in case of ties. This takes
+    /// `O(N)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[3, 1, 3]).unwrap();
+    /// let mode = dist.mode(&mut rand::thread_rng());
+    /// assert!(mode == 0 || mode == 2);
+    /// ```
+    pub fn mode<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let mut best = 0;
+        // Number of indices of the largest weight so far; each new one
+        // replaces the choice with probability `1 / ties`.
+        let mut ties = 1u64;
+        for (i, w) in self.weights.iter().enumerate().skip(1) {
+            if *w > self.weights[best] {
+                best = i;
+                ties = 1;
+            } else if *w == self.weights[best] {
+                ties += 1;
+                if rng.gen_range(0, ties) == 0 {
+                    best = i;
+                }
+            }
+        }
+        best
+    }
+
+    /// The index of the largest weight, for greedy selection without
+    /// sampling.
+    ///
+    /// If several weights are equal to the largest one, the lowest of their
+    /// indices is returned; see [`mode`](#method.mode) to break ties at
+    /// random instead. This takes `O(N)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[3, 1, 4, 4]).unwrap();
+    /// assert_eq!(dist.most_probable(), 2);
+    /// ```
+    pub fn most_probable(&self) -> usize {
+        let mut best = 0;
+        for (i, w) in self.weights.iter().enumerate().skip(1) {
+            if *w > self.weights[best] {
+                best = i;
+            }
+        }
+        best
+    }
+
+    /// The Shannon entropy of the distribution of indices, in nats.
+    ///
+    /// This is `ln(N)` for `N` equal weights, and approaches zero as the
+    /// weights become concentrated on a single index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[1.0, 1.0]).unwrap();
+    /// assert!((dist.entropy() - 2f64.ln()).abs() < 1e-12);
+    /// ```
+    #[cfg(feature="std")]
+    pub fn entropy(&self) -> f64
+        where X: Into<f64> + Clone
+    {
+        let total: f64 = self.weights.iter().map(|w| w.clone().into()).sum();
+        self.weights.iter().map(|w| {
+            let p = w.clone().into() / total;
+            if p > 0.0 { -p * p.ln() } else { 0.0 }
+        }).sum()
+    }
+
+    /// The natural logarithm of the probability of sampling `index`.
+    ///
+    /// This is `ln(w[index] / sum(w))`, and `-∞` for an index of zero weight.
+    /// It is computed from the stored weights in `O(N)` time, for example to
+    /// weigh the gradient of a sampled choice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[1.0, 3.0, 0.0]).unwrap();
+    /// assert!((dist.ln_prob(1) - 0.75f64.ln()).abs() < 1e-12);
+    /// assert_eq!(dist.ln_prob(2), std::f64::NEG_INFINITY);
+    /// ```
+    #[cfg(feature="std")]
+    pub fn ln_prob(&self, index: usize) -> f64
+        where X: Into<f64> + Clone
+    {
+        let w: f64 = self.weights[index].clone().into();
+        if w == 0.0 {
+            return ::std::f64::NEG_INFINITY;
+        }
+        let total: f64 = self.weights.iter().map(|w| w.clone().into()).sum();
+        (w / total).ln()
+    }
+
+    /// Return all indices in a random order, as chosen by weighted sampling
+    /// without replacement: the first index is sampled according to the
+    /// weights, the next one from the remaining indices, and so on.
+    ///
+    /// High-weight indices thus tend to come first. This uses the keys of
+    /// Efraimidis and Spirakis: index `i` is assigned the key `u^(1/w[i])`
+    /// for a uniform `u`, and the indices are sorted by decreasing key.
+    /// Indices of zero weight, which could never be sampled, come last in a
+    /// uniformly random order. This takes `O(N log N)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[1.0, 10.0, 0.0, 3.0]).unwrap();
+    /// let order = dist.weighted_permutation(&mut rand::thread_rng());
+    /// assert_eq!(order.len(), 4);
+    /// assert_eq!(order[3], 2);
+    /// ```
+    #[cfg(feature="std")]
+    pub fn weighted_permutation<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<usize>
+        where X: Into<f64> + Clone
+    {
+        use distributions::Open01;
+        use seq::SliceRandom;
+
+        let mut keyed = Vec::with_capacity(self.nonzero);
+        let mut zero = Vec::new();
+        for (i, w) in self.weights.iter().enumerate() {
+            let w: f64 = w.clone().into();
+            if w > 0.0 {
+                // `ln(u^(1/w)) = ln(u) / w` orders the same way, but does not
+                // underflow for small weights.
+                let u: f64 = rng.sample(Open01);
+                keyed.push((u.ln() / w, i));
+            } else {
+                zero.push(i);
+            }
+        }
+        // Keys are never NaN.
+        keyed.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+        zero.shuffle(rng);
+        keyed.into_iter().map(|(_, i)| i).chain(zero).collect()
+    }
+}
+
+#[cfg(feature="std")]
+impl WeightedIndex<f64> {
+    /// Creates a new `WeightedIndex` from unnormalized log-probabilities
+    /// (logits): item `i` is picked with probability proportional to
+    /// `exp(logits[i])`, i.e. according to the softmax of `logits`.
+    ///
+    /// The maximum logit is subtracted before exponentiating (the log-sum-exp
+    /// trick), so very large or very negative logits neither overflow nor
+    /// all underflow to zero. A logit of negative infinity gives a weight of
+    /// zero.
+    ///
+    /// Returns an error if `logits` is empty, if any logit is NaN or
+    /// positive infinity, or if all logits are negative infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{WeightedIndex, Distribution};
+    ///
+    /// let dist = WeightedIndex::from_logits(&[1000.0, 999.0, -1e10]).unwrap();
+    /// assert!(dist.sample(&mut rand::thread_rng()) < 2);
+    /// ```
+    pub fn from_logits(logits: &[f64]) -> Result<WeightedIndex<f64>, WeightedError> {
+        if logits.iter().any(|&x| x.is_nan() || x == ::std::f64::INFINITY) {
+            return Err(WeightedError::InvalidWeight);
+        }
+        let max = logits.iter().cloned().fold(::std::f64::NEG_INFINITY, f64::max);
+        if max == ::std::f64::NEG_INFINITY {
+            // Empty, or all weights are zero.
+            return WeightedIndex::new(logits.iter().map(|_| 0.0));
+        }
+        WeightedIndex::new(logits.iter().map(|&x| (x - max).exp()))
+    }
+
+    /// Creates a new `WeightedIndex` from logits scaled by a `temperature`:
+    /// item `i` is picked with probability proportional to
+    /// `exp(logits[i] / temperature)`.
+    ///
+    /// A temperature of `1` is the same as [`from_logits`]. As the
+    /// temperature approaches zero the distribution concentrates on the
+    /// largest logits, and as it grows the distribution approaches the
+    /// uniform one.
+    ///
+    /// Returns an error if `temperature` is not strictly positive and finite,
+    /// or for the `logits` rejected by [`from_logits`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{WeightedIndex, Distribution};
+    ///
+    /// let dist = WeightedIndex::with_temperature(&[1.0, 3.0, 2.0], 0.01).unwrap();
+    /// assert_eq!(dist.sample(&mut rand::thread_rng()), 1);
+    /// ```
+    ///
+    /// [`from_logits`]: #method.from_logits
+    pub fn with_temperature(logits: &[f64], temperature: f64)
+        -> Result<WeightedIndex<f64>, WeightedError>
+    {
+        if !(temperature > 0.0 && temperature.is_finite()) {
+            return Err(WeightedError::InvalidTemperature);
+        }
+        let scaled: Vec<f64> = logits.iter().map(|&x| x / temperature).collect();
+        WeightedIndex::from_logits(&scaled)
+    }
+
+    /// Creates a new `WeightedIndex` interpolating between the weights `a`
+    /// and `b`: item `i` has the weight `(1 - t) a[i] + t b[i]`.
+    ///
+    /// A blend factor `t` of `0` gives the distribution of `a`, and `1` that
+    /// of `b`. If both weight vectors have the same sum, such as when they are
+    /// probabilities, the probability of each item is interpolated linearly,
+    /// for example to anneal from one distribution to the other.
+    ///
+    /// Returns an error if `a` and `b` differ in length, if `t` is not in
+    /// `[0, 1]`, or for the blended weights rejected by [`new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{WeightedIndex, Distribution};
+    ///
+    /// let explore = [0.25, 0.25, 0.25, 0.25];
+    /// let exploit = [0.0, 0.0, 1.0, 0.0];
+    /// for step in 0..11 {
+    ///     let dist = WeightedIndex::blend(&explore, &exploit, step as f64 / 10.0).unwrap();
+    ///     println!("step {}: action {}", step, dist.sample(&mut rand::thread_rng()));
+    /// }
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    pub fn blend(a: &[f64], b: &[f64], t: f64) -> Result<WeightedIndex<f64>, WeightedError> {
+        if a.len() != b.len() {
+            return Err(WeightedError::LengthMismatch);
+        }
+        if !(t >= 0.0 && t <= 1.0) {
+            return Err(WeightedError::InvalidBlendFactor);
+        }
+        WeightedIndex::new(a.iter().zip(b.iter()).map(|(&x, &y)| (1.0 - t) * x + t * y))
+    }
+
+    /// Multiply every weight by `factor`, for exponential forgetting of old
+    /// weights.
+    ///
+    /// The probabilities are unchanged, but weights added afterwards with
+    /// [`add_weight`] count relatively more; see
+    /// [`decay_and_add`](#method.decay_and_add). This takes `O(N)` time.
+    /// Weights that become too small underflow to zero.
+    ///
+    /// Returns an error, leaving the distribution unchanged, unless
+    /// `0 < factor <= 1`, or if all weights would underflow to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let mut dist = WeightedIndex::new(&[3.0, 1.0]).unwrap();
+    /// dist.decay_all(0.5).unwrap();
+    /// assert!((dist.ln_prob(0) - 0.75f64.ln()).abs() < 1e-12);
+    /// ```
+    ///
+    /// [`add_weight`]: #method.add_weight
+    pub fn decay_all(&mut self, factor: f64) -> Result<(), WeightedError> {
+        if !(factor > 0.0 && factor <= 1.0) {
+            return Err(WeightedError::InvalidDecayFactor);
+        }
+        if self.weights.iter().all(|&w| w * factor == 0.0) {
+            return Err(WeightedError::AllWeightsZero);
+        }
+        self.scale_weights(factor);
+        Ok(())
+    }
+
+    /// Multiply every weight by `factor`, then add `delta` to the weight of
+    /// item `index`.
+    ///
+    /// This is one step of an exponentially weighted count: with a `factor`
+    /// of `1 - α`, the weight of an observation made `t` steps ago is
+    /// multiplied by `(1 - α)^t`. This takes `O(N)` time.
+    ///
+    /// Returns an error, leaving the distribution unchanged, unless
+    /// `0 < factor <= 1`, if the new weight of `index` would be `< 0`, or if
+    /// all weights would be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// // Recently observed events are the most likely.
+    /// let mut recent = WeightedIndex::new(&[1.0, 1.0, 1.0]).unwrap();
+    /// for &event in [0, 0, 2, 1, 1, 1].iter() {
+    ///     recent.decay_and_add(0.5, event, 1.0).unwrap();
+    /// }
+    /// assert!(recent.ln_prob(1) > recent.ln_prob(0));
+    /// ```
+    pub fn decay_and_add(&mut self, factor: f64, index: usize, delta: f64)
+        -> Result<(), WeightedError>
+    {
+        if !(factor > 0.0 && factor <= 1.0) {
+            return Err(WeightedError::InvalidDecayFactor);
+        }
+        let weight = self.weights[index] * factor + delta;
+        if !(weight >= 0.0) {
+            return Err(WeightedError::NegativeWeight);
+        }
+        let others_zero = self.weights.iter().enumerate()
+            .all(|(i, &w)| i == index || w * factor == 0.0);
+        if weight == 0.0 && others_zero {
+            return Err(WeightedError::AllWeightsZero);
+        }
+        self.scale_weights(factor);
+        self.replace_weight(index, weight);
+        Ok(())
+    }
+
+    /// Multiply the weights and partial sums by `factor`.
+    fn scale_weights(&mut self, factor: f64) {
+        for w in self.weights.iter_mut().chain(self.tree.iter_mut()) {
+            *w *= factor;
+        }
+        self.nonzero = self.weights.iter().filter(|&&w| w != 0.0).count();
+        if self.nonzero > 0 {
+            let total = total_weight(&self.tree);
+            self.weight_distribution = <f64 as SampleUniform>::Sampler::new(0.0, total);
+        }
+    }
+}
+
+#[cfg(rust_1_26)]
+impl WeightedIndex<u128> {
+    /// Creates a new `WeightedIndex` from `u64` weights, accumulated as
+    /// `u128` so that their total can't overflow, however large the weights.
+    ///
+    /// Returns an error if the iterator is empty or if all weights are 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::{WeightedIndex, Distribution};
+    ///
+    /// let weights = [u64::max_value(), u64::max_value(), 0];
+    /// let dist = WeightedIndex::from_u64(&weights).unwrap();
+    /// assert!(dist.sample(&mut rand::thread_rng()) < 2);
+    /// ```
+    pub fn from_u64<I>(weights: I) -> Result<WeightedIndex<u128>, WeightedError>
+        where I: IntoIterator,
+              I::Item: SampleBorrow<u64> {
+        WeightedIndex::new(weights.into_iter().map(|w| *w.borrow() as u128))
     }
 }
 
 impl<X> Distribution<usize> for WeightedIndex<X> where
-    X: SampleUniform + PartialOrd {
+    X: SampleUniform + PartialOrd +
+       for<'a> ::core::ops::AddAssign<&'a X> +
+       Clone +
+       Default {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
-        use ::core::cmp::Ordering;
         let chosen_weight = self.weight_distribution.sample(rng);
-        // Find the first item which has a weight *higher* than the chosen weight.
-        self.cumulative_weights.binary_search_by(
-            |w| if *w <= chosen_weight { Ordering::Less } else { Ordering::Greater }).unwrap_err()
+        // Find the first item whose cumulative weight is *higher* than the
+        // chosen weight, by descending the tree: `pos` is the largest number
+        // of items found so far whose total is at most the chosen weight.
+        let n = self.tree.len();
+        let mut pos = 0;
+        let mut total = <X as Default>::default();
+        let mut step = (n + 1).next_power_of_two() / 2;
+        while step > 0 {
+            if pos + step <= n {
+                let mut next = total.clone();
+                next += &self.tree[pos + step - 1];
+                if next <= chosen_weight {
+                    pos += step;
+                    total = next;
+                }
+            }
+            step /= 2;
+        }
+        // The descent adds up the tree nodes in a different order than they
+        // were built in, so rounding may land past the end or on an item of
+        // zero weight. Move on to the next item with a weight, or back to the
+        // previous one if there is none.
+        let zero = <X as Default>::default();
+        let mut pos = pos.min(n - 1);
+        while pos + 1 < n && self.weights[pos] == zero {
+            pos += 1;
+        }
+        while pos > 0 && self.weights[pos] == zero {
+            pos -= 1;
+        }
+        pos
+    }
+}
+
+#[cfg(feature="serde1")]
+impl<X> ::serde::Serialize for WeightedIndex<X>
+    where X: SampleUniform + PartialOrd + ::serde::Serialize {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WeightedIndex", 2)?;
+        state.serialize_field("weights", &self.weights)?;
+        state.serialize_field("tree", &self.tree)?;
+        state.end()
     }
 }
 
+/// The serialized form of a `WeightedIndex`.
+#[cfg(feature="serde1")]
+#[derive(Deserialize)]
+#[serde(rename = "WeightedIndex")]
+struct WeightedIndexTable<X> {
+    weights: Vec<X>,
+    tree: Vec<X>,
+}
+
+/// Weights which can be added without overflow, to check deserialized tables.
+///
+/// This trait is not exported, so it is implemented for the primitive weight
+/// types only.
+#[cfg(feature="serde1")]
+pub trait CheckedWeight: Sized {
+    /// `self + other`, or `None` if the sum overflows or is not finite.
+    fn checked_weight_add(&self, other: &Self) -> Option<Self>;
+}
+
+#[cfg(feature="serde1")]
+macro_rules! checked_weight_int_impl {
+    ($($ty:ty),*) => {$(
+        impl CheckedWeight for $ty {
+            #[inline]
+            fn checked_weight_add(&self, other: &$ty) -> Option<$ty> {
+                self.checked_add(*other)
+            }
+        }
+    )*}
+}
+
+#[cfg(feature="serde1")]
+checked_weight_int_impl! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+#[cfg(all(feature="serde1", rust_1_26))]
+checked_weight_int_impl! { i128, u128 }
+
+#[cfg(feature="serde1")]
+macro_rules! checked_weight_float_impl {
+    ($($ty:ty),*) => {$(
+        impl CheckedWeight for $ty {
+            #[inline]
+            fn checked_weight_add(&self, other: &$ty) -> Option<$ty> {
+                let sum = *self + *other;
+                if sum.is_finite() { Some(sum) } else { None }
+            }
+        }
+    )*}
+}
+
+#[cfg(feature="serde1")]
+checked_weight_float_impl! { f32, f64 }
+
+#[cfg(feature="serde1")]
+impl<'de, X> ::serde::Deserialize<'de> for WeightedIndex<X>
+    where X: SampleUniform + PartialOrd +
+             for<'a> ::core::ops::AddAssign<&'a X> +
+             CheckedWeight +
+             Clone +
+             Default +
+             ::serde::Deserialize<'de> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let WeightedIndexTable { weights, tree } = WeightedIndexTable::deserialize(deserializer)?;
+        if weights.len() != tree.len() {
+            return Err(D::Error::custom("WeightedIndex: table length does not match weights"));
+        }
+        let zero = <X as Default>::default();
+        let mut nonzero = 0;
+        for w in weights.iter() {
+            if !(*w >= zero) {
+                return Err(D::Error::custom(WeightedError::NegativeWeight));
+            }
+            if *w != zero {
+                nonzero += 1;
+            }
+        }
+        if weights.is_empty() {
+            return Err(D::Error::custom(WeightedError::NoItem));
+        }
+        if nonzero == 0 {
+            return Err(D::Error::custom(WeightedError::AllWeightsZero));
+        }
+        // Each node holds its own weight plus its children, so it must be at
+        // least its weight, and at most its parent.
+        for i in 1..(tree.len() + 1) {
+            let parent = i + lowbit(i);
+            if !(tree[i - 1] >= weights[i - 1])
+                || (parent <= tree.len() && !(tree[parent - 1] >= tree[i - 1])) {
+                return Err(D::Error::custom("WeightedIndex: table is not monotone"));
+            }
+            if zero.checked_weight_add(&tree[i - 1]).is_none() {
+                return Err(D::Error::custom("WeightedIndex: table is not finite"));
+            }
+        }
+
+        // Like `total_weight`, but checking for overflow.
+        let mut total = zero.clone();
+        let mut pos = 0;
+        let mut step = (tree.len() + 1).next_power_of_two() / 2;
+        while step > 0 {
+            if pos + step <= tree.len() {
+                pos += step;
+                total = total.checked_weight_add(&tree[pos - 1]).ok_or_else(||
+                    D::Error::custom("WeightedIndex: total weight overflows"))?;
+            }
+            step /= 2;
+        }
+        Ok(WeightedIndex {
+            weights,
+            tree,
+            nonzero,
+            weight_distribution: X::Sampler::new(zero, total),
+        })
+    }
+}
+
+/// A distribution over keys with weights given by a map, such as a
+/// `HashMap<K, f64>`.
+///
+/// Sampling returns a clone of one of the keys, picked with probability
+/// proportional to its weight. This is a [`WeightedIndex`] over the keys,
+/// for weights which are looked up by label rather than by index.
+///
+/// The keys are sorted when the distribution is created, so that the result
+/// does not depend on the iteration order of the map: sampling with a seeded
+/// RNG is reproducible regardless of the map's hashing.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rand::distributions::{Categorical, Distribution};
+///
+/// let mut weights = HashMap::new();
+/// weights.insert("rain", 0.3);
+/// weights.insert("sun", 0.6);
+/// weights.insert("snow", 0.1);
+/// let weather = Categorical::new(weights).unwrap();
+/// println!("Tomorrow brings {}", weather.sample(&mut rand::thread_rng()));
+/// ```
+///
+/// [`WeightedIndex`]: struct.WeightedIndex.html
+#[derive(Debug, Clone)]
+pub struct Categorical<K> {
+    /// The keys, sorted.
+    keys: Vec<K>,
+    index: WeightedIndex<f64>,
+}
+
+impl<K: Ord> Categorical<K> {
+    /// Creates a new `Categorical` from pairs of keys and weights. The weights
+    /// of repeated keys are added together.
+    ///
+    /// Returns an error if the iterator is empty, if any weight is `< 0`, NaN
+    /// or infinite, or if all weights are zero.
+    pub fn new<I>(weights: I) -> Result<Categorical<K>, WeightedError>
+        where I: IntoIterator<Item=(K, f64)>
+    {
+        let mut pairs: Vec<(K, f64)> = weights.into_iter().collect();
+        if pairs.iter().any(|&(_, w)| w.is_nan() || w.is_infinite()) {
+            return Err(WeightedError::InvalidWeight);
+        }
+        // Check each pair before merging, so that a negative weight is not
+        // hidden by a larger one of the same key.
+        if pairs.iter().any(|&(_, w)| w < 0.0) {
+            return Err(WeightedError::NegativeWeight);
+        }
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut keys = Vec::with_capacity(pairs.len());
+        let mut key_weights: Vec<f64> = Vec::with_capacity(pairs.len());
+        for (key, w) in pairs {
+            if keys.last() == Some(&key) {
+                *key_weights.last_mut().unwrap() += w;
+            } else {
+                keys.push(key);
+                key_weights.push(w);
+            }
+        }
+        let index = WeightedIndex::new(&key_weights)?;
+        Ok(Categorical { keys, index })
+    }
+
+    /// The keys, in sorted order.
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// The Shannon entropy of the distribution, in nats.
+    #[cfg(feature="std")]
+    pub fn entropy(&self) -> f64 {
+        self.index.entropy()
+    }
+}
+
+impl<K: Clone> Distribution<K> for Categorical<K> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> K {
+        self.keys[self.index.sample(rng)].clone()
+    }
+}
+
+/// A sampler of [`WeightedIndex`] indices which skips excluded ones, for
+/// sequential sampling without repeats.
+///
+/// Each call to [`sample_excluding`] picks an index with probability
+/// proportional to its weight among the indices which are not excluded, and
+/// then marks it as excluded. Sampling repeatedly with the same exclusion set
+/// thus draws the indices without replacement, like
+/// [`WeightedIndex::weighted_permutation`] but one at a time.
+///
+/// The exclusion set is a slice of `bool`s owned by the caller, which may
+/// also exclude or restore indices between calls. The sampler keeps a copy of
+/// the distribution with the weights of the excluded indices set to zero, and
+/// updates it in `O(log N)` time for each index whose exclusion changed;
+/// checking for changes takes `O(N)` time.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{CategoricalSampler, WeightedIndex};
+///
+/// let words = ["the", "a", "cat", "dog"];
+/// let mut sampler = CategoricalSampler::new(WeightedIndex::new(&[4, 3, 2, 1]).unwrap());
+/// let mut used = [false; 4];
+/// let mut rng = rand::thread_rng();
+/// while let Some(i) = sampler.sample_excluding(&mut rng, &mut used) {
+///     println!("{}", words[i]);
+/// }
+/// assert!(used.iter().all(|&u| u));
+/// ```
+///
+/// [`WeightedIndex`]: struct.WeightedIndex.html
+/// [`WeightedIndex::weighted_permutation`]: struct.WeightedIndex.html#method.weighted_permutation
+/// [`sample_excluding`]: #method.sample_excluding
+pub struct CategoricalSampler<X: SampleUniform + PartialOrd> {
+    /// The distribution over the indices which are not excluded.
+    index: WeightedIndex<X>,
+    /// The original weights.
+    weights: Vec<X>,
+    /// Which indices are excluded in `index`.
+    excluded: Vec<bool>,
+}
+
+// `derive` would not bound the sampler of `X`.
+impl<X: SampleUniform + PartialOrd> Clone for CategoricalSampler<X>
+    where WeightedIndex<X>: Clone, X: Clone
+{
+    fn clone(&self) -> CategoricalSampler<X> {
+        CategoricalSampler {
+            index: self.index.clone(),
+            weights: self.weights.clone(),
+            excluded: self.excluded.clone(),
+        }
+    }
+}
+
+impl<X: SampleUniform + PartialOrd> fmt::Debug for CategoricalSampler<X>
+    where WeightedIndex<X>: fmt::Debug, X: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CategoricalSampler")
+            .field("index", &self.index)
+            .field("weights", &self.weights)
+            .field("excluded", &self.excluded)
+            .finish()
+    }
+}
+
+impl<X> CategoricalSampler<X>
+    where X: SampleUniform + PartialOrd +
+             for<'a> ::core::ops::AddAssign<&'a X> +
+             for<'a> ::core::ops::SubAssign<&'a X> +
+             Clone +
+             Default
+{
+    /// Creates a new `CategoricalSampler` of the indices of `index`, with
+    /// their weights.
+    pub fn new(index: WeightedIndex<X>) -> CategoricalSampler<X> {
+        let weights = index.weights.clone();
+        let excluded = vec![false; weights.len()];
+        CategoricalSampler { index, weights, excluded }
+    }
+
+    /// Sample an index which is not `excluded`, and exclude it.
+    ///
+    /// The probability of each remaining index is its weight divided by the
+    /// sum of the remaining weights. Returns `None`, without using `rng`, if
+    /// the weights of all remaining indices are zero, or none remain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `excluded` and the weights differ in length.
+    pub fn sample_excluding<R: Rng + ?Sized>(&mut self, rng: &mut R, excluded: &mut [bool])
+        -> Option<usize>
+    {
+        assert_eq!(excluded.len(), self.weights.len(),
+                   "CategoricalSampler::sample_excluding: excluded and weights differ in length");
+        let zero = <X as Default>::default();
+        for (i, &exclude) in excluded.iter().enumerate() {
+            if exclude != self.excluded[i] {
+                let weight = if exclude { zero.clone() } else { self.weights[i].clone() };
+                self.index.replace_weight(i, weight);
+                self.excluded[i] = exclude;
+            }
+        }
+        if self.index.nonzero == 0 {
+            return None;
+        }
+        // Sampling skips the zero weights of the excluded indices, even when
+        // rounding in the partial sums lands on one.
+        let i = self.index.sample(rng);
+        debug_assert!(self.index.weights[i] != zero);
+        self.index.replace_weight(i, zero);
+        self.excluded[i] = true;
+        excluded[i] = true;
+        Some(i)
+    }
+}
+
+/// Build the Fenwick tree of `weights` in `O(N)`, by pushing each node into
+/// its parent.
+fn build_tree<X>(weights: &[X]) -> Vec<X>
+    where X: for<'a> ::core::ops::AddAssign<&'a X> + Clone {
+    let mut tree = weights.to_vec();
+    for i in 1..(tree.len() + 1) {
+        let parent = i + lowbit(i);
+        if parent <= tree.len() {
+            let node = tree[i - 1].clone();
+            tree[parent - 1] += &node;
+        }
+    }
+    tree
+}
+
+/// The sum of all weights in the Fenwick `tree`.
+fn total_weight<X>(tree: &[X]) -> X
+    where X: for<'a> ::core::ops::AddAssign<&'a X> + Default {
+    // Accumulate in the same order as `sample`, so that the sampled weight is
+    // always below the sum, even with rounding.
+    let n = tree.len();
+    let mut total = X::default();
+    let mut pos = 0;
+    let mut step = (n + 1).next_power_of_two() / 2;
+    while step > 0 {
+        if pos + step <= n {
+            pos += step;
+            total += &tree[pos - 1];
+        }
+        step /= 2;
+    }
+    total
+}
+
+/// The lowest set bit of `i`.
+#[inline]
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -184,12 +1210,676 @@ mod test {
             assert_eq!(WeightedIndex::new(&[0, 0, 0, 0, 10, 0]).unwrap().sample(&mut r), 4);
         }
 
+        let items = ['a', 'b', 'c', 'd'];
+        let distr = WeightedIndex::new(&[1, 0, 2, 3]).unwrap();
+        for _ in 0..100 {
+            let (i, item) = distr.sample_with(&mut r, &items);
+            assert_eq!(items[i], *item);
+            assert!(*item != 'b');
+        }
+
         assert_eq!(WeightedIndex::new(&[10][0..0]).unwrap_err(), WeightedError::NoItem);
         assert_eq!(WeightedIndex::new(&[0]).unwrap_err(), WeightedError::AllWeightsZero);
         assert_eq!(WeightedIndex::new(&[10, 20, -1, 30]).unwrap_err(), WeightedError::NegativeWeight);
         assert_eq!(WeightedIndex::new(&[-10, 20, 1, 30]).unwrap_err(), WeightedError::NegativeWeight);
         assert_eq!(WeightedIndex::new(&[-10]).unwrap_err(), WeightedError::NegativeWeight);
     }
+
+    #[test]
+    fn test_weightedindex_add_weight() {
+        let mut r = ::test::rng(702);
+        const N_REPS: u32 = 10_000;
+        let mut weights = [1u32, 2, 3, 0, 5, 6, 7, 1, 2, 3, 4, 5, 6];
+        let mut distr = WeightedIndex::new(&weights[..]).unwrap();
+
+        for &(i, delta) in &[(3, 4), (0, 10), (12, 20), (5, 30), (3, 1)] {
+            distr.add_weight(i, delta).unwrap();
+            weights[i] += delta;
+        }
+        let mut distr_f = WeightedIndex::new(&[0.5f64, 0.5]).unwrap();
+        distr_f.add_weight(0, -0.5).unwrap();
+        for _ in 0..100 {
+            assert_eq!(distr_f.sample(&mut r), 1);
+        }
+
+        let total_weight = weights.iter().sum::<u32>() as f32;
+        let mut chosen = [0i32; 13];
+        for _ in 0..N_REPS {
+            chosen[distr.sample(&mut r)] += 1;
+        }
+        for (i, count) in chosen.iter().enumerate() {
+            let exp = (weights[i] * N_REPS) as f32 / total_weight;
+            assert!((*count as f32 - exp).abs() <= 0.25 * exp);
+        }
+
+        let mut distr = WeightedIndex::new(&[0, 3, 0]).unwrap();
+        assert_eq!(distr.add_weight(1, -4).unwrap_err(), WeightedError::NegativeWeight);
+        assert_eq!(distr.add_weight(1, -3).unwrap_err(), WeightedError::AllWeightsZero);
+        distr.add_weight(2, 1).unwrap();
+        distr.add_weight(1, -3).unwrap();
+        for _ in 0..100 {
+            assert_eq!(distr.sample(&mut r), 2);
+        }
+    }
+
+    #[test]
+    fn test_weightedindex_add_weight_cancellation() {
+        // `1e20 + 1 - 1e20` rounds to a total of zero, although the weight
+        // `1` remains.
+        let mut r = ::test::rng(150);
+        let mut distr = WeightedIndex::new(&[1e20, 1.0]).unwrap();
+        distr.add_weight(0, -1e20).unwrap();
+        assert_eq!(total_weight(&distr.tree), 1.0);
+        for _ in 0..100 {
+            assert_eq!(distr.sample(&mut r), 1);
+        }
+        distr.add_weight(0, 1.0).unwrap();
+        assert_eq!(total_weight(&distr.tree), 2.0);
+    }
+
+    #[test]
+    fn test_weightedindex_descent_rounding() {
+        use rngs::mock::StepRng;
+        // The chosen weight is 1. The tree holds items 8 to 11 as one node of
+        // `2^-52`, which is too much, but the descent then adds items 8 and
+        // 10 to the total 1 one at a time, and each addition rounds away.
+        // Without care it thus passes both and lands on item 11, of weight
+        // zero.
+        let tiny = 1.0 / (1u64 << 53) as f64;
+        let weights = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, tiny, 0.0, tiny, 0.0, 1.0];
+        let distr = WeightedIndex::new(&weights[..]).unwrap();
+        let mut r = StepRng::new(1 << 63, 0);
+        assert_eq!(distr.sample(&mut r), 12);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_with_temperature() {
+        let logits = [0.5, -1.0, 2.0, 0.0];
+        let mut r = ::test::rng(204);
+        let frequencies = |distr: &WeightedIndex<f64>, r: &mut ::test::TestRng<_>| {
+            let mut chosen = [0u32; 4];
+            for _ in 0..10_000 {
+                chosen[distr.sample(r)] += 1;
+            }
+            chosen
+        };
+
+        let cold = WeightedIndex::with_temperature(&logits, 0.05).unwrap();
+        let chosen = frequencies(&cold, &mut r);
+        assert!(chosen[2] > 9990, "{:?}", chosen);
+
+        let hot = WeightedIndex::with_temperature(&logits, 1e3).unwrap();
+        let chosen = frequencies(&hot, &mut r);
+        for &count in chosen.iter() {
+            assert!((count as f64 - 2500.0).abs() < 150.0, "{:?}", chosen);
+        }
+
+        // A temperature of 1 gives the plain softmax.
+        let plain = WeightedIndex::from_logits(&logits).unwrap();
+        let unit = WeightedIndex::with_temperature(&logits, 1.0).unwrap();
+        let mut r1 = ::test::rng(205);
+        let mut r2 = ::test::rng(205);
+        for _ in 0..100 {
+            assert_eq!(plain.sample(&mut r1), unit.sample(&mut r2));
+        }
+
+        for &t in [0.0, -1.0, ::std::f64::NAN, ::std::f64::INFINITY].iter() {
+            assert_eq!(WeightedIndex::with_temperature(&logits, t).unwrap_err(),
+                       WeightedError::InvalidTemperature);
+        }
+        assert_eq!(WeightedIndex::with_temperature(&[], 1.0).unwrap_err(),
+                   WeightedError::NoItem);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_decay_all() {
+        let weights = [1.0, 2.0, 3.0, 4.0];
+        let original = WeightedIndex::new(&weights).unwrap();
+        let mut distr = original.clone();
+        for _ in 0..3 {
+            distr.decay_all(0.5).unwrap();
+        }
+        // Decays by powers of two are exact, and sample identically.
+        for (w, &expected) in distr.weights.iter().zip(weights.iter()) {
+            assert_eq!(*w, expected / 8.0);
+        }
+        assert_eq!(total_weight(&distr.tree), 10.0 / 8.0);
+        let mut r1 = ::test::rng(237);
+        let mut r2 = ::test::rng(237);
+        for _ in 0..1000 {
+            assert_eq!(distr.sample(&mut r1), original.sample(&mut r2));
+        }
+
+        let mut distr = original.clone();
+        for _ in 0..10 {
+            distr.decay_all(0.9).unwrap();
+        }
+        let shrink = 0.9f64.powi(10);
+        for i in 0..4 {
+            assert!((distr.weights[i] - weights[i] * shrink).abs() < 1e-12);
+            assert!((distr.ln_prob(i) - original.ln_prob(i)).abs() < 1e-12);
+        }
+        assert!((total_weight(&distr.tree) - 10.0 * shrink).abs() < 1e-12);
+
+        for &factor in [0.0, -0.5, 1.5, ::std::f64::NAN].iter() {
+            assert_eq!(distr.decay_all(factor).unwrap_err(), WeightedError::InvalidDecayFactor);
+        }
+        let mut tiny = WeightedIndex::new(&[1e-300, 0.0]).unwrap();
+        assert_eq!(tiny.decay_all(1e-100).unwrap_err(), WeightedError::AllWeightsZero);
+        assert_eq!(tiny.weights[0], 1e-300);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_decay_and_add() {
+        let mut distr = WeightedIndex::new(&[0.0, 1.0, 0.0]).unwrap();
+        distr.decay_and_add(0.5, 0, 2.0).unwrap();
+        assert_eq!(distr.weights, vec![2.0, 0.5, 0.0]);
+        distr.decay_and_add(0.5, 2, 1.0).unwrap();
+        assert_eq!(distr.weights, vec![1.0, 0.25, 1.0]);
+        assert_eq!(total_weight(&distr.tree), 2.25);
+        let mut r = ::test::rng(238);
+        let counts = distr.sample_counts(&mut r, 9000);
+        assert!(counts[1] > 800 && counts[1] < 1200, "{:?}", counts);
+
+        // Errors leave the weights unchanged.
+        assert_eq!(distr.decay_and_add(0.5, 1, -1.0).unwrap_err(), WeightedError::NegativeWeight);
+        assert_eq!(distr.decay_and_add(2.0, 1, 1.0).unwrap_err(),
+                   WeightedError::InvalidDecayFactor);
+        let mut single = WeightedIndex::new(&[1.0]).unwrap();
+        assert_eq!(single.decay_and_add(0.5, 0, -0.5).unwrap_err(),
+                   WeightedError::AllWeightsZero);
+        assert_eq!(distr.weights, vec![1.0, 0.25, 1.0]);
+        assert_eq!(single.weights, vec![1.0]);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_blend() {
+        let a = [0.5, 0.5, 0.0, 0.0];
+        let b = [0.0, 0.2, 0.3, 0.5];
+        let same_samples = |x: &WeightedIndex<f64>, y: &WeightedIndex<f64>| {
+            let mut r1 = ::test::rng(233);
+            let mut r2 = ::test::rng(233);
+            (0..1000).all(|_| x.sample(&mut r1) == y.sample(&mut r2))
+        };
+        let start = WeightedIndex::blend(&a, &b, 0.0).unwrap();
+        assert!(same_samples(&start, &WeightedIndex::new(&a).unwrap()));
+        let end = WeightedIndex::blend(&a, &b, 1.0).unwrap();
+        assert!(same_samples(&end, &WeightedIndex::new(&b).unwrap()));
+
+        let mut r = ::test::rng(234);
+        let n = 100_000;
+        for &t in [0.25, 0.5, 0.9].iter() {
+            let distr = WeightedIndex::blend(&a, &b, t).unwrap();
+            let counts = distr.sample_counts(&mut r, n);
+            for i in 0..4 {
+                let expected = (1.0 - t) * a[i] + t * b[i];
+                let freq = counts[i] as f64 / n as f64;
+                assert!((freq - expected).abs() < 0.01, "t = {}: {:?}", t, counts);
+            }
+        }
+
+        assert_eq!(WeightedIndex::blend(&a, &b[..3], 0.5).unwrap_err(),
+                   WeightedError::LengthMismatch);
+        for &t in [-0.1, 1.1, ::std::f64::NAN].iter() {
+            assert_eq!(WeightedIndex::blend(&a, &b, t).unwrap_err(),
+                       WeightedError::InvalidBlendFactor);
+        }
+        assert_eq!(WeightedIndex::blend(&[1.0, 0.0], &[0.0, 0.0], 1.0).unwrap_err(),
+                   WeightedError::AllWeightsZero);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_from_logits() {
+        let logits = [0.5, -1.0, 2.0, 0.0, -3.5];
+        let sum: f64 = logits.iter().map(|x: &f64| x.exp()).sum();
+        let softmax: Vec<f64> = logits.iter().map(|x| x.exp() / sum).collect();
+        let from_logits = WeightedIndex::from_logits(&logits).unwrap();
+        let from_weights = WeightedIndex::new(&softmax).unwrap();
+        let mut r1 = ::test::rng(703);
+        let mut r2 = ::test::rng(703);
+        for _ in 0..1000 {
+            assert_eq!(from_logits.sample(&mut r1), from_weights.sample(&mut r2));
+        }
+
+        // Shifting all logits by the same amount has no effect, even far
+        // beyond the range of `exp`.
+        let mut r = ::test::rng(704);
+        for &shift in [1e4, -1e4].iter() {
+            let shifted: Vec<f64> = logits.iter().map(|x| x + shift).collect();
+            let distr = WeightedIndex::from_logits(&shifted).unwrap();
+            let mut chosen = [0u32; 5];
+            for _ in 0..10_000 {
+                chosen[distr.sample(&mut r)] += 1;
+            }
+            for (count, p) in chosen.iter().zip(softmax.iter()) {
+                let exp = p * 10_000.0;
+                assert!((*count as f64 - exp).abs() <= 0.25 * exp + 5.0);
+            }
+        }
+
+        let neg_inf = ::std::f64::NEG_INFINITY;
+        for _ in 0..100 {
+            assert_eq!(WeightedIndex::from_logits(&[neg_inf, 3.0]).unwrap().sample(&mut r), 1);
+        }
+        assert_eq!(WeightedIndex::from_logits(&[]).unwrap_err(), WeightedError::NoItem);
+        assert_eq!(WeightedIndex::from_logits(&[neg_inf, neg_inf]).unwrap_err(),
+                   WeightedError::AllWeightsZero);
+        assert_eq!(WeightedIndex::from_logits(&[1.0, ::std::f64::NAN]).unwrap_err(),
+                   WeightedError::InvalidWeight);
+        assert_eq!(WeightedIndex::from_logits(&[1.0, ::std::f64::INFINITY]).unwrap_err(),
+                   WeightedError::InvalidWeight);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_categorical_reproducible() {
+        use std::collections::HashMap;
+        // Maps with the same contents may iterate in different orders.
+        let words = ["alpha", "beta", "gamma", "delta", "epsilon", "zeta"];
+        let mut forward = HashMap::new();
+        let mut backward = HashMap::new();
+        for (i, w) in words.iter().enumerate() {
+            forward.insert(*w, i as f64 + 1.0);
+        }
+        for (i, w) in words.iter().enumerate().rev() {
+            backward.insert(*w, i as f64 + 1.0);
+        }
+        let a = Categorical::new(forward).unwrap();
+        let b = Categorical::new(backward).unwrap();
+        assert_eq!(a.keys(), &["alpha", "beta", "delta", "epsilon", "gamma", "zeta"]);
+
+        let mut r1 = ::test::rng(189);
+        let mut r2 = ::test::rng(189);
+        let mut counts = HashMap::new();
+        for _ in 0..21_000 {
+            let x = a.sample(&mut r1);
+            assert_eq!(x, b.sample(&mut r2));
+            *counts.entry(x).or_insert(0) += 1;
+        }
+        for (i, w) in words.iter().enumerate() {
+            let expected = (i + 1) as f64 * 1000.0;
+            assert!((counts[w] as f64 - expected).abs() < 0.1 * expected);
+        }
+    }
+
+    #[test]
+    fn test_categorical_pairs() {
+        let mut r = ::test::rng(190);
+        // Repeated keys are merged.
+        let distr = Categorical::new(vec![(3, 1.0), (1, 0.0), (3, 2.0)]).unwrap();
+        assert_eq!(distr.keys(), &[1, 3]);
+        for _ in 0..100 {
+            assert_eq!(distr.sample(&mut r), 3);
+        }
+        assert_eq!(Categorical::<u8>::new(vec![]).unwrap_err(), WeightedError::NoItem);
+        assert_eq!(Categorical::new(vec![(1, -1.0)]).unwrap_err(),
+                   WeightedError::NegativeWeight);
+        assert_eq!(Categorical::new(vec![(3, -1.0), (3, 2.0)]).unwrap_err(),
+                   WeightedError::NegativeWeight);
+        assert_eq!(Categorical::new(vec![(1, 0.0), (2, 0.0)]).unwrap_err(),
+                   WeightedError::AllWeightsZero);
+        assert_eq!(Categorical::new(vec![(1, ::core::f64::NAN)]).unwrap_err(),
+                   WeightedError::InvalidWeight);
+    }
+
+    #[test]
+    fn test_weightedindex_sample_counts() {
+        let mut r = ::test::rng(192);
+        let weights = [1u32, 0, 4, 5];
+        let distr = WeightedIndex::new(&weights).unwrap();
+        let counts = distr.sample_counts(&mut r, 100_000);
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts.iter().sum::<u64>(), 100_000);
+        assert_eq!(counts[1], 0);
+        for (&count, &w) in counts.iter().zip(weights.iter()) {
+            let exp = w as f64 * 10_000.0;
+            assert!((count as f64 - exp).abs() <= 0.02 * exp);
+        }
+        assert_eq!(distr.sample_counts(&mut r, 0), vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_weightedindex_sample_counts_into() {
+        let distr = WeightedIndex::new(&[3u32, 1, 0, 6]).unwrap();
+        let mut a = ::test::rng(226);
+        let mut b = ::test::rng(226);
+        let mut out = [7u64; 4];
+        for &n in [1000, 10, 0, 500].iter() {
+            distr.sample_counts_into(&mut a, n, &mut out);
+            assert_eq!(out.to_vec(), distr.sample_counts(&mut b, n));
+            assert_eq!(out.iter().sum::<u64>(), n as u64);
+            assert_eq!(out[2], 0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weightedindex_sample_counts_into_wrong_length() {
+        let distr = WeightedIndex::new(&[1u32, 2]).unwrap();
+        distr.sample_counts_into(&mut ::test::rng(227), 10, &mut [0; 3]);
+    }
+
+    #[test]
+    fn test_weightedindex_sample_argmax_of() {
+        let mut r = ::test::rng(200);
+        let distr = WeightedIndex::new(&[2u32, 5, 3]).unwrap();
+        let mut wins = [0u32; 3];
+        for _ in 0..1000 {
+            wins[distr.sample_argmax_of(&mut r, 50)] += 1;
+        }
+        assert!(wins[1] > 800, "{:?}", wins);
+        assert!(wins[2] > wins[0], "{:?}", wins);
+        // A single draw is a plain sample.
+        let one_hot = WeightedIndex::new(&[0, 0, 1]).unwrap();
+        assert_eq!(one_hot.sample_argmax_of(&mut r, 1), 2);
+        // Ties go to the lowest index.
+        let equal = WeightedIndex::new(&[1, 1]).unwrap();
+        let mut zeros = 0;
+        for _ in 0..1000 {
+            if equal.sample_argmax_of(&mut r, 2) == 0 { zeros += 1; }
+        }
+        // Index 0 wins unless both draws are 1, with probability 3/4.
+        assert!((zeros as f64 - 750.0).abs() < 60.0, "{}", zeros);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weightedindex_sample_argmax_of_zero() {
+        WeightedIndex::new(&[1, 2]).unwrap().sample_argmax_of(&mut ::test::rng(201), 0);
+    }
+
+    #[test]
+    fn test_weightedindex_mode() {
+        let mut r = ::test::rng(216);
+        let distr = WeightedIndex::new(&[2, 5, 1, 5, 0, 5, 4]).unwrap();
+        let mut counts = [0u32; 7];
+        for _ in 0..3000 {
+            counts[distr.mode(&mut r)] += 1;
+        }
+        assert_eq!(counts[0] + counts[2] + counts[4] + counts[6], 0);
+        for &i in [1, 3, 5].iter() {
+            assert!(counts[i] > 900 && counts[i] < 1100, "{:?}", counts);
+        }
+
+        // A unique maximum doesn't use the generator.
+        let distr = WeightedIndex::new(&[0.5, 2.5, 1.0]).unwrap();
+        let mut a = ::test::rng(217);
+        let mut b = ::test::rng(217);
+        assert_eq!(distr.mode(&mut a), 1);
+        assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+        assert_eq!(WeightedIndex::new(&[7]).unwrap().mode(&mut a), 0);
+    }
+
+    #[test]
+    fn test_weightedindex_most_probable() {
+        let weights = [2, 5, 1, 7, 0, 7, 4];
+        let distr = WeightedIndex::new(&weights).unwrap();
+        assert_eq!(distr.most_probable(), 3);
+        assert_eq!(WeightedIndex::new(&[0.5, 2.5, 1.0]).unwrap().most_probable(), 1);
+        assert_eq!(WeightedIndex::new(&[0, 0, 3]).unwrap().most_probable(), 2);
+        assert_eq!(WeightedIndex::new(&[1, 1, 1]).unwrap().most_probable(), 0);
+
+        // It follows updates of the weights.
+        let mut distr = WeightedIndex::new(&weights).unwrap();
+        distr.add_weight(6, 4).unwrap();
+        assert_eq!(distr.most_probable(), 6);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_weighted_permutation() {
+        let mut r = ::test::rng(218);
+        let weights = [1.0, 8.0, 0.0, 4.0, 2.0, 0.0];
+        let distr = WeightedIndex::new(&weights).unwrap();
+        let trials = 10_000;
+        let mut rank_sums = [0usize; 6];
+        let mut first = [0u32; 6];
+        for _ in 0..trials {
+            let order = distr.weighted_permutation(&mut r);
+            let mut sorted = order.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![0, 1, 2, 3, 4, 5]);
+            for (rank, &i) in order.iter().enumerate() {
+                rank_sums[i] += rank;
+            }
+            first[order[0]] += 1;
+        }
+        // Higher weights have lower average ranks, and zero weights come last.
+        let ranks: Vec<f64> = rank_sums.iter().map(|&s| s as f64 / trials as f64).collect();
+        assert!(ranks[1] < ranks[3] && ranks[3] < ranks[4] && ranks[4] < ranks[0],
+                "{:?}", ranks);
+        assert!(ranks[2] > 4.4 && ranks[2] < 4.6 && ranks[5] > 4.4 && ranks[5] < 4.6);
+        // The first index is a sample of the distribution.
+        for &(i, p) in [(0, 1.0 / 15.0), (1, 8.0 / 15.0), (3, 4.0 / 15.0)].iter() {
+            assert!((first[i] as f64 / trials as f64 - p).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_entropy() {
+        let uniform = WeightedIndex::new(&[3u32, 3, 3, 3]).unwrap();
+        assert!((uniform.entropy() - 4f64.ln()).abs() < 1e-12);
+        let one_hot = WeightedIndex::new(&[0.0, 2.5, 0.0]).unwrap();
+        assert_eq!(one_hot.entropy(), 0.0);
+        // Entropy in bits of a fair coin and an unfair one: 1 and ~0.469.
+        let coin = WeightedIndex::new(&[1.0f32, 1.0]).unwrap();
+        assert!((coin.entropy() / 2f64.ln() - 1.0).abs() < 1e-12);
+        let unfair = WeightedIndex::new(&[9u8, 1]).unwrap();
+        assert!((unfair.entropy() / 2f64.ln() - 0.4690).abs() < 1e-4);
+        let categorical = Categorical::new(vec![("a", 1.0), ("b", 1.0)]).unwrap();
+        assert!((categorical.entropy() - 2f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_weightedindex_ln_prob() {
+        let weights = [2u32, 0, 5, 1, 0, 12];
+        let distr = WeightedIndex::new(&weights).unwrap();
+        let total: f64 = (0..weights.len()).map(|i| distr.ln_prob(i).exp()).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert_eq!(distr.ln_prob(1), ::std::f64::NEG_INFINITY);
+        assert_eq!(distr.ln_prob(4), ::std::f64::NEG_INFINITY);
+        assert!((distr.ln_prob(5) - 0.6f64.ln()).abs() < 1e-12);
+        // Consistent with the entropy.
+        let entropy: f64 = [0, 2, 3, 5].iter()
+            .map(|&i| -distr.ln_prob(i) * distr.ln_prob(i).exp()).sum();
+        assert!((entropy - distr.entropy()).abs() < 1e-12);
+
+        let logits = WeightedIndex::from_logits(&[-1.0, 0.5, 3.0]).unwrap();
+        let total: f64 = (0..3).map(|i| logits.ln_prob(i).exp()).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_categorical_sampler() {
+        let mut r = ::test::rng(235);
+        let weights = [1u32, 2, 3, 4];
+        let mut sampler = CategoricalSampler::new(WeightedIndex::new(&weights).unwrap());
+        let trials = 20_000;
+        let mut first = [0u32; 4];
+        // `before[i][j]` counts how often `i` was drawn before `j`.
+        let mut before = [[0u32; 4]; 4];
+        for _ in 0..trials {
+            let mut excluded = [false; 4];
+            let mut order = Vec::new();
+            while let Some(i) = sampler.sample_excluding(&mut r, &mut excluded) {
+                order.push(i);
+            }
+            let mut sorted = order.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![0, 1, 2, 3]);
+            assert!(excluded.iter().all(|&e| e));
+            first[order[0]] += 1;
+            for (k, &i) in order.iter().enumerate() {
+                for &j in order[k + 1..].iter() {
+                    before[i][j] += 1;
+                }
+            }
+        }
+        for i in 0..4 {
+            let expected = weights[i] as f64 / 10.0;
+            assert!((first[i] as f64 / trials as f64 - expected).abs() < 0.015, "{:?}", first);
+            for j in 0..4 {
+                if i != j {
+                    // The probability that `i` comes before `j` is
+                    // `w[i] / (w[i] + w[j])`.
+                    let expected = weights[i] as f64 / (weights[i] + weights[j]) as f64;
+                    let freq = before[i][j] as f64 / trials as f64;
+                    assert!((freq - expected).abs() < 0.015, "{} {}: {}", i, j, freq);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_categorical_sampler_exclusions() {
+        let mut r = ::test::rng(236);
+        let mut sampler = CategoricalSampler::new(WeightedIndex::new(&[0.5, 0.0, 2.0]).unwrap());
+        let mut excluded = [false, false, true];
+        assert_eq!(sampler.sample_excluding(&mut r, &mut excluded), Some(0));
+        assert_eq!(excluded, [true, false, true]);
+        // Only a zero weight remains.
+        assert_eq!(sampler.sample_excluding(&mut r, &mut excluded), None);
+        // Restored indices can be sampled again.
+        excluded[2] = false;
+        assert_eq!(sampler.sample_excluding(&mut r, &mut excluded), Some(2));
+        let mut fresh = [false; 3];
+        let i = sampler.sample_excluding(&mut r, &mut fresh).unwrap();
+        assert!(i == 0 || i == 2);
+    }
+
+    #[test]
+    fn test_categorical_sampler_rounding() {
+        use rngs::mock::StepRng;
+        let mut r = ::test::rng(235);
+        // Excluding the first index cancels the total to zero.
+        let mut sampler = CategoricalSampler::new(WeightedIndex::new(&[1e20, 1.0]).unwrap());
+        let mut excluded = [false; 2];
+        let mut order = Vec::new();
+        while let Some(i) = sampler.sample_excluding(&mut r, &mut excluded) {
+            order.push(i);
+        }
+        assert_eq!(order.len(), 2);
+        assert!(order.contains(&0) && order.contains(&1));
+
+        // Weights of very different magnitudes accumulate rounding errors in
+        // the partial sums; excluded indices are still never sampled.
+        let weights: Vec<f64> = (0..37).map(|i| (1.7f64).powi(i % 23) / 3.0).collect();
+        let mut sampler = CategoricalSampler::new(WeightedIndex::new(&weights).unwrap());
+        for _ in 0..50 {
+            let mut excluded = [false; 37];
+            let mut count = 0;
+            while let Some(i) = sampler.sample_excluding(&mut r, &mut excluded) {
+                count += 1;
+                assert!(excluded[i]);
+            }
+            assert_eq!(count, 37);
+            assert!(excluded.iter().all(|&e| e));
+        }
+
+        // The descent of the tree rounds onto index 11, of weight zero; see
+        // `test_weightedindex_descent_rounding`.
+        let tiny = 1.0 / (1u64 << 53) as f64;
+        let weights = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, tiny, 0.0, tiny, 0.0, 1.0];
+        let mut sampler = CategoricalSampler::new(WeightedIndex::new(&weights[..]).unwrap());
+        let mut excluded = [false; 13];
+        assert_eq!(sampler.sample_excluding(&mut StepRng::new(1 << 63, 0), &mut excluded),
+                   Some(12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_categorical_sampler_length_mismatch() {
+        let mut sampler = CategoricalSampler::new(WeightedIndex::new(&[1, 2]).unwrap());
+        sampler.sample_excluding(&mut ::test::rng(237), &mut [false; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weightedindex_sample_with_length_mismatch() {
+        let mut r = ::test::rng(701);
+        let distr = WeightedIndex::new(&[1, 2, 3]).unwrap();
+        distr.sample_with(&mut r, &['a', 'b']);
+    }
+
+    #[test]
+    #[cfg(rust_1_26)]
+    fn test_weightedindex_from_u64() {
+        let mut r = ::test::rng(210);
+        let half = ::core::u64::MAX / 2;
+        // The total is about `2.25 * u64::MAX`.
+        let weights = [half, half / 2, half, 0, half, half, half / 2];
+        let distr = WeightedIndex::from_u64(&weights).unwrap();
+        let total = weights.iter().map(|&w| w as u128).sum::<u128>();
+        assert!(total > ::core::u64::MAX as u128);
+
+        const N_REPS: u32 = 50_000;
+        let mut chosen = [0u32; 7];
+        for _ in 0..N_REPS {
+            chosen[distr.sample(&mut r)] += 1;
+        }
+        for (i, &count) in chosen.iter().enumerate() {
+            let exp = weights[i] as f64 / total as f64 * N_REPS as f64;
+            assert!((count as f64 - exp).abs() <= 0.05 * exp, "{:?}", chosen);
+        }
+        assert_eq!(chosen[3], 0);
+
+        assert_eq!(WeightedIndex::from_u64(&[0u64, 0]).unwrap_err(),
+                   WeightedError::AllWeightsZero);
+        assert_eq!(WeightedIndex::from_u64(Vec::<u64>::new()).unwrap_err(),
+                   WeightedError::NoItem);
+    }
+
+    #[test]
+    #[cfg(feature="serde1")]
+    fn test_weightedindex_serde() {
+        use bincode;
+        let mut r = ::test::rng(208);
+        let weights: Vec<f64> = (0..10_000).map(|_| r.gen::<f64>()).collect();
+        let distr = WeightedIndex::new(&weights).unwrap();
+        let buf = bincode::serialize(&distr).unwrap();
+        let loaded: WeightedIndex<f64> = bincode::deserialize(&buf).unwrap();
+        assert_eq!(loaded.weights, distr.weights);
+        assert_eq!(loaded.nonzero, distr.nonzero);
+
+        let mut r1 = ::test::rng(209);
+        let mut r2 = ::test::rng(209);
+        for _ in 0..10_000 {
+            assert_eq!(distr.sample(&mut r1), loaded.sample(&mut r2));
+        }
+    }
+
+    #[test]
+    #[cfg(feature="serde1")]
+    fn test_weightedindex_serde_invalid() {
+        use bincode;
+        // A `WeightedIndex` is serialized like the pair of its weights and
+        // its table.
+        let load = |weights: Vec<u32>, tree: Vec<u32>| {
+            let buf = bincode::serialize(&(weights, tree)).unwrap();
+            bincode::deserialize::<WeightedIndex<u32>>(&buf)
+        };
+        let distr = load(vec![1, 2, 3], vec![1, 3, 3]).unwrap();
+        assert_eq!(total_weight(&distr.tree), 6);
+        // Node 2 is less than its child, node 1.
+        assert!(load(vec![1, 2, 3], vec![4, 3, 3]).is_err());
+        // Node 3 is less than its weight.
+        assert!(load(vec![1, 2, 3], vec![1, 3, 2]).is_err());
+        assert!(load(vec![1, 2], vec![1, 3, 3]).is_err());
+        assert!(load(vec![], vec![]).is_err());
+        assert!(load(vec![0, 0], vec![0, 0]).is_err());
+        // The total of nodes 2 and 3 overflows.
+        assert!(load(vec![1, 1, 1 << 31], vec![1, 1 << 31, 1 << 31]).is_err());
+
+        let buf = bincode::serialize(&(vec![1.0], vec![::std::f64::INFINITY])).unwrap();
+        assert!(bincode::deserialize::<WeightedIndex<f64>>(&buf).is_err());
+    }
 }
 
 /// Error type returned from `WeightedIndex::new`.
@@ -203,6 +1893,21 @@ pub enum WeightedError {
 
     /// All items in the provided iterator had a weight of zero.
     AllWeightsZero,
+
+    /// A weight was NaN or infinite.
+    InvalidWeight,
+
+    /// The temperature was not strictly positive and finite.
+    InvalidTemperature,
+
+    /// Two weight vectors had different lengths.
+    LengthMismatch,
+
+    /// The blend factor was not in `[0, 1]`.
+    InvalidBlendFactor,
+
+    /// The decay factor was not in `(0, 1]`.
+    InvalidDecayFactor,
 }
 
 impl WeightedError {
@@ -211,6 +1916,11 @@ impl WeightedError {
             WeightedError::NoItem => "No items found",
             WeightedError::NegativeWeight => "Item has negative weight",
             WeightedError::AllWeightsZero => "All items had weight zero",
+            WeightedError::InvalidWeight => "Item has NaN or infinite weight",
+            WeightedError::InvalidTemperature => "Temperature is not positive and finite",
+            WeightedError::LengthMismatch => "Weight vectors differ in length",
+            WeightedError::InvalidBlendFactor => "Blend factor is not between 0 and 1",
+            WeightedError::InvalidDecayFactor => "Decay factor is not in (0, 1]",
         }
     }
 }
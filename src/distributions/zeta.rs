@@ -0,0 +1,140 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Zeta distribution.
+
+use Rng;
+use distributions::{Distribution, DistError, OpenClosed01};
+use std::fmt;
+
+/// The Zeta distribution `Zeta(s)`.
+///
+/// This is a discrete distribution over the ranks `k = 1, 2, 3, ...` with
+/// probability `k^-s / ζ(s)`, where `ζ` is the Riemann zeta function. It is
+/// the limit of the Zipf distribution for an infinite number of elements,
+/// and a discrete analogue of the [`Pareto`] distribution.
+///
+/// Samples are generated by the rejection method of Devroye, which does not
+/// need `ζ(s)`. The result is an integral `f64`, since for `s` close to `1`
+/// the tail is too heavy to fit in an integer type; values above `2^53` are
+/// not exact.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Zeta, Distribution};
+///
+/// let zeta = Zeta::new(1.5).unwrap();
+/// let v = zeta.sample(&mut rand::thread_rng());
+/// println!("{} is from a Zeta(1.5) distribution", v);
+/// ```
+///
+/// [`Pareto`]: struct.Pareto.html
+#[derive(Clone, Copy, Debug)]
+pub struct Zeta {
+    s: f64,
+    /// `s - 1`.
+    s_minus_1: f64,
+    /// `2^(s - 1)`.
+    b: f64,
+}
+
+impl Zeta {
+    /// Construct a new `Zeta` with the given exponent `s`.
+    ///
+    /// Returns an error unless `s > 1`.
+    pub fn new(s: f64) -> Result<Zeta, DistError> {
+        if !(s > 1.0) {
+            return Err(DistError::ShapeOutOfRange);
+        }
+        let s_minus_1 = s - 1.0;
+        Ok(Zeta { s, s_minus_1, b: s_minus_1.exp2() })
+    }
+
+    /// Construct a new `Zeta`, like [`new`](#method.new).
+    ///
+    /// Panics unless `s > 1`.
+    pub fn new_unchecked(s: f64) -> Zeta {
+        Zeta::new(s).expect("Zeta::new_unchecked called with s <= 1")
+    }
+}
+
+impl Distribution<f64> for Zeta {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        // Devroye, Non-Uniform Random Variate Generation, p. 551: propose
+        // `floor(U^(-1 / (s - 1)))`, a discretised Pareto variate, and accept
+        // it with probability proportional to the ratio of the densities.
+        loop {
+            let u: f64 = rng.sample(OpenClosed01);
+            let v = rng.gen::<f64>();
+            let x = u.powf(-1.0 / self.s_minus_1).floor();
+            // An infinite `x` gives `t == 1` and is rejected, since the
+            // product below is NaN.
+            let t = (1.0 + 1.0 / x).powf(self.s_minus_1);
+            if v * x * (t - 1.0) * self.b <= t * (self.b - 1.0) {
+                return x;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Zeta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Zeta(s={})", self.s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use distributions::{Distribution, DistError};
+    use super::Zeta;
+
+    #[test]
+    fn test_zeta_ranks() {
+        let mut rng = ::test::rng(166);
+        // `ζ(2) = π^2 / 6`, so rank `k` has probability `6 / (π k)^2`.
+        let zeta = Zeta::new(2.0).unwrap();
+        let n = 100_000;
+        let mut counts = [0u32; 6];
+        for _ in 0..n {
+            let x = zeta.sample(&mut rng);
+            assert!(x >= 1.0 && x == x.floor());
+            if x < 6.0 {
+                counts[x as usize] += 1;
+            }
+        }
+        assert_eq!(counts[0], 0);
+        for k in 1..6 {
+            let expected = 6.0 / (::std::f64::consts::PI * k as f64).powi(2);
+            let freq = counts[k] as f64 / n as f64;
+            assert!((freq - expected).abs() < 0.01, "rank {}: {} != {}", k, freq, expected);
+        }
+        // Rank 1 dominates, and the tail decays.
+        assert!(counts[1] as u64 * 2 > n);
+        for k in 2..6 {
+            assert!(counts[k] < counts[k - 1]);
+        }
+    }
+
+    #[test]
+    fn test_zeta_heavy_tail() {
+        let mut rng = ::test::rng(167);
+        let zeta = Zeta::new(1.01).unwrap();
+        for _ in 0..1000 {
+            let x = zeta.sample(&mut rng);
+            assert!(x >= 1.0 && x.is_finite());
+        }
+    }
+
+    #[test]
+    fn test_zeta_invalid() {
+        assert_eq!(Zeta::new(1.0).unwrap_err(), DistError::ShapeOutOfRange);
+        assert_eq!(Zeta::new(0.5).unwrap_err(), DistError::ShapeOutOfRange);
+        assert_eq!(Zeta::new(::std::f64::NAN).unwrap_err(), DistError::ShapeOutOfRange);
+    }
+}
@@ -249,6 +249,10 @@ extern crate rand_pcg;
 extern crate rand_xorshift;
 
 #[cfg(feature = "log")] #[macro_use] extern crate log;
+#[cfg(feature = "rayon")] extern crate rayon;
+#[cfg(feature="serde1")] extern crate serde;
+#[cfg(feature="serde1")] #[macro_use] extern crate serde_derive;
+#[cfg(all(feature="serde1", test))] extern crate bincode;
 #[allow(unused)]
 #[cfg(not(feature = "log"))] macro_rules! trace { ($($x:tt)*) => () }
 #[allow(unused)]
@@ -262,11 +266,13 @@ extern crate rand_xorshift;
 
 
 // Re-exports from rand_core
-pub use rand_core::{RngCore, CryptoRng, SeedableRng};
+pub use rand_core::{RngCore, CryptoRng, SeedableRng, SeedBuilder};
 pub use rand_core::{ErrorKind, Error};
 
 // Public exports
+#[cfg(rust_1_51)] pub use array::GenArray;
 #[cfg(feature="std")] pub use rngs::thread::thread_rng;
+#[cfg(all(feature="std", feature="rayon"))] pub use rngs::thread::seed_pool;
 
 // Public modules
 pub mod distributions;
@@ -275,6 +281,9 @@ pub mod prng;
 pub mod rngs;
 pub mod seq;
 
+// Only parsed by compilers supporting const generics.
+#[cfg(rust_1_51)] mod array;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Compatibility re-exports. Documentation is hidden; will be removed eventually.
 
@@ -367,6 +376,10 @@ pub mod read {
 use core::{mem, slice};
 use distributions::{Distribution, Standard};
 use distributions::uniform::{SampleUniform, UniformSampler, SampleBorrow};
+use distributions::utils::WideningMultiply;
+
+/// Number of attempts after which `Rng::sample_until` panics.
+const SAMPLE_UNTIL_MAX_ITERS: usize = 1 << 20;
 
 /// An automatically-implemented extension trait on [`RngCore`] providing high-level
 /// generic methods for sampling values and other convenience methods.
@@ -457,6 +470,170 @@ pub trait Rng: RngCore {
         T::Sampler::sample_single(low, high, self)
     }
 
+    /// Generate a random value in the range [`low`, `high`) like
+    /// [`gen_range`], or return `fallback` if the range is empty, i.e. unless
+    /// `low < high`.
+    ///
+    /// The check is a single comparison, so floating-point bounds which are
+    /// NaN also give `fallback`. The range isn't otherwise validated: like
+    /// [`gen_range`], this panics for a range of floats with infinite size.
+    /// No random numbers are used for an empty range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut rng = thread_rng();
+    /// let items = [1, 2, 3];
+    /// // Skip the first item, if there is more than one.
+    /// let i = rng.gen_range_or(1, items.len(), 0);
+    /// println!("{}", items[i]);
+    /// assert_eq!(rng.gen_range_or(1, 1, 0), 0);
+    /// ```
+    ///
+    /// [`gen_range`]: #method.gen_range
+    fn gen_range_or<T: SampleUniform + PartialOrd, B1, B2>(&mut self, low: B1, high: B2,
+                                                          fallback: T) -> T
+        where B1: SampleBorrow<T> + Sized,
+              B2: SampleBorrow<T> + Sized {
+        if low.borrow() < high.borrow() {
+            T::Sampler::sample_single(low, high, self)
+        } else {
+            fallback
+        }
+    }
+
+    /// Generate a random value in the range [0, `bound`), for example to pick
+    /// a random index.
+    ///
+    /// Unlike `gen_range(0, bound)`, this uses Lemire's nearly divisionless
+    /// method[^1], which needs a division only in the rare case that a
+    /// sample may have to be rejected. The result is free of modulo bias.
+    ///
+    /// [^1]: Daniel Lemire (2018). [*Fast Random Integer Generation in an
+    ///       Interval*](https://arxiv.org/abs/1805.10941).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let v = [1, 2, 3, 4];
+    /// let i = thread_rng().gen_below(v.len() as u64);
+    /// println!("{}", v[i as usize]);
+    /// ```
+    fn gen_below(&mut self, bound: u64) -> u64 {
+        assert!(bound != 0, "Rng::gen_below called with bound == 0");
+        let (mut hi, mut lo) = self.next_u64().wmul(bound);
+        if lo < bound {
+            // `2^64 mod bound`: reject the lowest `threshold` values of `lo`
+            // so that every result is produced by equally many inputs.
+            let threshold = bound.wrapping_neg() % bound;
+            while lo < threshold {
+                let (h, l) = self.next_u64().wmul(bound);
+                hi = h;
+                lo = l;
+            }
+        }
+        hi
+    }
+
+    /// Return `1` or `-1` with equal probability.
+    ///
+    /// This uses a single bit of one `u32` draw.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let sign = thread_rng().gen_sign();
+    /// assert!(sign == 1 || sign == -1);
+    /// ```
+    fn gen_sign(&mut self) -> i8 {
+        // Use the most significant bit, which is the best one for some
+        // weaker generators.
+        if self.next_u32() >> 31 == 0 { 1 } else { -1 }
+    }
+
+    /// Return a uniformly distributed integer in the range `[0, 2^bits)`.
+    ///
+    /// This masks a single `u64` draw, so unlike [`gen_range`] it never
+    /// rejects samples; it is only suitable when the range is a power of two.
+    ///
+    /// # Panics
+    ///
+    /// If `bits > 64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let nibble = thread_rng().gen_bits(4);
+    /// assert!(nibble < 16);
+    /// ```
+    ///
+    /// [`gen_range`]: trait.Rng.html#method.gen_range
+    fn gen_bits(&mut self, bits: u32) -> u64 {
+        assert!(bits <= 64, "Rng::gen_bits called with bits > 64");
+        let x = self.next_u64();
+        if bits == 64 { x } else { x & ((1 << bits) - 1) }
+    }
+
+    /// Return a uniformly distributed `f64` in the half-open range `[0, 1)`.
+    ///
+    /// The value is constructed from a single `u64` draw by filling the 52
+    /// mantissa bits of a float in `[1, 2)` and subtracting one. There is no
+    /// division or rounding, so the result is exactly representable and is
+    /// never `1.0`. The precision is 52 bits, one less than the [`Standard`]
+    /// distribution for `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let x = thread_rng().gen_unit_f64();
+    /// assert!(0.0 <= x && x < 1.0);
+    /// ```
+    ///
+    /// [`Standard`]: distributions/struct.Standard.html
+    fn gen_unit_f64(&mut self) -> f64 {
+        const ONE_BITS: u64 = 0x3ff0_0000_0000_0000;
+        f64::from_bits((self.next_u64() >> 12) | ONE_BITS) - 1.0
+    }
+
+    /// Advance the generator by `n` outputs of [`RngCore::next_u32`],
+    /// discarding them.
+    ///
+    /// Some generators have weak initial outputs after seeding, which can be
+    /// skipped with this. Generators which can skip ahead directly, such as
+    /// `ChaChaRng`, do so in constant time by overriding
+    /// [`RngCore::discard_u32`]; otherwise this takes time linear in `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// rng.discard(1000);
+    /// println!("{}", rng.gen::<u32>());
+    /// ```
+    ///
+    /// [`RngCore::next_u32`]: trait.RngCore.html#tymethod.next_u32
+    /// [`RngCore::discard_u32`]: trait.RngCore.html#method.discard_u32
+    fn discard(&mut self, n: u64) {
+        self.discard_u32(n)
+    }
+
     /// Sample a new value, using the given distribution.
     ///
     /// ### Example
@@ -508,6 +685,36 @@ pub trait Rng: RngCore {
         distr.sample_iter(self)
     }
 
+    /// Sample from `distr` repeatedly until a value satisfies `pred`, and
+    /// return that value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value satisfies `pred` within `2^20` attempts, rather
+    /// than looping forever, e.g. when the predicate is impossible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    /// use rand::distributions::Uniform;
+    ///
+    /// let odd = thread_rng().sample_until(&Uniform::new(0u32, 100), |&x| x % 2 == 1);
+    /// assert!(odd % 2 == 1);
+    /// ```
+    fn sample_until<T, D, F>(&mut self, distr: &D, pred: F) -> T
+        where D: Distribution<T>, F: Fn(&T) -> bool
+    {
+        for _ in 0..SAMPLE_UNTIL_MAX_ITERS {
+            let x = distr.sample(self);
+            if pred(&x) {
+                return x;
+            }
+        }
+        panic!("Rng::sample_until: no sample satisfied the predicate within {} attempts",
+               SAMPLE_UNTIL_MAX_ITERS);
+    }
+
     /// Fill `dest` entirely with random bytes (uniform value distribution),
     /// where `dest` is any type supporting [`AsByteSliceMut`], namely slices
     /// and arrays over primitive integer types (`i8`, `i16`, `u32`, etc.).
@@ -594,10 +801,120 @@ pub trait Rng: RngCore {
     /// [`Bernoulli`]: distributions/bernoulli/struct.Bernoulli.html
     #[inline]
     fn gen_bool(&mut self, p: f64) -> bool {
-        let d = distributions::Bernoulli::new(p);
+        let d = distributions::Bernoulli::new_unchecked(p);
         self.sample(d)
     }
 
+    /// Fill `dest` with bools, each with a probability `p` of being true.
+    ///
+    /// This is faster than calling [`gen_bool`] for each element: for
+    /// `p == 0.5` each `u64` drawn from the generator yields 64 bools, and
+    /// otherwise the [`Bernoulli`] threshold is computed only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut coins = [false; 100];
+    /// thread_rng().fill_bools(&mut coins, 0.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0` or `p > 1`.
+    ///
+    /// [`gen_bool`]: #method.gen_bool
+    /// [`Bernoulli`]: distributions/bernoulli/struct.Bernoulli.html
+    fn fill_bools(&mut self, dest: &mut [bool], p: f64) {
+        if p == 0.5 {
+            for chunk in dest.chunks_mut(64) {
+                let bits = self.next_u64();
+                for (i, b) in chunk.iter_mut().enumerate() {
+                    *b = (bits >> i) & 1 == 1;
+                }
+            }
+        } else {
+            let d = distributions::Bernoulli::new_unchecked(p);
+            for b in dest.iter_mut() {
+                *b = self.sample(d);
+            }
+        }
+    }
+
+    /// Return a mask of `len` bools, each with a probability `density` of
+    /// being true.
+    ///
+    /// This uses [`fill_bools`], including its fast path for
+    /// `density == 0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mask = thread_rng().sample_mask(1000, 0.01);
+    /// println!("{} of 1000 entries are set", mask.iter().filter(|&&b| b).count());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `density < 0` or `density > 1`.
+    ///
+    /// [`fill_bools`]: #method.fill_bools
+    #[cfg(feature="std")]
+    fn sample_mask(&mut self, len: usize, density: f64) -> Vec<bool> {
+        assert!(density >= 0.0 && density <= 1.0,
+                "Rng::sample_mask called with density outside [0, 1]");
+        let mut mask = vec![false; len];
+        self.fill_bools(&mut mask, density);
+        mask
+    }
+
+    /// Return a random subset of `n` elements as a bitmask: each of the low
+    /// `n` bits is set independently with probability `inclusion_prob`, and
+    /// the other bits are zero.
+    ///
+    /// This avoids building a `Vec<bool>` for universes of at most 64
+    /// elements. For `inclusion_prob == 0.5` a single `u64` is drawn, as by
+    /// [`gen_bits`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let toppings = ["cheese", "ham", "olives", "onions", "pineapple"];
+    /// let mask = thread_rng().random_subset_mask(toppings.len() as u32, 0.4);
+    /// for (i, topping) in toppings.iter().enumerate() {
+    ///     if mask & (1 << i) != 0 {
+    ///         println!("{}", topping);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `n > 64`, or if `inclusion_prob < 0` or `inclusion_prob > 1`.
+    ///
+    /// [`gen_bits`]: #method.gen_bits
+    fn random_subset_mask(&mut self, n: u32, inclusion_prob: f64) -> u64 {
+        assert!(n <= 64, "Rng::random_subset_mask called with n > 64");
+        assert!(inclusion_prob >= 0.0 && inclusion_prob <= 1.0,
+                "Rng::random_subset_mask called with inclusion_prob outside [0, 1]");
+        if inclusion_prob == 0.5 {
+            return self.gen_bits(n);
+        }
+        let d = distributions::Bernoulli::new_unchecked(inclusion_prob);
+        let mut mask = 0;
+        for i in 0..n {
+            if self.sample(d) {
+                mask |= 1 << i;
+            }
+        }
+        mask
+    }
+
     /// Return a bool with a probability of `numerator/denominator` of being
     /// true. I.e. `gen_ratio(2, 3)` has chance of 2 in 3, or about 67%, of
     /// returning true. If `numerator == denominator`, then the returned value
@@ -623,10 +940,203 @@ pub trait Rng: RngCore {
     /// [`Bernoulli`]: distributions/bernoulli/struct.Bernoulli.html
     #[inline]
     fn gen_ratio(&mut self, numerator: u32, denominator: u32) -> bool {
-        let d = distributions::Bernoulli::from_ratio(numerator, denominator);
+        let d = distributions::Bernoulli::from_ratio_unchecked(numerator, denominator);
         self.sample(d)
     }
 
+    /// Collect `amount` items from `iter` by weighted random sampling
+    /// without replacement, in a single pass over an iterator of unknown
+    /// length.
+    ///
+    /// The weight of each item is given by `weight`. This uses the A-Res
+    /// algorithm by Efraimidis and Spirakis[^1]: each item is assigned the
+    /// key `u^(1/w)` for a uniform `u` and its weight `w`, and the items with
+    /// the `amount` largest keys are kept. The order of the returned items is
+    /// unspecified. If `iter` has fewer than `amount` items, all are returned.
+    ///
+    /// See also [`IteratorRandom::choose_multiple`] for unweighted sampling.
+    ///
+    /// Complexity is `O(n log(amount))` where `n` is the length of the
+    /// iterator.
+    ///
+    /// [^1]: Pavlos S. Efraimidis and Paul G. Spirakis (2006). *Weighted
+    ///       random sampling with a reservoir*. Information Processing
+    ///       Letters 97, 5, 181-185.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any weight is not strictly positive (or NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut rng = thread_rng();
+    /// // Values closer to 100 are more likely to be chosen.
+    /// let v = rng.sample_reservoir_weighted(1..101, 5, |&x| x as f64);
+    /// assert_eq!(v.len(), 5);
+    /// ```
+    ///
+    /// [`IteratorRandom::choose_multiple`]: seq/trait.IteratorRandom.html#method.choose_multiple
+    #[cfg(feature="std")]
+    fn sample_reservoir_weighted<I, T, F>(&mut self, iter: I, amount: usize, mut weight: F)
+        -> Vec<T>
+        where I: IntoIterator<Item = T>, F: FnMut(&T) -> f64
+    {
+        use std::cmp::Ordering;
+        use std::collections::BinaryHeap;
+
+        // Ordered by *decreasing* key, so that the heap's top is the item with
+        // the smallest key.
+        struct Keyed<T> { key: f64, item: T }
+        impl<T> PartialEq for Keyed<T> {
+            fn eq(&self, other: &Self) -> bool { self.key == other.key }
+        }
+        impl<T> Eq for Keyed<T> {}
+        impl<T> PartialOrd for Keyed<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+        }
+        impl<T> Ord for Keyed<T> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                // Keys are never NaN.
+                other.key.partial_cmp(&self.key).unwrap()
+            }
+        }
+
+        if amount == 0 {
+            return Vec::new();
+        }
+        let mut reservoir = BinaryHeap::with_capacity(amount);
+        for item in iter {
+            let w = weight(&item);
+            assert!(w > 0.0, "Rng::sample_reservoir_weighted: weight is not positive");
+            // `ln(u^(1/w)) = ln(u) / w` orders the same way, but does not
+            // underflow for small weights.
+            let u: f64 = self.sample(distributions::Open01);
+            let key = u.ln() / w;
+            if reservoir.len() < amount {
+                reservoir.push(Keyed { key, item });
+            } else if key > reservoir.peek().unwrap().key {
+                reservoir.pop();
+                reservoir.push(Keyed { key, item });
+            }
+        }
+        reservoir.into_iter().map(|k| k.item).collect()
+    }
+
+    /// Return a random permutation of `0..n`.
+    ///
+    /// This allocates the range once and shuffles it in place with
+    /// [`SliceRandom::shuffle`], so complexity is `O(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let p = thread_rng().permutation(5);
+    /// assert_eq!(p.len(), 5);
+    /// ```
+    ///
+    /// [`SliceRandom::shuffle`]: seq/trait.SliceRandom.html#method.shuffle
+    #[cfg(feature="std")]
+    fn permutation(&mut self, n: usize) -> Vec<usize> {
+        use seq::SliceRandom;
+        let mut v: Vec<usize> = (0..n).collect();
+        v.shuffle(self);
+        v
+    }
+
+    /// Return a random permutation of `0..n` in which no element is more
+    /// than `max_displacement` positions away from its sorted position.
+    ///
+    /// Each position is filled in turn with one of the unused elements
+    /// within `max_displacement` of it, chosen uniformly, except that an
+    /// element about to exceed the bound is placed immediately. The result
+    /// is not uniform over all such permutations, but is a convenient way to
+    /// generate nearly-sorted test data, e.g. for adaptive sort algorithms.
+    /// Complexity is `O(n * max_displacement)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let p = thread_rng().nearly_sorted_permutation(100, 3);
+    /// for (i, &x) in p.iter().enumerate() {
+    ///     assert!((i as isize - x as isize).abs() <= 3);
+    /// }
+    /// ```
+    #[cfg(feature="std")]
+    fn nearly_sorted_permutation(&mut self, n: usize, max_displacement: usize) -> Vec<usize> {
+        let d = max_displacement;
+        let mut used = vec![false; n];
+        let mut v = Vec::with_capacity(n);
+        for i in 0..n {
+            let low = i.saturating_sub(d);
+            let high = i.saturating_add(d).min(n - 1);
+            let x = if i >= d && !used[low] {
+                // The last position `low` may take.
+                low
+            } else {
+                let count = (low..high + 1).filter(|&j| !used[j]).count();
+                let k = self.gen_range(0, count);
+                (low..high + 1).filter(|&j| !used[j]).nth(k).unwrap()
+            };
+            used[x] = true;
+            v.push(x);
+        }
+        v
+    }
+
+    /// Return a uniformly random composition of `n` into `parts`
+    /// non-negative parts: a vector of length `parts` summing to `n`, where
+    /// the order of the parts matters.
+    ///
+    /// This uses the stars and bars bijection: `parts - 1` distinct separator
+    /// positions are chosen among `n + parts - 1` with Floyd's algorithm, and
+    /// each part is the number of positions between consecutive separators.
+    /// Complexity is `O(parts log(parts))`, independent of `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let c = thread_rng().random_composition(10, 3);
+    /// assert_eq!(c.len(), 3);
+    /// assert_eq!(c.iter().sum::<u64>(), 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `parts == 0`, or if `n + parts - 1` overflows a `u64`.
+    #[cfg(feature="std")]
+    fn random_composition(&mut self, n: u64, parts: usize) -> Vec<u64> {
+        use std::collections::BTreeSet;
+
+        assert!(parts >= 1, "Rng::random_composition called with parts = 0");
+        let separators = (parts - 1) as u64;
+        let positions = n.checked_add(separators)
+            .expect("Rng::random_composition: n + parts - 1 overflows");
+        let mut chosen = BTreeSet::new();
+        for j in (positions - separators)..positions {
+            let t = self.gen_range(0, j + 1);
+            if !chosen.insert(t) {
+                chosen.insert(j);
+            }
+        }
+        let mut composition = Vec::with_capacity(parts);
+        let mut start = 0;
+        for s in chosen {
+            composition.push(s - start);
+            start = s + 1;
+        }
+        composition.push(positions - start);
+        composition
+    }
+
     /// Return a random element from `values`.
     ///
     /// Deprecated: use [`SliceRandom::choose`] instead.
@@ -963,6 +1473,39 @@ mod test {
         TestRng { inner: StdRng::seed_from_u64(seed) }
     }
 
+    /// Assert that the sample mean and (population) variance of `samples`
+    /// are within a relative tolerance `rel_tol` of the expected values.
+    #[allow(unused)]
+    pub fn assert_moments(samples: &[f64], expected_mean: f64, expected_var: f64,
+                          rel_tol: f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        assert!((mean - expected_mean).abs() <= rel_tol * expected_mean.abs(),
+                "mean {} differs from expected {} by more than {} (relative)",
+                mean, expected_mean, rel_tol);
+        assert!((var - expected_var).abs() <= rel_tol * expected_var.abs(),
+                "variance {} differs from expected {} by more than {} (relative)",
+                var, expected_var, rel_tol);
+    }
+
+    /// The sample (Pearson) correlation coefficient of `xs` and `ys`.
+    #[allow(unused)]
+    pub fn correlation(xs: &[f64], ys: &[f64]) -> f64 {
+        let n = xs.len() as f64;
+        let mean_x = xs.iter().sum::<f64>() / n;
+        let mean_y = ys.iter().sum::<f64>() / n;
+        let mut cov = 0.0;
+        let mut var_x = 0.0;
+        let mut var_y = 0.0;
+        for (&x, &y) in xs.iter().zip(ys.iter()) {
+            cov += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x) * (x - mean_x);
+            var_y += (y - mean_y) * (y - mean_y);
+        }
+        cov / (var_x * var_y).sqrt()
+    }
+
     #[test]
     fn test_fill_bytes_default() {
         let mut r = StepRng::new(0x11_22_33_44_55_66_77_88, 0);
@@ -1031,6 +1574,29 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_gen_range_or() {
+        let mut r = rng(215);
+        for _ in 0..1000 {
+            let a = r.gen_range_or(-10, 10, 100);
+            assert!(a >= -10 && a < 10);
+            let a = r.gen_range_or(&2.0, 3.0, -1.0);
+            assert!(a >= 2.0 && a < 3.0);
+        }
+        assert_eq!(r.gen_range_or(5, 5, 42), 42);
+        assert_eq!(r.gen_range_or(5u32, 2, 42), 42);
+        assert_eq!(r.gen_range_or(1.0, -1.0, 0.5), 0.5);
+        assert_eq!(r.gen_range_or(::core::f64::NAN, 1.0, 0.5), 0.5);
+        // The fallback needn't be in the range.
+        assert_eq!(r.gen_range_or(0, 1, 7), 0);
+
+        // An empty range doesn't advance the generator.
+        let mut a = rng(216);
+        let mut b = rng(216);
+        a.gen_range_or(3, 3, 0);
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+
     #[test]
     #[should_panic]
     fn test_gen_range_panic_int() {
@@ -1045,6 +1611,237 @@ mod test {
         r.gen_range(5, 2);
     }
 
+    #[test]
+    fn test_gen_below() {
+        let mut r = rng(106);
+        let mut counts = [0u32; 10];
+        for _ in 0..10_000 {
+            let v = r.gen_below(10);
+            assert!(v < 10);
+            counts[v as usize] += 1;
+        }
+        for &c in counts.iter() {
+            assert!(c > 900 && c < 1100);
+        }
+
+        for _ in 0..100 {
+            assert_eq!(r.gen_below(1), 0);
+            assert!(r.gen_below(u64::max_value()) < u64::max_value());
+            assert!(r.gen_below((1 << 63) + 1) <= 1 << 63);
+        }
+    }
+
+    #[test]
+    fn test_gen_sign() {
+        assert_eq!(StepRng::new(0, 0).gen_sign(), 1);
+        assert_eq!(StepRng::new(1 << 31, 0).gen_sign(), -1);
+
+        let mut r = rng(110);
+        let sum: i32 = (0..10_000).map(|_| r.gen_sign() as i32).sum();
+        assert!(sum.abs() < 300);
+    }
+
+    #[test]
+    fn test_gen_bits() {
+        assert_eq!(StepRng::new(u64::max_value(), 0).gen_bits(0), 0);
+        assert_eq!(StepRng::new(u64::max_value(), 0).gen_bits(5), 31);
+        assert_eq!(StepRng::new(u64::max_value(), 0).gen_bits(64),
+                   u64::max_value());
+
+        let mut r = rng(111);
+        let mut counts = [0u32; 8];
+        let n = 80_000;
+        for _ in 0..n {
+            let x = r.gen_bits(3);
+            assert!(x < 8);
+            counts[x as usize] += 1;
+        }
+        for &c in counts.iter() {
+            assert!((c as i32 - n / 8).abs() < 400);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_bits_panic() {
+        let mut r = rng(112);
+        r.gen_bits(65);
+    }
+
+    #[test]
+    fn test_discard() {
+        let mut r = rng(188);
+        let mut clone = rng(188);
+        r.discard(5);
+        for _ in 0..5 {
+            clone.gen::<u32>();
+        }
+        assert_eq!(r.gen::<u32>(), clone.gen::<u32>());
+
+        // Through a trait object, and discarding nothing.
+        let mut r = StdRng::seed_from_u64(188);
+        let mut clone = r.clone();
+        {
+            let dyn_rng: &mut RngCore = &mut r;
+            dyn_rng.discard(0);
+        }
+        assert_eq!(r.gen::<u64>(), clone.gen::<u64>());
+    }
+
+    #[test]
+    fn test_gen_unit_f64() {
+        // The extreme inputs give the extreme outputs.
+        assert_eq!(StepRng::new(0, 0).gen_unit_f64(), 0.0);
+        let max = StepRng::new(u64::max_value(), 0).gen_unit_f64();
+        assert_eq!(max, 1.0 - ::core::f64::EPSILON);
+
+        let mut r = rng(109);
+        let mut sum = 0.0;
+        let n = 1 << 24;
+        for _ in 0..n {
+            let x = r.gen_unit_f64();
+            assert!(0.0 <= x && x < 1.0);
+            sum += x;
+        }
+        assert!((sum / n as f64 - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_below_panic_zero() {
+        let mut r = rng(107);
+        r.gen_below(0);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_sample_reservoir_weighted() {
+        let mut r = rng(108);
+        // Items 0..10 have weight 100, all others weight 1.
+        let weight = |&x: &u32| if x < 10 { 100.0 } else { 1.0 };
+        let mut counts = [0u32; 1000];
+        for _ in 0..200 {
+            let v = r.sample_reservoir_weighted(0..1000, 10, weight);
+            assert_eq!(v.len(), 10);
+            let mut sorted = v.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), 10);
+            for x in v {
+                counts[x as usize] += 1;
+            }
+        }
+        // Each heavy item is then about as likely as 100 light items.
+        let heavy: u32 = counts[..10].iter().sum();
+        let light: u32 = counts[10..].iter().sum();
+        assert!(heavy > light / 2);
+        assert!(counts[..10].iter().all(|&c| c > counts[10..].iter().cloned().max().unwrap()));
+
+        assert_eq!(r.sample_reservoir_weighted(0..5, 10, |_| 1.0).len(), 5);
+        assert!(r.sample_reservoir_weighted(0..5, 0, |_| 1.0).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    #[should_panic]
+    fn test_sample_reservoir_weighted_zero_weight() {
+        let mut r = rng(109);
+        r.sample_reservoir_weighted(0..10, 3, |&x| x as f64);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_sample_until() {
+        use distributions::Binomial;
+        let mut r = rng(116);
+        let binomial = Binomial::new(15, 0.4).unwrap();
+        for _ in 0..100 {
+            let x = r.sample_until(&binomial, |&x| x % 2 == 0);
+            assert!(x % 2 == 0 && x <= 15);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_until_impossible() {
+        let mut r = rng(117);
+        r.sample_until(&::distributions::Uniform::new(0u32, 10), |&x| x > 10);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_permutation() {
+        let mut r = rng(113);
+        assert!(r.permutation(0).is_empty());
+        assert_eq!(r.permutation(1), vec![0]);
+
+        let p = r.permutation(1000);
+        let mut seen = vec![false; 1000];
+        for &i in p.iter() {
+            assert!(!seen[i]);
+            seen[i] = true;
+        }
+        assert!(seen.iter().all(|&b| b));
+        assert!(p != (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_nearly_sorted_permutation() {
+        let mut r = rng(199);
+        assert!(r.nearly_sorted_permutation(0, 2).is_empty());
+        assert_eq!(r.nearly_sorted_permutation(10, 0), (0..10).collect::<Vec<_>>());
+
+        for &(n, d) in [(1000, 1), (1000, 5), (20, 100), (1000, 999)].iter() {
+            let p = r.nearly_sorted_permutation(n, d);
+            assert_eq!(p.len(), n);
+            let mut seen = vec![false; n];
+            for (i, &x) in p.iter().enumerate() {
+                assert!(!seen[x]);
+                seen[x] = true;
+                assert!((i as isize - x as isize).abs() <= d as isize,
+                        "{} at position {} with n = {}, d = {}", x, i, n, d);
+            }
+            assert!(p != (0..n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_random_composition() {
+        let mut r = rng(203);
+        assert_eq!(r.random_composition(7, 1), vec![7]);
+        assert_eq!(r.random_composition(0, 3), vec![0, 0, 0]);
+        let c = r.random_composition(::core::u64::MAX, 1);
+        assert_eq!(c, vec![::core::u64::MAX]);
+
+        let (n, parts) = (20, 4);
+        let trials = 20_000;
+        let mut sums = [0u64; 4];
+        let mut all_in_first = 0;
+        for _ in 0..trials {
+            let c = r.random_composition(n, parts);
+            assert_eq!(c.len(), parts);
+            assert_eq!(c.iter().sum::<u64>(), n);
+            for i in 0..parts { sums[i] += c[i]; }
+            if c[0] == n { all_in_first += 1; }
+        }
+        for &sum in sums.iter() {
+            let mean = sum as f64 / trials as f64;
+            assert!((mean - 5.0).abs() < 0.1, "{:?}", sums);
+        }
+        // Each of the `C(23, 3) = 1771` compositions is equally likely.
+        let expected = trials as f64 / 1771.0;
+        assert!((all_in_first as f64 - expected).abs() < 5.0 * expected.sqrt());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature="std")]
+    fn test_random_composition_no_parts() {
+        rng(204).random_composition(5, 0);
+    }
+
     #[test]
     fn test_gen_bool() {
         let mut r = rng(105);
@@ -1054,6 +1851,92 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_fill_bools() {
+        let mut r = rng(111);
+        for &p in [0.0, 0.1, 0.5, 0.75, 1.0].iter() {
+            let mut v = [false; 10_000];
+            r.fill_bools(&mut v, p);
+            let freq = v.iter().filter(|&&b| b).count() as f64 / 10_000.0;
+            assert!((freq - p).abs() < 0.02);
+        }
+
+        // For `p == 0.5` each bit of a `u64` gives one bool, least significant
+        // first.
+        let mut r = StepRng::new(0x5555_5555_5555_5555, 0);
+        let mut v = [true; 130];
+        r.fill_bools(&mut v, 0.5);
+        for (i, &b) in v.iter().enumerate() {
+            assert_eq!(b, i % 64 % 2 == 0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fill_bools_invalid_p() {
+        rng(112).fill_bools(&mut [false; 4], 1.5);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_sample_mask() {
+        let mut r = rng(118);
+        assert!(r.sample_mask(0, 0.3).is_empty());
+        assert!(r.sample_mask(100, 0.0).iter().all(|&b| !b));
+        assert!(r.sample_mask(100, 1.0).iter().all(|&b| b));
+        let len = 100_000;
+        for &density in [0.5, 0.01, 0.9].iter() {
+            let mask = r.sample_mask(len, density);
+            assert_eq!(mask.len(), len);
+            let count = mask.iter().filter(|&&b| b).count() as f64;
+            // Within five standard deviations of the binomial count.
+            let expected = len as f64 * density;
+            let std_dev = (expected * (1.0 - density)).sqrt();
+            assert!((count - expected).abs() < 5.0 * std_dev);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature="std")]
+    fn test_sample_mask_invalid_density() {
+        rng(119).sample_mask(10, -0.1);
+    }
+
+    #[test]
+    fn test_random_subset_mask() {
+        let mut r = rng(234);
+        assert_eq!(r.random_subset_mask(0, 0.7), 0);
+        assert_eq!(r.random_subset_mask(10, 0.0), 0);
+        assert_eq!(r.random_subset_mask(10, 1.0), 0x3ff);
+        assert_eq!(r.random_subset_mask(64, 1.0), u64::max_value());
+        let trials = 10_000;
+        for &(n, p) in [(64, 0.5), (20, 0.5), (40, 0.1), (64, 0.8), (7, 0.3)].iter() {
+            let mut total = 0;
+            for _ in 0..trials {
+                let mask = r.random_subset_mask(n, p);
+                assert!(n == 64 || mask >> n == 0);
+                total += mask.count_ones();
+            }
+            // Within five standard deviations of the binomial count.
+            let expected = (trials * n) as f64 * p;
+            let std_dev = (expected * (1.0 - p)).sqrt();
+            assert!((total as f64 - expected).abs() < 5.0 * std_dev, "{} {}", n, p);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_subset_mask_too_large() {
+        rng(235).random_subset_mask(65, 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_subset_mask_invalid_prob() {
+        rng(236).random_subset_mask(8, 1.5);
+    }
+
     #[test]
     fn test_rng_trait_object() {
         use distributions::{Distribution, Standard};
@@ -134,4 +134,34 @@ mod test {
 
         assert!(rng.try_fill_bytes(&mut w).err().unwrap().kind == ErrorKind::Unavailable);
     }
+
+    #[test]
+    fn test_reader_rng_cursor() {
+        use std::io::Cursor;
+        use Rng;
+
+        // The same bytes always drive the same samples.
+        let data: Vec<u8> = (0..64).collect();
+        let mut a = ReadRng::new(Cursor::new(data.clone()));
+        let mut b = ReadRng::new(Cursor::new(data));
+        for _ in 0..8 {
+            let x: u32 = a.gen_range(0, 100);
+            assert_eq!(x, b.gen_range(0, 100));
+        }
+
+        // Words are read in little-endian order.
+        let mut rng = ReadRng::new(Cursor::new(vec![1u8, 0, 0, 0, 2, 0]));
+        assert_eq!(rng.next_u32(), 1);
+        // Only two bytes remain.
+        let mut w = [0u8; 4];
+        let err = rng.try_fill_bytes(&mut w).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Unavailable);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reader_rng_eof_panics() {
+        let mut rng = ReadRng::new(&[1u8, 2, 3][..]);
+        rng.next_u32();
+    }
 }
@@ -11,6 +11,8 @@
 use rand_core::{RngCore, CryptoRng, Error, ErrorKind, impls};
 #[allow(unused)]
 use rngs;
+#[cfg(feature="test_entropy")]
+use std::cell::RefCell;
 
 /// An interface returning random data from external source(s), provided
 /// specifically for securely seeding algorithmic generators (PRNGs).
@@ -21,7 +23,10 @@ use rngs;
 /// still try to use [`OsRng`] on the next usage.
 ///
 /// If no secure source of entropy is available `EntropyRng` will panic on use;
-/// i.e. it should never output predictable data.
+/// i.e. it should never output predictable data. The only exception is the
+/// `test_entropy` feature: while bytes are installed with
+/// [`set_test_entropy`], every new `EntropyRng` on that thread outputs them
+/// instead.
 ///
 /// This is either a little slow ([`OsRng`] requires a system call) or extremely
 /// slow ([`JitterRng`] must use significant CPU time to generate sufficient
@@ -43,6 +48,7 @@ use rngs;
 /// [`thread_rng`]: ../fn.thread_rng.html
 /// [`RngCore`]: ../trait.RngCore.html
 /// [`try_fill_bytes`]: ../trait.RngCore.html#method.tymethod.try_fill_bytes
+/// [`set_test_entropy`]: fn.set_test_entropy.html
 #[derive(Debug)]
 pub struct EntropyRng {
     source: Source,
@@ -50,6 +56,7 @@ pub struct EntropyRng {
 
 #[derive(Debug)]
 enum Source {
+    Test(Test),
     Os(Os),
     Custom(Custom),
     Jitter(Jitter),
@@ -90,6 +97,15 @@ impl RngCore for EntropyRng {
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
         let mut reported_error = None;
 
+        if let Source::Test(ref mut test) = self.source {
+            return test.fill(dest);
+        } else if Test::is_supported() {
+            let test = Test::new_and_fill(dest)?;
+            debug!("EntropyRng: using test entropy");
+            self.source = Source::Test(test);
+            return Ok(());
+        }
+
         if let Source::Os(ref mut os_rng) = self.source {
             match os_rng.fill(dest) {
                 Ok(()) => return Ok(()),
@@ -263,6 +279,92 @@ type Os = NoSource;
 type Custom = NoSource;
 
 
+#[cfg(feature="test_entropy")]
+thread_local!(
+    static TEST_ENTROPY: RefCell<Option<Vec<u8>>> = RefCell::new(None);
+);
+
+/// Make every `EntropyRng` created afterwards on the current thread output
+/// `bytes`, repeated cyclically, instead of real entropy.
+///
+/// Since [`FromEntropy::from_entropy`] and [`thread_rng`] are seeded via
+/// `EntropyRng`, this makes them reproducible; note that [`thread_rng`] is
+/// only seeded on its first use in each thread. The override lasts until
+/// [`clear_test_entropy`] is called or the thread exits.
+///
+/// This is only available with the `test_entropy` feature and must never be
+/// used outside of tests.
+///
+/// # Panics
+///
+/// If `bytes` is empty.
+///
+/// # Example
+///
+/// ```
+/// use rand::{Rng, FromEntropy};
+/// use rand::rngs::{set_test_entropy, StdRng};
+///
+/// set_test_entropy(vec![42; 32]);
+/// let a = StdRng::from_entropy().gen::<u64>();
+/// let b = StdRng::from_entropy().gen::<u64>();
+/// assert_eq!(a, b);
+/// ```
+///
+/// [`FromEntropy::from_entropy`]: ../trait.FromEntropy.html#tymethod.from_entropy
+/// [`thread_rng`]: ../fn.thread_rng.html
+/// [`clear_test_entropy`]: fn.clear_test_entropy.html
+#[cfg(feature="test_entropy")]
+pub fn set_test_entropy(bytes: Vec<u8>) {
+    assert!(!bytes.is_empty(), "set_test_entropy called with no bytes");
+    TEST_ENTROPY.with(|t| *t.borrow_mut() = Some(bytes));
+}
+
+/// Remove the override installed by [`set_test_entropy`] from the current
+/// thread.
+///
+/// Generators which already use the test entropy keep doing so.
+///
+/// [`set_test_entropy`]: fn.set_test_entropy.html
+#[cfg(feature="test_entropy")]
+pub fn clear_test_entropy() {
+    TEST_ENTROPY.with(|t| *t.borrow_mut() = None);
+}
+
+#[cfg(feature="test_entropy")]
+#[derive(Clone, Debug)]
+pub struct Test {
+    bytes: Vec<u8>,
+    pos: usize,
+}
+
+#[cfg(feature="test_entropy")]
+impl EntropySource for Test {
+    fn new_and_fill(dest: &mut [u8]) -> Result<Self, Error> {
+        let bytes = TEST_ENTROPY.with(|t| t.borrow().clone()).ok_or_else(||
+            Error::new(ErrorKind::Unavailable, "No test entropy installed"))?;
+        let mut test = Test { bytes, pos: 0 };
+        test.fill(dest)?;
+        Ok(test)
+    }
+
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for b in dest.iter_mut() {
+            *b = self.bytes[self.pos];
+            self.pos = (self.pos + 1) % self.bytes.len();
+        }
+        Ok(())
+    }
+
+    fn is_supported() -> bool {
+        TEST_ENTROPY.with(|t| t.borrow().is_some())
+    }
+}
+
+#[cfg(not(feature="test_entropy"))]
+type Test = NoSource;
+
+
 #[cfg(not(target_arch = "wasm32"))]
 #[derive(Clone, Debug)]
 pub struct Jitter(rngs::JitterRng);
@@ -294,4 +396,30 @@ mod test {
         let n = (rng.next_u32() ^ rng.next_u32()).count_ones();
         assert!(n >= 2);    // p(failure) approx 1e-7
     }
+
+    #[test]
+    #[cfg(feature="test_entropy")]
+    fn test_test_entropy() {
+        use {Rng, FromEntropy};
+        use rngs::StdRng;
+
+        set_test_entropy((0..256).map(|x| x as u8).collect());
+        let mut a = StdRng::from_entropy();
+        let mut b = StdRng::from_entropy();
+        for _ in 0..10 {
+            assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+        }
+
+        let mut rng = EntropyRng::new();
+        let mut buf = [0u8; 4];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [0, 1, 2, 3]);
+
+        clear_test_entropy();
+        let mut c = StdRng::from_entropy();
+        assert!(c.gen::<u64>() != StdRng::from_entropy().gen::<u64>());
+        // Generators created earlier are unaffected.
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [4, 5, 6, 7]);
+    }
 }
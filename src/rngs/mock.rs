@@ -9,6 +9,7 @@
 //! Mock random number generator
 
 use rand_core::{RngCore, Error, impls};
+#[cfg(all(feature="alloc", not(feature="std")))] use alloc::vec::Vec;
 
 /// A simple implementation of `RngCore` for testing purposes.
 /// 
@@ -57,3 +58,239 @@ impl RngCore for StepRng {
         Ok(self.fill_bytes(dest))
     }
 }
+
+/// A mock generator replaying a recorded sequence of `u64` values.
+///
+/// Each call to `next_u64` returns the next recorded value, in order;
+/// `next_u32` truncates the next recorded value, like [`StepRng`]. This makes
+/// it possible to reproduce a failing random sequence exactly, for example in
+/// a regression test.
+///
+/// By default the generator panics once all recorded values have been
+/// returned; use [`wrapping`] to restart from the beginning instead.
+///
+/// ```
+/// use rand::Rng;
+/// use rand::rngs::mock::ReplayRng;
+///
+/// let mut my_rng = ReplayRng::from_u64s(vec![7, 8]).wrapping(true);
+/// let sample: [u64; 3] = my_rng.gen();
+/// assert_eq!(sample, [7, 8, 7]);
+/// ```
+///
+/// [`StepRng`]: struct.StepRng.html
+/// [`wrapping`]: struct.ReplayRng.html#method.wrapping
+#[cfg(feature="alloc")]
+#[derive(Debug, Clone)]
+pub struct ReplayRng {
+    values: Vec<u64>,
+    index: usize,
+    wrapping: bool,
+}
+
+#[cfg(feature="alloc")]
+impl ReplayRng {
+    /// Create a `ReplayRng`, yielding the given `values` in order.
+    ///
+    /// Panics if `values` is empty.
+    pub fn from_u64s(values: Vec<u64>) -> Self {
+        assert!(!values.is_empty(), "ReplayRng::from_u64s called with no values");
+        ReplayRng { values, index: 0, wrapping: false }
+    }
+
+    /// Set whether the generator restarts from the first recorded value once
+    /// exhausted (`true`), or panics (`false`, the default).
+    pub fn wrapping(mut self, wrapping: bool) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// The number of recorded values not yet returned.
+    pub fn remaining(&self) -> usize {
+        self.values.len() - self.index
+    }
+}
+
+#[cfg(feature="alloc")]
+impl RngCore for ReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.index == self.values.len() {
+            assert!(self.wrapping, "ReplayRng: recorded values exhausted");
+            self.index = 0;
+        }
+        let result = self.values[self.index];
+        self.index += 1;
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        Ok(self.fill_bytes(dest))
+    }
+}
+
+/// A wrapper around a generator counting how much of its output is consumed.
+///
+/// The count is in 32-bit words: each call to `next_u32` counts one word,
+/// `next_u64` two words, and filling bytes one word for every four bytes,
+/// rounded up. This makes it possible to check in tests that a change to an
+/// algorithm did not alter how many random numbers it uses, which would break
+/// the reproducibility of later draws from the same generator.
+///
+/// ```
+/// use rand::Rng;
+/// use rand::rngs::mock::{CountingRng, StepRng};
+///
+/// let mut rng = CountingRng::new(StepRng::new(0, 1));
+/// let _: (u32, u64) = rng.gen();
+/// assert_eq!(rng.words_consumed(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CountingRng<R> {
+    inner: R,
+    words: u64,
+}
+
+impl<R: RngCore> CountingRng<R> {
+    /// Wrap `rng`, with a count of zero.
+    pub fn new(rng: R) -> Self {
+        CountingRng { inner: rng, words: 0 }
+    }
+
+    /// The number of 32-bit words consumed so far.
+    pub fn words_consumed(&self) -> u64 {
+        self.words
+    }
+
+    /// Reset the count to zero.
+    pub fn reset(&mut self) {
+        self.words = 0;
+    }
+
+    /// Return the wrapped generator.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: RngCore> RngCore for CountingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.words += 1;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.words += 2;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.words += (dest.len() as u64 + 3) / 4;
+        self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.words += (dest.len() as u64 + 3) / 4;
+        self.inner.try_fill_bytes(dest)
+    }
+
+    fn discard_u32(&mut self, n: u64) {
+        self.words += n;
+        self.inner.discard_u32(n)
+    }
+}
+
+#[cfg(all(test, feature="alloc"))]
+mod test {
+    use {Rng, RngCore};
+    use super::{CountingRng, ReplayRng, StepRng};
+    #[cfg(not(feature="std"))] use alloc::vec::Vec;
+
+    #[test]
+    fn test_replay() {
+        let mut rng = ReplayRng::from_u64s(vec![1, 2, 3]);
+        assert_eq!(rng.next_u64(), 1);
+        assert_eq!(rng.next_u32(), 2);
+        assert_eq!(rng.remaining(), 1);
+        assert_eq!(rng.next_u64(), 3);
+        assert_eq!(rng.remaining(), 0);
+    }
+
+    #[test]
+    fn test_replay_wrapping() {
+        let mut rng = ReplayRng::from_u64s(vec![1, 2]).wrapping(true);
+        let v: Vec<u64> = (0..5).map(|_| rng.next_u64()).collect();
+        assert_eq!(v, [1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_replay_exhausted() {
+        let mut rng = ReplayRng::from_u64s(vec![1]);
+        rng.next_u64();
+        rng.next_u64();
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_replay_binomial() {
+        use distributions::{Binomial, Distribution};
+
+        // Record the raw output consumed by a seeded generator, then replay
+        // it: both must give exactly the same results.
+        let mut rng = ::test::rng(401);
+        let recorded = {
+            let mut rng = ::test::rng(401);
+            (0..10_000).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        let mut replay = ReplayRng::from_u64s(recorded);
+
+        for &(n, p) in [(20, 0.3), (1000, 0.2), (1000, 0.7)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            assert_eq!(binomial.sample(&mut rng), binomial.sample(&mut replay));
+        }
+    }
+
+    #[test]
+    fn test_counting() {
+        let mut rng = CountingRng::new(StepRng::new(5, 1));
+        assert_eq!(rng.next_u32(), 5);
+        assert_eq!(rng.next_u64(), 6);
+        assert_eq!(rng.words_consumed(), 3);
+        let mut buf = [0u8; 9];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(rng.words_consumed(), 6);
+        rng.discard(4);
+        assert_eq!(rng.words_consumed(), 10);
+        rng.reset();
+        assert_eq!(rng.words_consumed(), 0);
+        assert_eq!(rng.into_inner().next_u64(), 13);
+    }
+
+    #[test]
+    #[cfg(feature="std")]
+    fn test_counting_binomial() {
+        use distributions::{Binomial, Distribution};
+
+        // Pin the consumption of each sampling algorithm over ten samples; a
+        // change means that draws following a sample are not reproducible.
+        let mut rng = CountingRng::new(::test::rng(195));
+        let expected = [(0, 0.5, 0), (20, 1.0, 0), (20, 0.3, 400), (1000, 0.2, 82),
+                        (1000, 0.7, 90), (400, 0.01, 158)];
+        for &(n, p, words) in expected.iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            rng.reset();
+            for _ in 0..10 {
+                binomial.sample(&mut rng);
+            }
+            assert_eq!(rng.words_consumed(), words, "Binomial({}, {})", n, p);
+        }
+    }
+}
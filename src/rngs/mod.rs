@@ -10,8 +10,11 @@
 //!
 //! - [`ThreadRng`], a fast, secure, auto-seeded thread-local generator
 //! - [`StdRng`] and [`SmallRng`], algorithms to cover typical usage
+//! - [`SplitMix64`], a tiny generator for expanding seeds
 //! - [`EntropyRng`], [`OsRng`] and [`JitterRng`] as entropy sources
 //! - [`mock::StepRng`] as a simple counter for tests
+//! - [`mock::ReplayRng`] to replay a recorded sequence in tests
+//! - [`mock::CountingRng`] to count the output consumed in tests
 //! - [`adapter::ReadRng`] to read from a file/stream
 //! - [`adapter::ReseedingRng`] to reseed a PRNG on clone / process fork etc.
 //!
@@ -140,6 +143,7 @@
 //! [`ThreadRng`]: struct.ThreadRng.html
 //! [`StdRng`]: struct.StdRng.html
 //! [`SmallRng`]: struct.SmallRng.html
+//! [`SplitMix64`]: struct.SplitMix64.html
 //! [`EntropyRng`]: struct.EntropyRng.html
 //! [`OsRng`]: struct.OsRng.html
 //! [`JitterRng`]: struct.JitterRng.html
@@ -156,6 +160,8 @@
 //! [`SeedableRng::from_seed`]: ../trait.SeedableRng.html#tymethod.from_seed
 //! [`thread_rng`]: ../fn.thread_rng.html
 //! [`mock::StepRng`]: mock/struct.StepRng.html
+//! [`mock::ReplayRng`]: mock/struct.ReplayRng.html
+//! [`mock::CountingRng`]: mock/struct.CountingRng.html
 //! [`adapter::ReadRng`]: adapter/struct.ReadRng.html
 //! [`adapter::ReseedingRng`]: adapter/struct.ReseedingRng.html
 //! [`ChaChaRng`]: ../../rand_chacha/struct.ChaChaRng.html
@@ -167,14 +173,18 @@ mod jitter;
 pub mod mock;   // Public so we don't export `StepRng` directly, making it a bit
                 // more clear it is intended for testing.
 mod small;
+mod splitmix;
 mod std;
 #[cfg(feature="std")] pub(crate) mod thread;
 
 
 pub use self::jitter::{JitterRng, TimerError};
 #[cfg(feature="std")] pub use self::entropy::EntropyRng;
+#[cfg(feature="test_entropy")]
+pub use self::entropy::{set_test_entropy, clear_test_entropy};
 
 pub use self::small::SmallRng;
+pub use self::splitmix::SplitMix64;
 pub use self::std::StdRng;
 #[cfg(feature="std")] pub use self::thread::ThreadRng;
 
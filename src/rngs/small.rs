@@ -9,6 +9,7 @@
 //! A small fast RNG
 
 use {RngCore, SeedableRng, Error};
+use rngs::SplitMix64;
 
 #[cfg(all(rust_1_26, target_pointer_width = "64"))]
 type Rng = ::rand_pcg::Pcg64Mcg;
@@ -70,6 +71,7 @@ type Rng = ::rand_pcg::Pcg32;
 /// [`Pcg64Mcg`]: https://docs.rs/rand_pcg/0.1.0/rand_pcg/type.Pcg64Mcg.html
 /// [`Pcg32`]: https://docs.rs/rand_pcg/0.1.0/rand_pcg/type.Pcg32.html
 #[derive(Clone, Debug)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
 pub struct SmallRng(Rng);
 
 impl RngCore for SmallRng {
@@ -99,7 +101,62 @@ impl SeedableRng for SmallRng {
         SmallRng(Rng::from_seed(seed))
     }
 
+    /// Create a new `SmallRng`, expanding `state` into a full seed with
+    /// [`SplitMix64`](struct.SplitMix64.html).
+    fn seed_from_u64(state: u64) -> Self {
+        let mut seed = Self::Seed::default();
+        SplitMix64::seed_from_u64(state).fill_bytes(seed.as_mut());
+        SmallRng::from_seed(seed)
+    }
+
     fn from_rng<R: RngCore>(rng: R) -> Result<Self, Error> {
         Rng::from_rng(rng).map(SmallRng)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use {RngCore, SeedableRng};
+    use rngs::SplitMix64;
+    use super::SmallRng;
+
+    #[test]
+    fn test_small_rng_seed_from_u64() {
+        let mut seed = <SmallRng as SeedableRng>::Seed::default();
+        SplitMix64::seed_from_u64(42).fill_bytes(seed.as_mut());
+        let mut a = SmallRng::seed_from_u64(42);
+        let mut b = SmallRng::from_seed(seed);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert!(SmallRng::seed_from_u64(43).next_u64() != a.next_u64());
+    }
+
+    #[test]
+    fn test_small_rng_seed_from_u64_stability() {
+        // The output depends on the platform's generator; changing it is a
+        // value-breaking change.
+        #[cfg(all(rust_1_26, target_pointer_width = "64"))]
+        let expected = [15069899730639214346, 8671687002609827925, 12012872267674557919];
+        #[cfg(not(all(rust_1_26, target_pointer_width = "64")))]
+        let expected = [16399220484193545111, 1235177755136452445, 13694689846354164297];
+        let mut rng = SmallRng::seed_from_u64(127);
+        for &e in expected.iter() {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    #[cfg(feature="serde1")]
+    fn test_small_rng_serde() {
+        use bincode;
+
+        let mut rng = SmallRng::seed_from_u64(239);
+        rng.next_u32();
+        let saved = bincode::serialize(&rng).unwrap();
+        let expected: Vec<u64> = (0..100).map(|_| rng.next_u64()).collect();
+
+        let mut restored: SmallRng = bincode::deserialize(&saved).unwrap();
+        for &x in expected.iter() {
+            assert_eq!(restored.next_u64(), x);
+        }
+    }
+}
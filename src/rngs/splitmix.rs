@@ -0,0 +1,118 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The SplitMix64 random number generator.
+
+use rand_core::{RngCore, SeedableRng, Error, impls, le};
+
+/// The "golden ratio" increment, `2^64 / phi` rounded to an odd integer.
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// A SplitMix64 random number generator.
+///
+/// SplitMix64 adds a fixed odd increment (derived from the golden ratio) to a
+/// 64-bit counter, and passes the counter through a strong bit-mixing
+/// function. It is very fast, and any 64-bit seed (including zero) gives a
+/// good stream, which makes it well suited to expanding a small seed into the
+/// larger state of another generator. It is also usable as a small
+/// general-purpose generator, but has only `2^64` states and is **not
+/// suitable for cryptography**.
+///
+/// The algorithm is described by Steele, Lea and Flood in [*Fast splittable
+/// pseudorandom number generators*](https://doi.org/10.1145/2714064.2660195);
+/// this is the variant used by Java's `SplittableRandom`, without splitting.
+///
+/// The seed is the initial counter value, in little-endian byte order, and
+/// `seed_from_u64` uses the given value directly.
+///
+/// ```
+/// use rand::{Rng, SeedableRng};
+/// use rand::rngs::SplitMix64;
+///
+/// let mut rng = SplitMix64::seed_from_u64(0);
+/// assert_eq!(rng.gen::<u64>(), 0xe220a8397b1dcdaf);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
+pub struct SplitMix64 {
+    x: u64,
+}
+
+impl RngCore for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.x = self.x.wrapping_add(GOLDEN_GAMMA);
+        let mut z = self.x;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        Ok(self.fill_bytes(dest))
+    }
+}
+
+impl SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: [u8; 8]) -> SplitMix64 {
+        let mut x = [0u64; 1];
+        le::read_u64_into(&seed, &mut x);
+        SplitMix64 { x: x[0] }
+    }
+
+    fn seed_from_u64(state: u64) -> SplitMix64 {
+        SplitMix64 { x: state }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {RngCore, SeedableRng};
+    use super::SplitMix64;
+
+    #[test]
+    fn test_splitmix64_reference() {
+        // Reference values from the C implementation by Sebastiano Vigna,
+        // http://xoshiro.di.unimi.it/splitmix64.c
+        let mut rng = SplitMix64::seed_from_u64(0);
+        let expected = [0xe220a8397b1dcdaf, 0x6e789e6aa1b965f4,
+                        0x06c45d188009454f, 0xf88bb8a8724c81ec,
+                        0x1b39896a51a8749b];
+        for &e in expected.iter() {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = SplitMix64::seed_from_u64(1234567);
+        let expected = [0x599ed017fb08fc85, 0x2c73f08458540fa5,
+                        0x883ebce5a3f27c77, 0x3fbef740e9177b3f,
+                        0xe3b8346708cb5ecd];
+        for &e in expected.iter() {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn test_splitmix64_from_seed() {
+        let seed = [0x87, 0xd6, 0x12, 0, 0, 0, 0, 0];
+        let mut a = SplitMix64::from_seed(seed);
+        let mut b = SplitMix64::seed_from_u64(1234567);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_eq!(a.next_u32(), (b.next_u64() >> 32) as u32);
+    }
+}
@@ -23,9 +23,15 @@ use rand_hc::Hc128Rng;
 /// produce different output depending on the architecture. If you require
 /// reproducible output, use a named RNG, for example [`ChaChaRng`].
 ///
+/// With the `serde1` feature the entire state can be serialized, including
+/// any buffered results, to resume the exact same sequence later. As the
+/// algorithm may change, only restore the state with the same library
+/// version.
+///
 /// [HC-128]: ../../rand_hc/struct.Hc128Rng.html
 /// [`ChaChaRng`]: ../../rand_chacha/struct.ChaChaRng.html
 #[derive(Clone, Debug)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
 pub struct StdRng(Hc128Rng);
 
 impl RngCore for StdRng {
@@ -78,4 +84,23 @@ mod test {
         let mut rng2 = StdRng::from_rng(rng1).unwrap();
         assert_eq!(rng2.next_u64(), 6766915756997287454);
     }
+
+    #[test]
+    #[cfg(feature="serde1")]
+    fn test_stdrng_serde() {
+        use bincode;
+
+        let mut rng = StdRng::seed_from_u64(239);
+        // Save part way through the buffered results.
+        for _ in 0..5 {
+            rng.next_u32();
+        }
+        let saved = bincode::serialize(&rng).unwrap();
+        let expected: Vec<u64> = (0..600).map(|_| rng.next_u64()).collect();
+
+        let mut restored: StdRng = bincode::deserialize(&saved).unwrap();
+        for &x in expected.iter() {
+            assert_eq!(restored.next_u64(), x);
+        }
+    }
 }
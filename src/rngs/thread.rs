@@ -99,6 +99,63 @@ pub fn thread_rng() -> ThreadRng {
     ThreadRng { rng: THREAD_RNG_KEY.with(|t| t.get()) }
 }
 
+/// Seed the [`thread_rng`] of every worker thread in the current rayon thread
+/// pool deterministically from `base_seed`.
+///
+/// Each worker's generator is replaced by one seeded from `base_seed` and the
+/// index of the worker in the pool, with reseeding disabled. This makes
+/// `thread_rng()` inside parallel iterators reproducible, but only for a
+/// fixed pool size and as long as the same work ends up on the same worker:
+/// with a different number of threads the seeds are distributed differently,
+/// and work stealing may move items between workers from one run to the
+/// next. For reproducible results independent of scheduling, prefer passing
+/// explicitly seeded generators, as [`sample_par`] does.
+///
+/// The call applies to the pool it is run in, e.g. via
+/// `ThreadPool::install`, or else to the global pool. Generators of threads
+/// outside that pool, including the calling thread, are not affected.
+///
+/// # Example
+///
+/// ```
+/// # extern crate rand;
+/// # extern crate rayon;
+/// use rand::{thread_rng, seed_pool, Rng};
+///
+/// # fn main() {
+///
+/// seed_pool(42);
+/// let x: u64 = rayon::broadcast(|_| thread_rng().gen::<u64>())[0];
+/// # let _ = x;
+/// # }
+/// ```
+///
+/// [`thread_rng`]: ../fn.thread_rng.html
+/// [`sample_par`]: ../distributions/trait.Distribution.html#method.sample_par
+#[cfg(feature="rayon")]
+pub fn seed_pool(base_seed: u64) {
+    ::rayon::broadcast(|ctx| seed_thread_rng(pool_seed(base_seed, ctx.index())));
+}
+
+/// The seed of the worker with index `index` used by `seed_pool`. Distinct
+/// indices give distinct seeds, because multiplying by an odd constant is a
+/// bijection.
+#[allow(dead_code)]
+fn pool_seed(base_seed: u64, index: usize) -> u64 {
+    base_seed ^ (index as u64).wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+/// Replace the generator of the current thread by one seeded from `seed`,
+/// which is never reseeded.
+#[allow(dead_code)]
+fn seed_thread_rng(seed: u64) {
+    let rng = ReseedingRng::new(Hc128Core::seed_from_u64(seed), 0,
+                                EntropyRng::new());
+    // No reference to the interior is active while we are in this function;
+    // see the rationale for using `UnsafeCell` above.
+    THREAD_RNG_KEY.with(|t| unsafe { *t.get() = rng });
+}
+
 impl RngCore for ThreadRng {
     #[inline(always)]
     fn next_u32(&mut self) -> u32 {
@@ -132,4 +189,47 @@ mod test {
         r.gen::<i32>();
         assert_eq!(r.gen_range(0, 1), 0);
     }
+
+    #[test]
+    fn test_pool_seed() {
+        use super::pool_seed;
+        for i in 0..64 {
+            assert_eq!(pool_seed(7, i), pool_seed(7, i));
+            assert!(pool_seed(7, i) != pool_seed(8, i));
+            for j in 0..i {
+                assert!(pool_seed(7, i) != pool_seed(7, j));
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed_thread_rng() {
+        use Rng;
+        use super::seed_thread_rng;
+        let mut r = ::thread_rng();
+        seed_thread_rng(3);
+        let a: [u64; 4] = r.gen();
+        seed_thread_rng(3);
+        let b: [u64; 4] = r.gen();
+        seed_thread_rng(4);
+        let c: [u64; 4] = r.gen();
+        assert_eq!(a, b);
+        assert!(a != c);
+    }
+
+    #[test]
+    #[cfg(feature="rayon")]
+    fn test_seed_pool() {
+        use Rng;
+        use rayon::ThreadPoolBuilder;
+        let pool = ThreadPoolBuilder::new().num_threads(3).build().unwrap();
+        let run = |base_seed| pool.install(|| {
+            super::seed_pool(base_seed);
+            ::rayon::broadcast(|_| ::thread_rng().gen::<u64>())
+        });
+        let a = run(1);
+        assert_eq!(a, run(1));
+        assert!(a != run(2));
+        assert!(a[0] != a[1] && a[1] != a[2] && a[0] != a[2]);
+    }
 }
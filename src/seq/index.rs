@@ -375,4 +375,38 @@ mod test {
         assert!(v1.iter().all(|e| e < length));
         assert_eq!(v1, v2);
     }
+
+    #[test]
+    fn test_sample_distinct() {
+        let mut r = ::test::rng(423);
+        // Cover each of the floyd, inplace and rejection algorithms.
+        for &(length, amount) in [(1000, 10), (1000, 60), (100, 50),
+                                   (1 << 20, 600), (20, 20)].iter() {
+            let v = sample(&mut r, length, amount).into_vec();
+            assert_eq!(v.len(), amount);
+            assert!(v.iter().all(|&e| e < length));
+            let mut sorted = v.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), amount);
+        }
+    }
+
+    #[test]
+    fn test_sample_alg_boundary() {
+        let seed_rng = ::test::rng;
+        let length = 300;
+
+        // Below 12 samples, Floyd's algorithm is always used.
+        let v1 = sample(&mut seed_rng(424), length, 11);
+        let v2 = sample_floyd(&mut seed_rng(424), length as u32, 11);
+        assert_eq!(v1, v2);
+
+        // From 12 samples with a short `length`, inplace is used instead.
+        let v1 = sample(&mut seed_rng(425), length, 12);
+        let v2 = sample_inplace(&mut seed_rng(425), length as u32, 12);
+        assert_eq!(v1, v2);
+        let v3 = sample_floyd(&mut seed_rng(425), length as u32, 12);
+        assert!(v1 != v3);
+    }
 }
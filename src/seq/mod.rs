@@ -440,6 +440,31 @@ impl<'a, S: Index<usize, Output = T> + ?Sized + 'a, T: 'a> ExactSizeIterator
 }
 
 
+/// Shuffle `slice` in place, with a generator seeded from `seed`.
+///
+/// This is equivalent to calling [`SliceRandom::shuffle`] with a new
+/// [`SplitMix64`] seeded by `seed`, so the permutation depends only on `seed`
+/// and the length of the slice, and no values are consumed from any other
+/// generator.
+///
+/// ```
+/// use rand::seq::slice_shuffle_seeded;
+///
+/// let mut a = [1, 2, 3, 4, 5];
+/// let mut b = [1, 2, 3, 4, 5];
+/// slice_shuffle_seeded(&mut a, 42);
+/// slice_shuffle_seeded(&mut b, 42);
+/// assert_eq!(a, b);
+/// ```
+///
+/// [`SliceRandom::shuffle`]: trait.SliceRandom.html#tymethod.shuffle
+/// [`SplitMix64`]: ../rngs/struct.SplitMix64.html
+pub fn slice_shuffle_seeded<T>(slice: &mut [T], seed: u64) {
+    use SeedableRng;
+    use rngs::SplitMix64;
+    slice.shuffle(&mut SplitMix64::seed_from_u64(seed));
+}
+
 /// Randomly sample `amount` elements from a finite iterator.
 ///
 /// Deprecated: use [`IteratorRandom::choose_multiple`] instead.
@@ -627,6 +652,37 @@ mod test {
         assert_eq!(UnhintedIterator{ iter: 0..0 }.choose(r), None);
     }
 
+    #[test]
+    fn test_slice_shuffle_seeded() {
+        let mut a: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut b = a;
+        slice_shuffle_seeded(&mut a, 1);
+        slice_shuffle_seeded(&mut b, 1);
+        assert_eq!(a, b);
+
+        let mut sorted = a;
+        sorted.sort();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // Different seeds give different permutations (nearly always, and for
+        // these seeds in particular).
+        let distinct = (2..10).filter(|&seed| {
+            let mut c: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            slice_shuffle_seeded(&mut c, seed);
+            c != a
+        }).count();
+        assert_eq!(distinct, 8);
+
+        // The permutation is a fixed function of the seed (and of the size of
+        // `usize`, which determines how indices are sampled).
+        #[cfg(target_pointer_width = "64")]
+        {
+            let mut c: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            slice_shuffle_seeded(&mut c, 0);
+            assert_eq!(c, [8, 6, 3, 9, 2, 7, 5, 1, 0, 4]);
+        }
+    }
+
     #[test]
     fn test_shuffle() {
         let mut r = ::test::rng(108);